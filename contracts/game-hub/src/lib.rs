@@ -0,0 +1,450 @@
+#![no_std]
+
+//! # Game Hub
+//!
+//! Session registry shared by the game contracts built on it. Games are
+//! registered by the admin; only a registered game can start a session, and
+//! only the game that started a session can end it.
+//!
+//! - Points: each player has a point balance. A session's stakes are debited
+//!   from both players when it starts, with their authorization, and the
+//!   whole pot goes to the winner when it ends
+//! - Sessions: each game numbers its own sessions; a `(game_id, session_id)`
+//!   pair is started once and ended once
+//! - History: sessions and per-player records are kept in persistent storage
+//!   so any game on the hub can show a player's cross-game profile
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, vec, Address, BytesN, Env,
+    IntoVal, Val, Vec,
+};
+
+/// Ledgers a session or balance stays live after it is written (~30 days).
+const PERSISTENT_TTL_LEDGERS: u32 = 518_400;
+
+/// Most sessions returned by one `get_sessions_for_player` call. Each costs
+/// two ledger reads (index entry and session), and a call may touch at most
+/// 100 entries.
+const MAX_PAGE_SIZE: u32 = 40;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    GameNotRegistered = 1,
+    SessionExists = 2,
+    SessionNotFound = 3,
+    SessionEnded = 4,
+    InsufficientPoints = 5,
+    InvalidPoints = 6,
+}
+
+/// A game session. Two-player games have one player per team.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub team1: Vec<Address>,
+    pub team2: Vec<Address>,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub ended: bool,
+    /// Set once the session has ended.
+    pub player1_won: Option<bool>,
+}
+
+/// A player's results across every game on the hub.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerRecord {
+    /// Sessions started, including ones still running.
+    pub sessions: u32,
+    pub wins: u32,
+    pub losses: u32,
+    /// The opponents' stakes collected from won sessions.
+    pub points_won: i128,
+    /// Own stakes lost in lost sessions.
+    pub points_lost: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    /// A game contract allowed to start sessions.
+    Game(Address),
+    Points(Address),
+    /// A session: (game_id, session_id).
+    Session(Address, u32),
+    /// Number of sessions a player has taken part in.
+    PlayerSessionCount(Address),
+    /// The `(game_id, session_id)` of the n-th session a player took part in: (player, n).
+    PlayerSession(Address, u32),
+    PlayerRecord(Address),
+}
+
+#[contractevent]
+pub struct GameStarted {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+#[contractevent]
+pub struct TeamGameStarted {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub team1: Vec<Address>,
+    pub team2: Vec<Address>,
+}
+
+#[contractevent]
+pub struct GameEnded {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub player1_won: bool,
+}
+
+#[contract]
+pub struct GameHub;
+
+#[contractimpl]
+impl GameHub {
+    /// Initialize the hub with an admin.
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Allow a game contract to start sessions (admin only).
+    pub fn add_game(env: Env, game_id: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        set_persistent(&env, &DataKey::Game(game_id), &true);
+    }
+
+    /// Stop a game contract from starting new sessions (admin only). Its
+    /// running sessions can still be ended.
+    pub fn remove_game(env: Env, game_id: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage().persistent().remove(&DataKey::Game(game_id));
+    }
+
+    pub fn is_game(env: Env, game_id: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Game(game_id))
+    }
+
+    /// Credit points to a player (admin only).
+    pub fn mint_points(env: Env, player: Address, amount: i128) -> Result<(), Error> {
+        Self::get_admin(env.clone()).require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidPoints);
+        }
+        credit(&env, &player, amount);
+        Ok(())
+    }
+
+    pub fn get_points(env: Env, player: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Points(player))
+            .unwrap_or(0)
+    }
+
+    /// Start a game session
+    ///
+    /// Debits each player's stake from their point balance. Must be
+    /// authorized by `game_id`, which has to be a registered game, and by
+    /// each player with a non-zero stake for `(game_id, session_id, points)`.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - Unique identifier for this game session
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points player 1 stakes
+    /// * `player2_points` - Points player 2 stakes
+    pub fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        check_new_session(&env, &game_id, session_id)?;
+
+        if player1_points < 0 || player2_points < 0 {
+            return Err(Error::InvalidPoints);
+        }
+        debit(&env, &game_id, session_id, &player1, player1_points)?;
+        debit(&env, &game_id, session_id, &player2, player2_points)?;
+
+        let session = Session {
+            session_id,
+            game_id: game_id.clone(),
+            team1: vec![&env, player1.clone()],
+            team2: vec![&env, player2.clone()],
+            player1_points,
+            player2_points,
+            ended: false,
+            player1_won: None,
+        };
+        set_persistent(&env, &DataKey::Session(session.game_id.clone(), session_id), &session);
+        index_session(&env, &session);
+
+        GameStarted {
+            session_id,
+            game_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        }
+        .publish(&env);
+        env.storage().instance().extend_ttl(17_280, 518_400);
+
+        Ok(())
+    }
+
+    /// Start a team game session with more than two players. Nothing is staked.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - Unique identifier for this game session
+    /// * `team1` - Players on the first team; `end_game`'s `player1_won` refers to this team
+    /// * `team2` - Players on the second team
+    pub fn start_team_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        team1: Vec<Address>,
+        team2: Vec<Address>,
+    ) -> Result<(), Error> {
+        check_new_session(&env, &game_id, session_id)?;
+
+        let session = Session {
+            session_id,
+            game_id: game_id.clone(),
+            team1: team1.clone(),
+            team2: team2.clone(),
+            player1_points: 0,
+            player2_points: 0,
+            ended: false,
+            player1_won: None,
+        };
+        set_persistent(&env, &DataKey::Session(session.game_id.clone(), session_id), &session);
+        index_session(&env, &session);
+
+        TeamGameStarted {
+            session_id,
+            game_id,
+            team1,
+            team2,
+        }
+        .publish(&env);
+        env.storage().instance().extend_ttl(17_280, 518_400);
+
+        Ok(())
+    }
+
+    /// End a game session and declare winner
+    ///
+    /// Must be authorized by the game that started the session. The winner
+    /// receives both stakes.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - The game session being ended
+    /// * `player1_won` - True if player1 won, false if player2 won
+    pub fn end_game(env: Env, game_id: Address, session_id: u32, player1_won: bool) -> Result<(), Error> {
+        game_id.require_auth();
+
+        let key = DataKey::Session(game_id.clone(), session_id);
+        let mut session: Session = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::SessionNotFound)?;
+
+        if session.ended {
+            return Err(Error::SessionEnded);
+        }
+
+        let pot = session.player1_points + session.player2_points;
+        if pot > 0 {
+            let winner = if player1_won {
+                session.team1.get(0).unwrap()
+            } else {
+                session.team2.get(0).unwrap()
+            };
+            credit(&env, &winner, pot);
+        }
+
+        session.ended = true;
+        session.player1_won = Some(player1_won);
+        set_persistent(&env, &key, &session);
+
+        // The losing side's stake changes hands
+        let (winners, losers, stake) = if player1_won {
+            (&session.team1, &session.team2, session.player2_points)
+        } else {
+            (&session.team2, &session.team1, session.player1_points)
+        };
+        for player in winners.iter() {
+            update_record(&env, &player, |record| {
+                record.wins += 1;
+                record.points_won += stake;
+            });
+        }
+        for player in losers.iter() {
+            update_record(&env, &player, |record| {
+                record.losses += 1;
+                record.points_lost += stake;
+            });
+        }
+
+        GameEnded {
+            session_id,
+            game_id,
+            player1_won,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Read a session. Each game numbers its own sessions, so a session id
+    /// alone does not name one and the game has to be given too.
+    pub fn get_session(env: Env, game_id: Address, session_id: u32) -> Result<Session, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Session(game_id, session_id))
+            .ok_or(Error::SessionNotFound)
+    }
+
+    /// Sessions `player` took part in, oldest first, starting at the
+    /// `cursor`-th one. Looks at `limit` entries (capped at 40), so the next
+    /// page starts at `cursor + min(limit, 40)`. Entries whose session or
+    /// index has expired are skipped, so a page may come back short.
+    pub fn get_sessions_for_player(env: Env, player: Address, cursor: u32, limit: u32) -> Vec<Session> {
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PlayerSessionCount(player.clone()))
+            .unwrap_or(0);
+        let end = count.min(cursor.saturating_add(limit.min(MAX_PAGE_SIZE)));
+
+        let mut sessions = vec![&env];
+        for n in cursor..end {
+            let entry: Option<(Address, u32)> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PlayerSession(player.clone(), n));
+            let Some((game_id, session_id)) = entry else {
+                continue;
+            };
+            if let Some(session) = env.storage().persistent().get(&DataKey::Session(game_id, session_id)) {
+                sessions.push_back(session);
+            }
+        }
+        sessions
+    }
+
+    pub fn get_player_record(env: Env, player: Address) -> PlayerRecord {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PlayerRecord(player))
+            .unwrap_or_default()
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        Self::get_admin(env.clone()).require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+/// Authorize `game_id` and check it may start `session_id`.
+fn check_new_session(env: &Env, game_id: &Address, session_id: u32) -> Result<(), Error> {
+    game_id.require_auth();
+
+    if !env.storage().persistent().has(&DataKey::Game(game_id.clone())) {
+        return Err(Error::GameNotRegistered);
+    }
+    if env.storage().persistent().has(&DataKey::Session(game_id.clone(), session_id)) {
+        return Err(Error::SessionExists);
+    }
+    Ok(())
+}
+
+/// Add a new session to each of its players' history.
+fn index_session(env: &Env, session: &Session) {
+    for player in session.team1.iter().chain(session.team2.iter()) {
+        let count_key = DataKey::PlayerSessionCount(player.clone());
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        set_persistent(
+            env,
+            &DataKey::PlayerSession(player.clone(), count),
+            &(session.game_id.clone(), session.session_id),
+        );
+        set_persistent(env, &count_key, &(count + 1));
+        update_record(env, &player, |record| record.sessions += 1);
+    }
+}
+
+fn update_record(env: &Env, player: &Address, update: impl FnOnce(&mut PlayerRecord)) {
+    let key = DataKey::PlayerRecord(player.clone());
+    let mut record: PlayerRecord = env.storage().persistent().get(&key).unwrap_or_default();
+    update(&mut record);
+    set_persistent(env, &key, &record);
+}
+
+/// Take a player's stake for a session, with the player's authorization.
+fn debit(env: &Env, game_id: &Address, session_id: u32, player: &Address, amount: i128) -> Result<(), Error> {
+    if amount == 0 {
+        return Ok(());
+    }
+    player.require_auth_for_args((game_id.clone(), session_id, amount).into_val(env));
+    let key = DataKey::Points(player.clone());
+    let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    if balance < amount {
+        return Err(Error::InsufficientPoints);
+    }
+    set_persistent(env, &key, &(balance - amount));
+    Ok(())
+}
+
+fn credit(env: &Env, player: &Address, amount: i128) {
+    let key = DataKey::Points(player.clone());
+    let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    set_persistent(env, &key, &(balance + amount));
+}
+
+fn set_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_TTL_LEDGERS, PERSISTENT_TTL_LEDGERS);
+}
+
+#[cfg(test)]
+mod test;
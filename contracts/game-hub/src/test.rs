@@ -0,0 +1,263 @@
+#![cfg(test)]
+
+extern crate std;
+
+use crate::{DataKey, Error, GameHub, GameHubClient, PlayerRecord, MAX_PAGE_SIZE};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
+
+fn setup() -> (Env, GameHubClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(GameHub, (&admin,));
+    let client = GameHubClient::new(&env, &contract_id);
+    let game_id = Address::generate(&env);
+    client.add_game(&game_id);
+    (env, client, game_id)
+}
+
+// ============================================================================
+// Session Tests
+// ============================================================================
+
+#[test]
+fn test_sessions_checked() {
+    let (env, client, game_id) = setup();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    let unknown_game = Address::generate(&env);
+    let result = client.try_start_game(&unknown_game, &1, &player1, &player2, &0, &0);
+    assert_eq!(result, Err(Ok(Error::GameNotRegistered)));
+
+    client.start_game(&game_id, &1, &player1, &player2, &0, &0);
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &0, &0);
+    assert_eq!(result, Err(Ok(Error::SessionExists)));
+    let team = vec![&env, player1.clone()];
+    let result = client.try_start_team_game(&game_id, &1, &team, &team);
+    assert_eq!(result, Err(Ok(Error::SessionExists)));
+    assert_eq!(client.try_end_game(&game_id, &2, &true), Err(Ok(Error::SessionNotFound)));
+
+    client.remove_game(&game_id);
+    assert!(!client.is_game(&game_id));
+    let result = client.try_start_game(&game_id, &2, &player1, &player2, &0, &0);
+    assert_eq!(result, Err(Ok(Error::GameNotRegistered)));
+
+    // A removed game can still end its running sessions, once
+    client.end_game(&game_id, &1, &true);
+    assert_eq!(client.try_end_game(&game_id, &1, &false), Err(Ok(Error::SessionEnded)));
+    assert_eq!(client.get_session(&game_id, &1).player1_won, Some(true));
+}
+
+#[test]
+fn test_session_ids_scoped_to_game() {
+    let (env, client, game_id) = setup();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    // Both games number their sessions from 1
+    client.start_game(&game_id, &1, &player1, &player2, &0, &0);
+    client.start_game(&other_game, &1, &player2, &player1, &0, &0);
+    assert_eq!(client.get_session(&other_game, &1).team1, vec![&env, player2.clone()]);
+
+    // Neither can end the other's session
+    assert_eq!(client.try_end_game(&other_game, &2, &true), Err(Ok(Error::SessionNotFound)));
+    client.end_game(&other_game, &1, &true);
+    assert!(!client.get_session(&game_id, &1).ended);
+    assert_eq!(client.get_player_record(&player2).wins, 1);
+    let unknown_game = Address::generate(&env);
+    assert_eq!(client.try_get_session(&unknown_game, &1), Err(Ok(Error::SessionNotFound)));
+}
+
+#[test]
+fn test_start_requires_game_auth() {
+    let (env, client, game_id) = setup();
+    env.set_auths(&[]);
+    let player = Address::generate(&env);
+    assert!(client.try_start_game(&game_id, &1, &player, &player, &0, &0).is_err());
+}
+
+// ============================================================================
+// Points Tests
+// ============================================================================
+
+#[test]
+fn test_stakes_paid_to_winner() {
+    let (env, client, game_id) = setup();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.mint_points(&player1, &1000);
+    client.mint_points(&player2, &500);
+    assert_eq!(client.try_mint_points(&player1, &0), Err(Ok(Error::InvalidPoints)));
+
+    client.start_game(&game_id, &1, &player1, &player2, &300, &300);
+    assert_eq!((client.get_points(&player1), client.get_points(&player2)), (700, 200));
+    let result = client.try_start_game(&game_id, &2, &player1, &player2, &0, &300);
+    assert_eq!(result, Err(Ok(Error::InsufficientPoints)));
+    let result = client.try_start_game(&game_id, &2, &player1, &player2, &-1, &0);
+    assert_eq!(result, Err(Ok(Error::InvalidPoints)));
+
+    client.end_game(&game_id, &1, &false);
+    assert_eq!((client.get_points(&player1), client.get_points(&player2)), (700, 800));
+    let session = client.get_session(&game_id, &1);
+    assert!(session.ended);
+    assert_eq!(session.player1_won, Some(false));
+    assert_eq!(client.try_end_game(&game_id, &1, &true), Err(Ok(Error::SessionEnded)));
+    assert_eq!((client.get_points(&player1), client.get_points(&player2)), (700, 800));
+}
+
+#[test]
+fn test_stakes_need_player_auth() {
+    let (env, client, game_id) = setup();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.mint_points(&player1, &100);
+
+    // Only the player putting points at stake signs, for this game and session
+    client.start_game(&game_id, &1, &player1, &player2, &40, &0);
+    let auths = env.auths();
+    assert_eq!(
+        auths.iter().find(|(address, _)| *address == player1).unwrap().1,
+        AuthorizedInvocation {
+            function: AuthorizedFunction::Contract((
+                client.address.clone(),
+                Symbol::new(&env, "start_game"),
+                (game_id.clone(), 1u32, 40i128).into_val(&env),
+            )),
+            sub_invocations: std::vec![],
+        }
+    );
+    assert!(!auths.iter().any(|(address, _)| *address == player2));
+}
+
+// ============================================================================
+// History Tests
+// ============================================================================
+
+#[test]
+fn test_player_history_and_record() {
+    let (env, client, game_id) = setup();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let partner = Address::generate(&env);
+    client.mint_points(&player1, &100);
+    client.mint_points(&player2, &100);
+
+    client.start_game(&game_id, &1, &player1, &player2, &40, &60);
+    client.end_game(&game_id, &1, &true);
+    client.start_team_game(&game_id, &2, &vec![&env, player2.clone(), partner.clone()], &vec![&env, player1.clone()]);
+    client.end_game(&game_id, &2, &true);
+    client.start_game(&game_id, &3, &player1, &player2, &0, &0);
+
+    let record = client.get_player_record(&player1);
+    assert_eq!((record.sessions, record.wins, record.losses), (3, 1, 1));
+    assert_eq!((record.points_won, record.points_lost), (60, 0));
+    assert_eq!(client.get_player_record(&player2).points_lost, 60);
+    assert_eq!(client.get_player_record(&partner).wins, 1);
+    assert_eq!(client.get_player_record(&Address::generate(&env)), PlayerRecord::default());
+
+    let page = client.get_sessions_for_player(&player1, &0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().session_id, 2);
+    let page = client.get_sessions_for_player(&player1, &2, &2);
+    assert_eq!(page.len(), 1);
+    assert!(!page.get(0).unwrap().ended);
+    assert!(client.get_sessions_for_player(&player1, &3, &10).is_empty());
+    assert_eq!(client.get_sessions_for_player(&partner, &0, &10).len(), 1);
+}
+
+#[test]
+fn test_session_pages_bounded() {
+    let (env, client, game_id) = setup();
+    let player = Address::generate(&env);
+    let total = MAX_PAGE_SIZE + 5;
+    for session_id in 0..total {
+        client.start_game(&game_id, &session_id, &player, &Address::generate(&env), &0, &0);
+    }
+
+    // A full page stays within the footprint limit
+    let page = client.get_sessions_for_player(&player, &0, &100);
+    assert_eq!(page.len(), MAX_PAGE_SIZE);
+    assert_eq!(page.get(MAX_PAGE_SIZE - 1).unwrap().session_id, MAX_PAGE_SIZE - 1);
+    let page = client.get_sessions_for_player(&player, &MAX_PAGE_SIZE, &100);
+    assert_eq!(page.len(), 5);
+    assert_eq!(page.get(0).unwrap().session_id, MAX_PAGE_SIZE);
+    assert_eq!(client.get_sessions_for_player(&player, &10, &3).len(), 3);
+
+    // Empty past the end or with no limit
+    assert!(client.get_sessions_for_player(&player, &0, &0).is_empty());
+    assert!(client.get_sessions_for_player(&player, &total, &10).is_empty());
+    assert!(client.get_sessions_for_player(&player, &u32::MAX, &u32::MAX).is_empty());
+    assert!(client.get_sessions_for_player(&Address::generate(&env), &0, &10).is_empty());
+}
+
+#[test]
+fn test_missing_history_entry_skipped() {
+    let (env, client, game_id) = setup();
+    let player = Address::generate(&env);
+    for session_id in 1..=3 {
+        client.start_game(&game_id, &session_id, &player, &Address::generate(&env), &0, &0);
+    }
+
+    env.as_contract(&client.address, || {
+        env.storage().persistent().remove(&DataKey::PlayerSession(player.clone(), 1));
+    });
+    let page = client.get_sessions_for_player(&player, &0, &3);
+    assert_eq!(page.len(), 2);
+    assert_eq!((page.get(0).unwrap().session_id, page.get(1).unwrap().session_id), (1, 3));
+}
+
+#[test]
+fn test_records_after_win_and_loss() {
+    let (env, client, game_id) = setup();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.mint_points(&player1, &100);
+    client.mint_points(&player2, &100);
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &30);
+    client.end_game(&game_id, &1, &false);
+    client.start_game(&game_id, &2, &player1, &player2, &20, &5);
+    client.end_game(&game_id, &2, &true);
+
+    let record = client.get_player_record(&player1);
+    assert_eq!((record.sessions, record.wins, record.losses), (2, 1, 1));
+    assert_eq!((record.points_won, record.points_lost), (5, 10));
+    let record = client.get_player_record(&player2);
+    assert_eq!((record.sessions, record.wins, record.losses), (2, 1, 1));
+    assert_eq!((record.points_won, record.points_lost), (10, 5));
+    assert_eq!((client.get_points(&player1), client.get_points(&player2)), (95, 105));
+
+    let page = client.get_sessions_for_player(&player2, &0, &10);
+    assert_eq!(page.get(0).unwrap().player1_won, Some(false));
+    assert_eq!(page.get(1).unwrap().player1_won, Some(true));
+}
+
+#[test]
+fn test_team_sessions_in_every_history() {
+    let (env, client, game_id) = setup();
+    let team1 = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let team2 = vec![&env, Address::generate(&env), Address::generate(&env)];
+    client.start_team_game(&game_id, &1, &team1, &team2);
+
+    for player in team1.iter().chain(team2.iter()) {
+        let page = client.get_sessions_for_player(&player, &0, &10);
+        assert_eq!(page.len(), 1);
+        let session = page.get(0).unwrap();
+        assert_eq!((session.team1.clone(), session.team2.clone()), (team1.clone(), team2.clone()));
+        assert_eq!((session.player1_points, session.player2_points), (0, 0));
+        assert!(!session.ended);
+    }
+
+    client.end_game(&game_id, &1, &false);
+    for player in team1.iter() {
+        assert_eq!(client.get_player_record(&player).losses, 1);
+    }
+    for player in team2.iter() {
+        let record = client.get_player_record(&player);
+        assert_eq!((record.sessions, record.wins, record.points_won), (1, 1, 0));
+    }
+}
@@ -11,10 +11,29 @@
 //! - Round win: Hunter catches Prey OR Prey survives 10 turns
 //! - Match: 4 rounds (2 as hunter each), player with most points wins
 //! - Power Search: Hunter can search ALL adjacent jungle tiles (2 uses per round)
+//! - Flare: Hunter searches any set of up to 9 tiles given as a 64-bit mask (1 use per round)
+//! - Traps: Hunter hides up to 2 traps per round and springs one if the Prey's public trail crossed it
+//! - Decoys: hidden Prey can register ghost commitments that absorb a search before expiring
+//! - Burn: Hunter turns an adjacent jungle tile into plains for the rest of the round (2 per round)
+//! - Scent: a hidden Prey must reveal where it was a few turns ago, proven against its current commitment
+//! - Shrinking zone: late in a round the outer rings become hazardous to the Prey
+//! - Escape mode: the Prey can instead win by reaching an extraction tile; surviving becomes a draw or a loss
+//! - Collectibles: the Prey can instead win by collecting K of N item tiles it secretly picks among random candidates each round
+//! - Team modes: 2v1 and 2v2, with team members alternating turns and sharing ability pools
+//! - Sonar: Hunter pings a Manhattan radius; the Prey proves whether it is inside (2 uses per round)
+//! - Energy economy: abilities can instead draw from a per-role pool that regenerates each turn
+//! - Audit: after a round the Prey can reveal its hidden positions; staked matches require it
+//! - Session keys: players can let a per-game key sign their moves until it expires
+//! - Channel play: turns can be exchanged off-chain as signed states, settled or disputed on-chain
+//! - Staked play: map and spawns can come from a two-party commit-reveal seed
+//! - Map draft: players can ban maps from a drawn pool before the match
+//! - Hidden spawn: the Prey can start the round already hidden in jungle
+//! - Hidden hunter: the Hunter can also move through jungle under a commitment and search by proof
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, vec, Address, Bytes, BytesN, Env,
-    IntoVal, Symbol, Val, Vec,
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, vec,
+    xdr::{ScErrorType, ToXdr},
+    Address, Bytes, BytesN, Env, Vec,
 };
 
 // ============================================================================
@@ -27,6 +46,33 @@ mod ultrahonk_contract {
 
 pub const ULTRAHONK_CONTRACT_ADDRESS: &str = "CB4QQWCTM4GHUQXADL72GCAIY4XOAD7CVFLVU7ZSOJ4SI3MDXSTYYYUN";
 
+// ============================================================================
+// External Contract Interface (Game Hub)
+// ============================================================================
+
+/// The calls zk-hunt makes on a Game Hub with the standard interface
+/// (`HubInterface::V1`).
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+    fn end_game(env: Env, session_id: u32, player1_won: bool);
+}
+
+/// The calls `contracts/game-hub` (`HubInterface::V2`) adds or changes.
+#[contractclient(name = "GameHubV2Client")]
+pub trait GameHubV2 {
+    fn start_team_game(env: Env, game_id: Address, session_id: u32, team1: Vec<Address>, team2: Vec<Address>);
+    fn end_game(env: Env, game_id: Address, session_id: u32, player1_won: bool);
+}
+
 // ============================================================================
 // Constants
 // ============================================================================
@@ -40,6 +86,51 @@ const MAX_TURNS: u32 = 10;
 /// Initial power searches for hunter
 const POWER_SEARCHES_INITIAL: u32 = 2;
 
+/// Initial sonar pings for hunter
+const SONAR_USES_INITIAL: u32 = 2;
+
+/// Largest useful sonar radius (the Manhattan diameter of the 8x8 board).
+const MAX_SONAR_RADIUS: u32 = 14;
+
+/// Initial flares for hunter
+const FLARE_USES_INITIAL: u32 = 1;
+
+/// Most tiles a single flare may light up (a row, a column or a 3x3 block).
+const MAX_FLARE_TILES: u32 = 9;
+
+/// Traps the hunter may place per round
+const MAX_TRAPS: u32 = 2;
+
+/// Decoys the prey may have active at once
+const MAX_DECOYS: u32 = 2;
+
+/// Turns a decoy stays active after being placed
+const DECOY_LIFETIME_TURNS: u32 = 3;
+
+/// Jungle tiles the hunter may burn per round
+const MAX_BURNS: u32 = 2;
+
+/// Most item tiles a round can place
+const MAX_ITEMS: u32 = 8;
+
+/// Candidate tiles drawn per item; the prey secretly picks which hold items
+const ITEM_CANDIDATES_PER_ITEM: u32 = 2;
+
+/// Longest scent delay (fixed by the `scent_reveal` circuit's path length)
+const MAX_SCENT_DELAY: u32 = 4;
+
+/// Ledgers the prey has to answer a sonar ping or burn (~10 minutes)
+const RESPONSE_TIMEOUT_LEDGERS: u32 = 120;
+
+/// Ledgers the prey has to reveal a round transcript in a staked audited match (~1 hour)
+const AUDIT_TIMEOUT_LEDGERS: u32 = 720;
+
+/// Ledgers the prey has to submit an optimistic round's move proofs (~1 hour)
+const MOVE_PROOF_TIMEOUT_LEDGERS: u32 = 720;
+
+/// Ledgers the hunter has to challenge submitted move proofs (~10 minutes)
+const CHALLENGE_WINDOW_LEDGERS: u32 = 120;
+
 /// Number of rounds each player is hunter
 const ROUNDS_PER_SIDE: u32 = 1; //TODO: change
 
@@ -49,9 +140,50 @@ const TOTAL_ROUNDS: u32 = ROUNDS_PER_SIDE * 2;
 /// Minimum Manhattan distance between hunter and prey spawn positions.
 const MIN_SPAWN_DISTANCE: u32 = 3;
 
+/// Minimum Manhattan distance between a prey spawn and the map's extraction tiles.
+const MIN_EXTRACTION_DISTANCE: u32 = 3;
+
+/// Ledgers both players have to reveal their seeds in a commit-reveal game (~1 hour).
+const SEED_REVEAL_TIMEOUT_LEDGERS: u32 = 720;
+
+/// Maps drawn into the pool for a map draft.
+const DRAFT_POOL_SIZE: u32 = 5;
+
+/// Ledgers a player has to make their ban during a map draft (~10 minutes).
+const DRAFT_TURN_LEDGERS: u32 = 120;
+
+/// Ledgers a disputed player has to answer a posted channel state (~10 minutes).
+const DISPUTE_TIMEOUT_LEDGERS: u32 = 120;
+
 /// Number of available maps.
 pub(crate) const MAP_COUNT: u32 = 20;
 
+/// Extraction tiles of each map for escape mode, as masks (bit y*8 + x).
+/// Each map has one jungle tile (reached hidden, `prey_extract_hidden`) and
+/// one plains tile (reached in the open), far apart on the map's own layout.
+pub(crate) const EXTRACTION_TILES: [u64; 20] = [
+    0x2000_0000_0000_0001, // Map 0: (5,7) jungle + (0,0) plains
+    0x8000_0000_0000_0400, // Map 1: (2,1) jungle + (7,7) plains
+    0x8000_0000_0000_0001, // Map 2: (0,0) jungle + (7,7) plains
+    0x0040_0000_0000_0001, // Map 3: (0,0) jungle + (6,6) plains
+    0x8000_0000_0000_0008, // Map 4: (3,0) jungle + (7,7) plains
+    0x8000_0000_0000_0001, // Map 5: (0,0) jungle + (7,7) plains
+    0x8000_0000_0000_0008, // Map 6: (3,0) jungle + (7,7) plains
+    0x8000_0000_0000_0100, // Map 7: (0,1) jungle + (7,7) plains
+    0x8000_0000_0000_0002, // Map 8: (1,0) jungle + (7,7) plains
+    0x8000_0000_0000_0002, // Map 9: (1,0) jungle + (7,7) plains
+    0x8000_0000_0000_0008, // Map 10: (3,0) jungle + (7,7) plains
+    0x0001_0000_0000_0080, // Map 11: (0,6) jungle + (7,0) plains
+    0x8000_0000_0000_0004, // Map 12: (2,0) jungle + (7,7) plains
+    0x8000_0000_0000_0002, // Map 13: (1,0) jungle + (7,7) plains
+    0x0000_8000_0000_0001, // Map 14: (0,0) jungle + (7,5) plains
+    0x8000_0000_0000_0002, // Map 15: (1,0) jungle + (7,7) plains
+    0x0100_0000_0000_8000, // Map 16: (0,7) jungle + (7,1) plains
+    0x8000_0000_0000_0004, // Map 17: (2,0) jungle + (7,7) plains
+    0x0100_0000_0000_0080, // Map 18: (7,0) jungle + (0,7) plains
+    0x8000_0000_0000_0200, // Map 19: (1,1) jungle + (7,7) plains
+];
+
 /// Pool of 20 balanced 8x8 maps as flat [u8; 64] arrays.
 /// Index = y*8 + x. 1 = jungle, 0 = plains.
 pub(crate) const MAPS: [[u8; 64]; 20] = [
@@ -126,6 +258,52 @@ pub enum Error {
     EmpOutOfRange = 19,
     NoDashes = 20,
     PreyFrozen = 21,
+    SeedRequired = 22,
+    SeedMismatch = 23,
+    SeedAlreadyRevealed = 24,
+    SeedNotRevealed = 25,
+    DeadlineNotReached = 26,
+    NotYourTurn = 27,
+    MapNotInPool = 28,
+    RuleDisabled = 29,
+    NotEnoughEnergy = 30,
+    NoSonar = 31,
+    InvalidRadius = 32,
+    NoFlare = 33,
+    InvalidMask = 34,
+    NoTraps = 35,
+    TrapNotFound = 36,
+    TrapMismatch = 37,
+    TooManyDecoys = 38,
+    DecoyNotFound = 39,
+    NoBurns = 40,
+    ZoneProofRequired = 41,
+    InvalidConfig = 42,
+    NotItemTile = 43,
+    TeamFull = 44,
+    AlreadySeated = 45,
+    HunterHidden = 46,
+    HunterNotHidden = 47,
+    ScentRevealRequired = 48,
+    NoScentDue = 49,
+    TranscriptNotFound = 50,
+    AlreadyAudited = 51,
+    SessionKeyExpired = 52,
+    ChannelNotOpen = 53,
+    ChannelAlreadyOpen = 54,
+    StaleUpdate = 55,
+    NoDispute = 56,
+    DisputeAnswered = 57,
+    ProofsAlreadySubmitted = 58,
+    ProofsNotSubmitted = 59,
+    StepNotFound = 60,
+    ChallengeWindowClosed = 61,
+    TrapProofRequired = 62,
+    NoTrapCheck = 63,
+    ItemsNotCommitted = 64,
+    ItemsAlreadyCommitted = 65,
+    ChannelStateMismatch = 66,
+    ItemAlreadyClaimed = 67,
 }
 
 // ============================================================================
@@ -141,6 +319,374 @@ pub enum GamePhase {
     PreyTurn = 2,
     SearchPending = 3,
     Ended = 4,
+    SeedReveal = 5,
+    MapDraft = 6,
+    PreySpawn = 7,
+    SonarPending = 8,
+    FlarePending = 9,
+    BurnPending = 10,
+    /// Staked audited match: the prey must reveal the finished round's transcript.
+    AuditPending = 11,
+    /// Optimistic round: waiting for the prey's move proofs, then the hunter's
+    /// challenge window.
+    MovesPending = 12,
+    /// The prey survived the turn limit with traps still hidden: the hunter
+    /// may reveal them before the round is scored.
+    TrapReveal = 13,
+}
+
+/// Optional rules picked by the creator via `create_game_with_config`.
+/// `create_game` uses the default (all rules off).
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameConfig {
+    /// Map and spawns come from a commit-reveal seed both players contribute to.
+    pub commit_reveal: bool,
+    /// Players alternately ban maps from a drawn pool; the survivors are
+    /// played in order, one per round.
+    pub map_draft: bool,
+    /// Each round opens with the Prey committing to a hidden jungle spawn
+    /// (`prey_spawn_hidden`) instead of a public plains spawn.
+    pub hidden_spawn: bool,
+    /// The Hunter may move and then search from the new tile in one turn
+    /// (`hunter_move_and_search`).
+    pub compound_turn: bool,
+    /// The Hunter may hide in jungle too (`hunter_enter_jungle`), searching
+    /// with adjacency proofs while hidden (`hunter_search_hidden`).
+    pub hidden_hunter: bool,
+    /// From this turn on, one more outer ring of the board becomes hazardous
+    /// each turn (down to the central 2x2). Off while 0.
+    pub zone_start_turn: u32,
+    /// The hidden Prey must reveal the tile it held this many moves ago
+    /// before acting (`prey_reveal_scent`). Off while 0.
+    pub scent_delay: u32,
+    /// Prey turns an EMP freezes (1 while 0).
+    pub emp_duration: u32,
+    /// Chebyshev range of the EMP from the Hunter (global while 0).
+    pub emp_range: u32,
+    /// Keep the hidden prey's per-turn commitments and archive them when the
+    /// round ends, for `reveal_round`. In commit-reveal matches the reveal is
+    /// required before the next round.
+    pub audit: bool,
+    /// Hidden prey moves (`prey_move_jungle`, `prey_dash_jungle`) post only the
+    /// new commitment. Their proofs come step by step at round end
+    /// (`submit_step_proof`) and the hunter can challenge any step.
+    pub optimistic: bool,
+    /// How the prey wins a round.
+    pub objective: Objective,
+    /// Item tiles (jungle) placed at random each round; off while 0.
+    pub item_count: u32,
+    /// Items the prey must collect to win the round.
+    pub items_required: u32,
+    /// Team sizes; partners seat themselves with `join_team`.
+    pub team_mode: TeamMode,
+    /// Per-role energy pool replacing the fixed ability counters; on when `energy.max > 0`.
+    pub energy: EnergyConfig,
+}
+
+/// Round objective for the prey.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Objective {
+    /// Survive `MAX_TURNS` turns.
+    #[default]
+    Survive = 0,
+    /// Reach an extraction tile; surviving to the turn limit is a draw.
+    EscapeOrDraw = 1,
+    /// Reach an extraction tile; surviving to the turn limit is a hunter win.
+    EscapeOrLose = 2,
+}
+
+/// Team sizes for a match: player 1's team vs player 2's team.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(u32)]
+pub enum TeamMode {
+    #[default]
+    Solo = 0,
+    /// Player 1 and a partner against player 2.
+    TwoVsOne = 1,
+    TwoVsTwo = 2,
+}
+
+/// Energy economy: each role starts a round with `max` energy, regains
+/// `regen` per turn (capped at `max`), and pays the listed cost per action.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EnergyConfig {
+    pub max: u32,
+    pub regen: u32,
+    pub move_cost: u32,
+    pub search_cost: u32,
+    pub power_search_cost: u32,
+    pub emp_cost: u32,
+    pub dash_cost: u32,
+    pub hide_cost: u32,
+    pub sonar_cost: u32,
+    pub flare_cost: u32,
+    pub trap_cost: u32,
+    pub decoy_cost: u32,
+    pub burn_cost: u32,
+}
+
+/// Proof statements beyond the original move/search pair, each verified
+/// against its own VK slot (`DataKey::Vk`).
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Circuit {
+    /// `hidden_spawn`: commitment is a jungle tile far enough from the hunter
+    /// and the extraction tiles.
+    Spawn = 0,
+    /// `jungle_dash`: hidden move of up to 2 tiles through jungle.
+    Dash = 1,
+    /// `sonar_response`: commitment is inside/outside a radius around the hunter.
+    Sonar = 2,
+    /// `flare_response`: commitment is not on any tile of a 64-bit mask.
+    Flare = 3,
+    /// `decoy_discard`: a decoy sits on one of the searched tiles.
+    Decoy = 4,
+    /// `zone_check`: commitment lies inside the safe zone.
+    Zone = 5,
+    /// `extraction`: commitment is on one of the map's extraction tiles.
+    Extraction = 6,
+    /// `item_claim`: commitment is at one of the prey's committed item tiles.
+    Item = 7,
+    /// `hunter_adjacency`: searched tiles are around the hidden hunter's commitment.
+    Adjacency = 8,
+    /// `scent_reveal`: an old commitment opens to a tile a few moves from the current one.
+    Scent = 9,
+    /// `round_audit`: revealed positions and nonces open a round's commitments.
+    Audit = 10,
+    /// `jungle_enter`: a step from a public tile onto adjacent jungle.
+    Enter = 11,
+    /// `jungle_exit`: a public exit tile is within one step of a commitment.
+    Exit = 12,
+}
+
+/// A hunter trap, hidden as `sha256(x || y || salt)` (x, y as big-endian u32)
+/// until the hunter reveals it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trap {
+    pub commitment: BytesN<32>,
+    pub placed_turn: u32,
+    pub revealed: bool,
+}
+
+/// A ghost commitment registered by the hidden prey. Decoys use a tagged hash
+/// (`Poseidon2(x, y, nonce, 1)`), so they can never equal a position commitment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Decoy {
+    pub commitment: BytesN<32>,
+    pub expires_turn: u32,
+}
+
+/// A team member waiting for their turn. Team members alternate turns: at the
+/// end of every turn the benched member swaps into the active hunter / prey slot.
+/// Hunter abilities only reach the active prey: a benched prey hidden in jungle
+/// cannot be searched, flared, pinged or burned until it rotates back in, while
+/// a visible benched prey is still caught by a hunter stepping onto it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BenchMember {
+    pub player: Address,
+    pub is_hunter: bool,
+    pub x: u32,
+    pub y: u32,
+    pub is_hidden: bool,
+    pub commitment: BytesN<32>,
+    /// Frozen turns a benched prey still owes; they resume when it returns.
+    pub frozen_turns: u32,
+}
+
+/// Rosters of a team game. The primary `Game` slots hold the active members;
+/// abilities and their pools are shared by the whole team.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Teams {
+    /// Partners of player 1 (not including player 1).
+    pub team1: Vec<Address>,
+    /// Partners of player 2 (not including player 2).
+    pub team2: Vec<Address>,
+    pub bench: Vec<BenchMember>,
+}
+
+/// Published when the prey claims an item. The item is named by its
+/// nullifier rather than its tile, so a hidden prey's tile stays secret.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ItemCollected {
+    #[topic]
+    pub session_id: u32,
+    pub nullifier: BytesN<32>,
+    pub items_collected: u32,
+}
+
+/// A tile the prey ended a turn on: seen while visible, or revealed later by scent.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrailStep {
+    pub turn: u32,
+    pub x: u32,
+    pub y: u32,
+}
+
+/// The hidden prey's commitment at the end of a turn.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentRecord {
+    pub turn: u32,
+    pub commitment: BytesN<32>,
+}
+
+/// Outcome of a round transcript audit.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AuditResult {
+    Pending = 0,
+    Passed = 1,
+    /// Revealed a non-jungle tile, or missed the deadline in a staked match.
+    Failed = 2,
+}
+
+/// The hidden prey's commitments of a finished round, stored per round
+/// under `DataKey::Transcript`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundTranscript {
+    pub hunter: Address,
+    pub prey: Address,
+    pub map_index: u32,
+    pub commitments: Vec<CommitmentRecord>,
+    /// The prey's public positions this round, which the revealed path must join.
+    pub trail: Vec<TrailStep>,
+    /// Turns the prey dashed on (a revealed step may cover 2 tiles).
+    pub dash_turns: Vec<u32>,
+    /// Who scored the round; None for a draw.
+    pub round_winner: Option<Address>,
+    /// Reveal deadline when the next round waits for it (staked matches).
+    pub deadline: u32,
+    pub result: AuditResult,
+}
+
+/// Published when a round transcript is audited.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundAudited {
+    #[topic]
+    pub session_id: u32,
+    pub round: u32,
+    pub passed: bool,
+}
+
+/// Published when the Game Hub rejects a notification with a contract
+/// error. Retrying would fail the same way, so the notification is dropped.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HubNotificationDropped {
+    #[topic]
+    pub session_id: u32,
+    pub notification: HubNotification,
+}
+
+/// Published when the hunter challenges an optimistic move.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StepChallenged {
+    #[topic]
+    pub session_id: u32,
+    pub round: u32,
+    pub step: u32,
+    /// The step's proof failed, so the round went to the hunter.
+    pub upheld: bool,
+}
+
+/// A hidden prey move posted without a proof (`optimistic` rule).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoveStep {
+    pub old_commitment: BytesN<32>,
+    pub new_commitment: BytesN<32>,
+    /// Burned tiles when the move was made, a public input of its proof.
+    pub burned_tiles: u64,
+    /// Proven by `jungle_dash` rather than `jungle_move`.
+    pub dash: bool,
+}
+
+/// A finished optimistic round waiting on its move proofs and challenge window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoveBatch {
+    pub round: u32,
+    pub hunter: Address,
+    pub prey: Address,
+    pub map_index: u32,
+    pub steps: Vec<MoveStep>,
+    /// Bit per step whose proof has been submitted (`DataKey::StepProof`).
+    pub proven_steps: u32,
+    pub round_winner: Option<Address>,
+    /// Proof submission deadline, then the end of the challenge window.
+    pub deadline: u32,
+}
+
+/// A key a player registered to sign its game actions in one session, so the
+/// main wallet does not have to sign every move.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionKey {
+    pub key: Address,
+    /// Last ledger the key is accepted on.
+    pub expires_ledger: u32,
+}
+
+/// A game state both players sign off-chain in channel mode. The signed
+/// message is the XDR encoding of the whole update.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChannelUpdate {
+    /// Contract the update is for, so signatures cannot be replayed on
+    /// another deployment.
+    pub contract: Address,
+    pub session_id: u32,
+    /// Increases with every exchanged state; only newer states are accepted on-chain.
+    pub seq: u32,
+    pub game: Game,
+}
+
+/// Channel-mode bookkeeping for a session: the players' ed25519 keys and the
+/// latest state posted on-chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Channel {
+    pub player1_key: Option<BytesN<32>>,
+    pub player2_key: Option<BytesN<32>>,
+    /// Sequence number of the latest state on-chain: the last posted update,
+    /// plus one for every on-chain game action since.
+    pub seq: u32,
+    /// Player who posted the pending dispute; the opponent has to answer.
+    pub challenger: Option<Address>,
+    pub dispute_deadline: u32,
+    /// Round, turn and phase of the disputed state; any move changes them.
+    pub disputed_round: u32,
+    pub disputed_turn: u32,
+    pub disputed_phase: GamePhase,
+}
+
+/// Seed commitments for a commit-reveal game.
+/// Commitments are `sha256(seed)`; the match seed is `sha256(player1_seed || player2_seed)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeedState {
+    pub player1_hash: BytesN<32>,
+    pub player2_hash: BytesN<32>,
+    pub player1_seed: Option<BytesN<32>>,
+    pub player2_seed: Option<BytesN<32>>,
+    pub reveal_deadline: u32,
+    pub match_seed: Option<BytesN<32>>,
 }
 
 #[contracttype]
@@ -154,6 +700,9 @@ pub struct Game {
     pub prey_y: u32,
     pub prey_is_hidden: bool,
     pub prey_commitment: BytesN<32>,
+    /// While hidden, `hunter_x`/`hunter_y` are the Hunter's last known position.
+    pub hunter_is_hidden: bool,
+    pub hunter_commitment: BytesN<32>,
     pub phase: GamePhase,
     pub turn_number: u32,
     pub power_searches_remaining: u32,
@@ -169,7 +718,59 @@ pub struct Game {
     pub map_index: u32,
     pub emp_uses_remaining: u32,
     pub prey_is_frozen: bool,
+    /// Prey turns still to be skipped while frozen.
+    pub prey_frozen_turns: u32,
     pub prey_dash_remaining: u32,
+    pub config: GameConfig,
+    pub draft_pool: Vec<u32>,
+    pub draft_deadline: u32,
+    pub hunter_energy: u32,
+    pub prey_energy: u32,
+    pub sonar_uses_remaining: u32,
+    /// Radius of the pending (or last answered) sonar ping.
+    pub sonar_radius: u32,
+    /// Answer to the last sonar ping this round: inside the radius or not.
+    pub last_sonar_inside: Option<bool>,
+    /// Ledger by which the prey must answer a pending flare, sonar ping or burn.
+    pub response_deadline: u32,
+    pub flare_uses_remaining: u32,
+    /// Tiles lit by the pending flare (bit `y * 8 + x`).
+    pub flare_mask: u64,
+    /// Traps placed this round (at most `MAX_TRAPS`).
+    pub traps: Vec<Trap>,
+    /// Revealed trap tiles the hidden prey has yet to prove it is not on (bit `y * 8 + x`).
+    pub trap_check_mask: u64,
+    /// Public positions of the prey this round (a public spawn as turn 0),
+    /// checked when a trap is revealed or a transcript is audited.
+    pub prey_trail: Vec<TrailStep>,
+    /// Active decoys (at most `MAX_DECOYS`), dropped once `turn_number` reaches `expires_turn`.
+    pub decoys: Vec<Decoy>,
+    /// Jungle tiles burned to plains this round (bit `y * 8 + x`).
+    pub burned_tiles: u64,
+    /// Last turn the hidden prey proved it is inside the safe zone.
+    pub zone_proven_turn: u32,
+    /// Commitment the prey proved inside the zone on `zone_proven_turn`.
+    pub zone_commitment: BytesN<32>,
+    /// Commitments of the hidden prey whose scent is not revealed yet, oldest first.
+    pub scent_history: Vec<CommitmentRecord>,
+    /// Hidden positions revealed by scent this round.
+    pub scent_trail: Vec<TrailStep>,
+    /// Every commitment the hidden prey ended a turn on this round, after its
+    /// hidden spawn as turn 0 (`audit` rule).
+    pub commitment_history: Vec<CommitmentRecord>,
+    /// Turns the hidden prey dashed on this round (`audit` rule).
+    pub dash_turns: Vec<u32>,
+    /// Hidden prey moves not proven yet this round (`optimistic` rule).
+    pub pending_steps: Vec<MoveStep>,
+    /// Candidate item tiles this round (bit `y * 8 + x`); `item_count` of them
+    /// hold items, chosen secretly by the prey (`prey_commit_items`).
+    pub item_candidates: u64,
+    /// Prey's commitment to which candidates hold items, once made this round.
+    pub items_commitment: Option<BytesN<32>>,
+    /// Nullifiers of the items claimed this round.
+    pub item_nullifiers: Vec<BytesN<32>>,
+    pub items_collected: u32,
+    pub teams: Teams,
 }
 
 #[contracttype]
@@ -181,6 +782,45 @@ pub enum DataKey {
     SearchVk,
     NextSessionId,
     GameHubAddress,
+    GameHubInterface,
+    Seeds(u32),
+    Vk(Circuit),
+    /// Transcript of a finished round: (session_id, round).
+    Transcript(u32, u32),
+    /// A player's session key for one game: (session_id, player).
+    SessionKey(u32, Address),
+    Channel(u32),
+    MoveBatch(u32),
+    /// Submitted proof of one optimistic step: (session_id, step).
+    StepProof(u32, u32),
+    /// A session's Game Hub notifications that failed, oldest first.
+    PendingHub(u32),
+}
+
+/// Interface of the configured Game Hub.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HubInterface {
+    /// `start_game` and `end_game(session_id, player1_won)`, as deployed hubs
+    /// and `mock-game-hub` implement. Team games are reported with each
+    /// team's first member.
+    V1,
+    /// `contracts/game-hub`: sessions are keyed by game, so `end_game` takes
+    /// `game_id` too, and `start_team_game` reports full rosters.
+    V2,
+}
+
+/// A Game Hub call waiting to be retried.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HubNotification {
+    /// `start_game(session_id, player1, player2)`.
+    Start(u32, Address, Address),
+    /// `start_team_game(session_id, team1, team2)`, or `start_game` with the
+    /// teams' first members on a V1 hub.
+    StartTeams(u32, Vec<Address>, Vec<Address>),
+    /// `end_game(session_id, player1_won)`, with `game_id` first on a V2 hub.
+    End(u32, bool),
 }
 
 // ============================================================================
@@ -215,9 +855,32 @@ impl ZkHuntContract {
             .set(&DataKey::SearchVk, &search_vk);
     }
 
+    /// Set the verification key of an additional circuit (called post-deploy by admin).
+    pub fn set_vk(env: Env, circuit: Circuit, vk: Bytes) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Vk(circuit), &vk);
+    }
+
     /// Set the Game Hub contract address (called post-deploy by admin).
+    /// The hub must have the standard interface (`HubInterface::V1`).
     /// If not set, GameHub notifications are silently skipped (local dev).
     pub fn set_game_hub(env: Env, game_hub: Address) {
+        Self::store_game_hub(env, game_hub, HubInterface::V1);
+    }
+
+    /// Set a Game Hub with the `contracts/game-hub` interface
+    /// (`HubInterface::V2`), called post-deploy by admin.
+    pub fn set_game_hub_v2(env: Env, game_hub: Address) {
+        Self::store_game_hub(env, game_hub, HubInterface::V2);
+    }
+
+    fn store_game_hub(env: Env, game_hub: Address, interface: HubInterface) {
         let admin: Address = env
             .storage()
             .instance()
@@ -228,64 +891,72 @@ impl ZkHuntContract {
         env.storage()
             .instance()
             .set(&DataKey::GameHubAddress, &game_hub);
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubInterface, &interface);
     }
 
     /// Create a new game. Caller becomes the Hunter.
     pub fn create_game(env: Env, hunter: Address) -> u32 {
         hunter.require_auth();
 
-        let session_id: u32 = env
-            .storage()
-            .instance()
-            .get(&DataKey::NextSessionId)
-            .unwrap_or(1);
-        env.storage()
-            .instance()
-            .set(&DataKey::NextSessionId, &(session_id + 1));
-
-        let map_index = select_random_map(&env);
-        let (hx, hy, px, py) = random_starting_positions(&env, map_index);
-
-        let game = Game {
-            hunter: hunter.clone(),
-            prey: hunter.clone(), // placeholder until prey joins
-            hunter_x: hx,
-            hunter_y: hy,
-            prey_x: px,
-            prey_y: py,
-            prey_is_hidden: false,
-            prey_commitment: BytesN::from_array(&env, &[0u8; 32]),
-            phase: GamePhase::WaitingForPlayer2,
-            turn_number: 0,
-            power_searches_remaining: POWER_SEARCHES_INITIAL,
-            searched_tiles_x: vec![&env],
-            searched_tiles_y: vec![&env],
-            winner: None,
-            player1: hunter.clone(),
-            player2: hunter.clone(), // placeholder until prey joins
-            round: 1,
-            total_rounds: TOTAL_ROUNDS,
-            player1_score: 0,
-            player2_score: 0,
-            map_index,
-            emp_uses_remaining: 1,
-            prey_is_frozen: false,
-            prey_dash_remaining: 2,
-        };
+        new_game(&env, hunter, GameConfig::default(), None)
+    }
 
-        let key = DataKey::Game(session_id);
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    /// Create a new game with optional rules. Caller becomes the Hunter.
+    ///
+    /// With `config.commit_reveal`, `seed_hash` must be `sha256(seed)` for a
+    /// secret 32-byte seed the hunter reveals later via `reveal_seed`.
+    pub fn create_game_with_config(
+        env: Env,
+        hunter: Address,
+        config: GameConfig,
+        seed_hash: Option<BytesN<32>>,
+    ) -> Result<u32, Error> {
+        hunter.require_auth();
+
+        if config.commit_reveal && seed_hash.is_none() {
+            return Err(Error::SeedRequired);
+        }
 
-        session_id
+        if config.item_count > MAX_ITEMS
+            || config.items_required > config.item_count
+            || (config.item_count > 0 && config.items_required == 0)
+            || config.scent_delay > MAX_SCENT_DELAY
+            // Rotating team members would interleave commitment histories
+            || ((config.scent_delay > 0 || config.audit || config.optimistic)
+                && config.team_mode != TeamMode::Solo)
+            // Both would hold the round open for the prey
+            || (config.audit && config.optimistic)
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        Ok(new_game(&env, hunter, config, seed_hash))
     }
 
     /// Prey joins an existing game.
     pub fn join_game(env: Env, session_id: u32, prey: Address) -> Result<(), Error> {
         prey.require_auth();
 
+        join(&env, session_id, prey, None)
+    }
+
+    /// Prey joins a commit-reveal game, committing `sha256(seed)`.
+    pub fn join_game_with_seed(
+        env: Env,
+        session_id: u32,
+        prey: Address,
+        seed_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        prey.require_auth();
+
+        join(&env, session_id, prey, Some(seed_hash))
+    }
+
+    /// A team partner takes a seat before the match starts: team 1 plays with
+    /// player 1, team 2 with player 2. The match starts once every seat is filled.
+    pub fn join_team(env: Env, session_id: u32, player: Address, team: u32) -> Result<(), Error> {
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -297,34 +968,64 @@ impl ZkHuntContract {
             return Err(Error::WrongPhase);
         }
 
-        game.prey = prey.clone();
-        game.player2 = prey;
-        game.phase = GamePhase::HunterTurn;
-        game.turn_number = 1;
+        player.require_auth();
+
+        if player == game.player1
+            || player == game.player2
+            || game.teams.team1.contains(&player)
+            || game.teams.team2.contains(&player)
+        {
+            return Err(Error::AlreadySeated);
+        }
+
+        let team2_seats = match game.config.team_mode {
+            TeamMode::Solo => return Err(Error::RuleDisabled),
+            TeamMode::TwoVsOne => 0,
+            TeamMode::TwoVsTwo => 1,
+        };
+        let (roster, seats) = match team {
+            1 => (&mut game.teams.team1, 1),
+            2 => (&mut game.teams.team2, team2_seats),
+            _ => return Err(Error::TeamFull),
+        };
+        if roster.len() >= seats {
+            return Err(Error::TeamFull);
+        }
+        roster.push_back(player.clone());
+
+        // Player 1 hunts first, so team 1's partner starts as the benched hunter
+        let is_hunter = team == 1;
+        let (x, y) = random_bench_spawn(&env, &game, is_hunter, game.teams.bench.len());
+        game.teams.bench.push_back(BenchMember {
+            player,
+            is_hunter,
+            x,
+            y,
+            is_hidden: false,
+            commitment: BytesN::from_array(&env, &[0u8; 32]),
+            frozen_turns: 0,
+        });
+
+        start_if_seated(&env, session_id, &mut game);
 
         env.storage().temporary().set(&key, &game);
         env.storage()
             .temporary()
             .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
-        // Notify Game Hub that a game session started
-        notify_game_hub_start(
-            &env,
-            session_id,
-            game.player1.clone(),
-            game.player2.clone(),
-        );
-
         Ok(())
     }
 
-    /// Hunter moves to an adjacent tile (public movement).
-    pub fn hunter_move(
+    /// Reveal a committed seed. Once both seeds are in, the match seed picks
+    /// the map and spawns and the first round starts.
+    pub fn reveal_seed(
         env: Env,
         session_id: u32,
-        x: u32,
-        y: u32,
+        player: Address,
+        seed: BytesN<32>,
     ) -> Result<(), Error> {
+        player.require_auth();
+
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -332,33 +1033,55 @@ impl ZkHuntContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.phase != GamePhase::HunterTurn {
+        if game.phase != GamePhase::SeedReveal {
             return Err(Error::WrongPhase);
         }
 
-        game.hunter.require_auth();
+        let seeds_key = DataKey::Seeds(session_id);
+        let mut seeds: SeedState = env
+            .storage()
+            .temporary()
+            .get(&seeds_key)
+            .ok_or(Error::WrongPhase)?;
+        let seed_hash = sha256_bytes(&env, &Bytes::from_array(&env, &seed.to_array()));
 
-        if x >= 8 || y >= 8 {
-            return Err(Error::OutOfBounds);
+        if player == game.player1 {
+            if seeds.player1_seed.is_some() {
+                return Err(Error::SeedAlreadyRevealed);
+            }
+            if seed_hash != seeds.player1_hash {
+                return Err(Error::SeedMismatch);
+            }
+            seeds.player1_seed = Some(seed);
+        } else if player == game.player2 {
+            if seeds.player2_seed.is_some() {
+                return Err(Error::SeedAlreadyRevealed);
+            }
+            if seed_hash != seeds.player2_hash {
+                return Err(Error::SeedMismatch);
+            }
+            seeds.player2_seed = Some(seed);
+        } else {
+            return Err(Error::NotPlayer);
         }
 
-        let dx = abs_diff(x, game.hunter_x);
-        let dy = abs_diff(y, game.hunter_y);
-        if dx + dy > 1 {
-            return Err(Error::InvalidMove);
+        if let (Some(s1), Some(s2)) = (&seeds.player1_seed, &seeds.player2_seed) {
+            let mut combined = Bytes::from_array(&env, &s1.to_array());
+            combined.append(&Bytes::from_array(&env, &s2.to_array()));
+            seeds.match_seed = Some(sha256_bytes(&env, &combined));
         }
 
-        game.hunter_x = x;
-        game.hunter_y = y;
+        env.storage().temporary().set(&seeds_key, &seeds);
+        env.storage()
+            .temporary()
+            .extend_ttl(&seeds_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
-        // Check if hunter stepped on visible prey
-        if !game.prey_is_hidden && game.hunter_x == game.prey_x && game.hunter_y == game.prey_y {
-            end_round(&env, &key, &mut game, true);
-            return Ok(());
+        if seeds.match_seed.is_some() {
+            seed_round_prng(&env, session_id, game.round);
+            begin_match(&env, &mut game);
         }
 
-        game.phase = GamePhase::PreyTurn;
-
+        bump_channel_seq(&env, session_id);
         env.storage().temporary().set(&key, &game);
         env.storage()
             .temporary()
@@ -367,13 +1090,17 @@ impl ZkHuntContract {
         Ok(())
     }
 
-    /// Hunter searches one adjacent jungle tile for the Prey.
-    pub fn hunter_search(
+    /// Claim the match when the opponent failed to reveal their seed in time.
+    /// Only a player who revealed can claim; they win the match outright.
+    /// If neither player revealed, either one can call it to cancel the match,
+    /// which ends as a 0-0 draw.
+    pub fn claim_reveal_timeout(
         env: Env,
         session_id: u32,
-        x: u32,
-        y: u32,
+        player: Address,
     ) -> Result<(), Error> {
+        player.require_auth();
+
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -381,35 +1108,82 @@ impl ZkHuntContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.phase != GamePhase::HunterTurn {
+        if game.phase != GamePhase::SeedReveal {
             return Err(Error::WrongPhase);
         }
 
-        game.hunter.require_auth();
-
-        if !game.prey_is_hidden {
-            return Err(Error::PreyNotHidden);
+        let seeds: SeedState = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Seeds(session_id))
+            .ok_or(Error::WrongPhase)?;
+        if env.ledger().sequence() <= seeds.reveal_deadline {
+            return Err(Error::DeadlineNotReached);
         }
 
-        if x >= 8 || y >= 8 {
-            return Err(Error::OutOfBounds);
+        let (revealed, opponent_revealed) = if player == game.player1 {
+            (seeds.player1_seed.is_some(), seeds.player2_seed.is_some())
+        } else if player == game.player2 {
+            (seeds.player2_seed.is_some(), seeds.player1_seed.is_some())
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if !revealed {
+            if opponent_revealed {
+                return Err(Error::SeedNotRevealed);
+            }
+            finish_match(&env, &key, &mut game);
+            return Ok(());
         }
 
-        let dx = abs_diff(x, game.hunter_x);
-        let dy = abs_diff(y, game.hunter_y);
-        if dx > 1 || dy > 1 {
-            return Err(Error::InvalidMove);
+        let player1_won = player == game.player1;
+        game.winner = Some(player);
+        game.phase = GamePhase::Ended;
+        bump_channel_seq(&env, session_id);
+        env.storage().temporary().set(&key, &game);
+
+        notify_game_hub_end(&env, session_id, player1_won);
+
+        Ok(())
+    }
+
+    /// Ban a map from the draft pool. Players alternate, player2 first.
+    /// Once only `total_rounds` maps remain, they are played in order.
+    pub fn ban_map(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        map_index: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::MapDraft {
+            return Err(Error::WrongPhase);
         }
 
-        let idx = (y * 8 + x) as usize;
-        if MAPS[game.map_index as usize][idx] == 0 {
-            return Err(Error::NotJungle);
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if player != draft_player(&game) {
+            return Err(Error::NotYourTurn);
         }
 
-        game.searched_tiles_x = vec![&env, x];
-        game.searched_tiles_y = vec![&env, y];
-        game.phase = GamePhase::SearchPending;
+        let pos = game
+            .draft_pool
+            .first_index_of(map_index)
+            .ok_or(Error::MapNotInPool)?;
+        game.draft_pool.remove(pos);
+
+        advance_draft(&env, session_id, &mut game);
 
+        bump_channel_seq(&env, session_id);
         env.storage().temporary().set(&key, &game);
         env.storage()
             .temporary()
@@ -418,8 +1192,9 @@ impl ZkHuntContract {
         Ok(())
     }
 
-    /// Hunter uses power search to search ALL adjacent jungle tiles (limited uses).
-    pub fn hunter_power_search(env: Env, session_id: u32) -> Result<(), Error> {
+    /// Ban a random map for a player who let their draft deadline pass.
+    /// Callable by anyone so a stalling player cannot hold the match hostage.
+    pub fn force_draft_ban(env: Env, session_id: u32) -> Result<(), Error> {
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -427,47 +1202,22 @@ impl ZkHuntContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.phase != GamePhase::HunterTurn {
+        if game.phase != GamePhase::MapDraft {
             return Err(Error::WrongPhase);
         }
 
-        game.hunter.require_auth();
-
-        if !game.prey_is_hidden {
-            return Err(Error::PreyNotHidden);
-        }
-
-        if game.power_searches_remaining == 0 {
-            return Err(Error::NoPowerSearches);
+        if env.ledger().sequence() <= game.draft_deadline {
+            return Err(Error::DeadlineNotReached);
         }
 
-        game.power_searches_remaining -= 1;
-
-        // Find all adjacent jungle tiles
-        let mut tiles_x: Vec<u32> = vec![&env];
-        let mut tiles_y: Vec<u32> = vec![&env];
-
-        let hx = game.hunter_x;
-        let hy = game.hunter_y;
-
-        // Check center + 4 cardinals + 4 diagonals (9 slots, matches ZK search_response circuit)
-        let offsets: [(i32, i32); 9] = [(0, 0), (-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (-1, 1), (1, -1), (1, 1)];
-        for (ox, oy) in offsets.iter() {
-            let nx = hx as i32 + ox;
-            let ny = hy as i32 + oy;
-            if nx >= 0 && nx < 8 && ny >= 0 && ny < 8 {
-                let tile_idx = (ny * 8 + nx) as usize;
-                if MAPS[game.map_index as usize][tile_idx] == 1 {
-                    tiles_x.push_back(nx as u32);
-                    tiles_y.push_back(ny as u32);
-                }
-            }
-        }
+        let pos = env
+            .prng()
+            .gen_range::<u64>(0..game.draft_pool.len() as u64) as u32;
+        game.draft_pool.remove(pos);
 
-        game.searched_tiles_x = tiles_x;
-        game.searched_tiles_y = tiles_y;
-        game.phase = GamePhase::SearchPending;
+        advance_draft(&env, session_id, &mut game);
 
+        bump_channel_seq(&env, session_id);
         env.storage().temporary().set(&key, &game);
         env.storage()
             .temporary()
@@ -476,10 +1226,26 @@ impl ZkHuntContract {
         Ok(())
     }
 
-    /// Prey moves publicly on plains (visible to visible).
-    pub fn prey_move_public(
+    /// Hunter moves to an adjacent tile (public movement).
+    pub fn hunter_move(env: Env, session_id: u32, x: u32, y: u32) -> Result<(), Error> {
+        Self::hunter_move_signed(env, session_id, None, x, y)
+    }
+
+    /// `hunter_move` signed by `signer`: the player or their session key.
+    pub fn hunter_move_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        x: u32,
+        y: u32,
+    ) -> Result<(), Error> {
+        Self::hunter_move_signed(env, session_id, Some(signer), x, y)
+    }
+
+    fn hunter_move_signed(
         env: Env,
         session_id: u32,
+        signer: Option<Address>,
         x: u32,
         y: u32,
     ) -> Result<(), Error> {
@@ -490,48 +1256,52 @@ impl ZkHuntContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.phase != GamePhase::PreyTurn {
+        if game.phase != GamePhase::HunterTurn {
             return Err(Error::WrongPhase);
         }
 
-        game.prey.require_auth();
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
 
-        if game.prey_is_frozen {
-            return Err(Error::PreyFrozen);
-        }
+        require_visible_hunter(&game)?;
+        validate_hunter_move(&game, x, y)?;
+        charge(&mut game, Ability::Move)?;
 
-        if x >= 8 || y >= 8 {
-            return Err(Error::OutOfBounds);
-        }
+        game.hunter_x = x;
+        game.hunter_y = y;
 
-        // Must move to plains
-        let idx = (y * 8 + x) as usize;
-        if MAPS[game.map_index as usize][idx] == 1 {
-            return Err(Error::IsJungle);
+        // Check if hunter stepped on visible prey
+        if visible_prey_at(&game, game.hunter_x, game.hunter_y) {
+            end_round(&env, &key, &mut game, true);
+            return Ok(());
         }
 
-        // Validate adjacency from last known position
-        let dx = abs_diff(x, game.prey_x);
-        let dy = abs_diff(y, game.prey_y);
-        if dx + dy > 1 {
-            return Err(Error::InvalidMove);
-        }
+        end_hunter_turn(&env, &key, &mut game);
 
-        game.prey_x = x;
-        game.prey_y = y;
-        game.prey_is_hidden = false;
+        Ok(())
+    }
 
-        check_prey_survival(&env, &key, &mut game);
+    /// Hunter searches one adjacent jungle tile for the Prey.
+    pub fn hunter_search(env: Env, session_id: u32, x: u32, y: u32) -> Result<(), Error> {
+        Self::hunter_search_signed(env, session_id, None, x, y)
+    }
 
-        Ok(())
+    /// `hunter_search` signed by `signer`: the player or their session key.
+    pub fn hunter_search_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        x: u32,
+        y: u32,
+    ) -> Result<(), Error> {
+        Self::hunter_search_signed(env, session_id, Some(signer), x, y)
     }
 
-    /// Prey enters jungle from a visible position (becomes hidden).
-    pub fn prey_enter_jungle(
+    fn hunter_search_signed(
         env: Env,
         session_id: u32,
-        new_commitment: BytesN<32>,
-        proof: Bytes,
+        signer: Option<Address>,
+        x: u32,
+        y: u32,
     ) -> Result<(), Error> {
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -540,53 +1310,66 @@ impl ZkHuntContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.phase != GamePhase::PreyTurn {
+        if game.phase != GamePhase::HunterTurn {
             return Err(Error::WrongPhase);
         }
 
-        game.prey.require_auth();
-
-        if game.prey_is_frozen {
-            return Err(Error::PreyFrozen);
-        }
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
 
-        if game.prey_is_hidden {
-            return Err(Error::PreyAlreadyHidden);
+        if !game.prey_is_hidden {
+            return Err(Error::PreyNotHidden);
         }
 
-        // Validate public inputs: new_commitment in proof must match the argument
-        let proof_new_commitment = extract_bytes32(&proof, 36);
-        assert!(
-            proof_new_commitment == new_commitment,
-            "proof new_commitment does not match argument"
-        );
+        require_visible_hunter(&game)?;
+        validate_search_tile(&game, x, y)?;
+        charge(&mut game, Ability::Search)?;
 
-        // Validate map_id in proof matches game state
-        let proof_map_id = extract_u8(&proof, 68);
-        assert!(
-            proof_map_id == game.map_index as u8,
-            "proof map_id does not match game state"
-        );
+        game.searched_tiles_x = vec![&env, x];
+        game.searched_tiles_y = vec![&env, y];
+        game.phase = GamePhase::SearchPending;
 
-        // Verify the jungle_move proof
-        Self::verify_proof(&env, &DataKey::MoveVk, &proof)?;
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
-        game.prey_commitment = new_commitment;
-        game.prey_is_hidden = true;
-        // Clear last known public position (prey now hidden)
-        // Keep prey_x/prey_y as last-known for UI reference
+        Ok(())
+    }
 
-        check_prey_survival(&env, &key, &mut game);
+    /// Hunter moves, then searches one jungle tile adjacent to the new position,
+    /// as a single turn (requires the `compound_turn` rule).
+    pub fn hunter_move_and_search(
+        env: Env,
+        session_id: u32,
+        x: u32,
+        y: u32,
+        sx: u32,
+        sy: u32,
+    ) -> Result<(), Error> {
+        Self::hunter_move_and_search_signed(env, session_id, None, x, y, sx, sy)
+    }
 
-        Ok(())
+    /// `hunter_move_and_search` signed by `signer`: the player or their session key.
+    pub fn hunter_move_and_search_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        x: u32,
+        y: u32,
+        sx: u32,
+        sy: u32,
+    ) -> Result<(), Error> {
+        Self::hunter_move_and_search_signed(env, session_id, Some(signer), x, y, sx, sy)
     }
 
-    /// Prey moves within jungle (hidden to hidden).
-    pub fn prey_move_jungle(
+    fn hunter_move_and_search_signed(
         env: Env,
         session_id: u32,
-        new_commitment: BytesN<32>,
-        proof: Bytes,
+        signer: Option<Address>,
+        x: u32,
+        y: u32,
+        sx: u32,
+        sy: u32,
     ) -> Result<(), Error> {
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -595,55 +1378,62 @@ impl ZkHuntContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.phase != GamePhase::PreyTurn {
+        if game.phase != GamePhase::HunterTurn {
             return Err(Error::WrongPhase);
         }
 
-        game.prey.require_auth();
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
 
-        if game.prey_is_frozen {
-            return Err(Error::PreyFrozen);
+        if !game.config.compound_turn {
+            return Err(Error::RuleDisabled);
         }
 
         if !game.prey_is_hidden {
             return Err(Error::PreyNotHidden);
         }
 
-        // Validate public inputs: old_commitment must match stored, new_commitment must match argument
-        let proof_old_commitment = extract_bytes32(&proof, 4);
-        assert!(
-            proof_old_commitment == game.prey_commitment,
-            "proof old_commitment does not match game state"
-        );
-        let proof_new_commitment = extract_bytes32(&proof, 36);
-        assert!(
-            proof_new_commitment == new_commitment,
-            "proof new_commitment does not match argument"
-        );
+        require_visible_hunter(&game)?;
+        validate_hunter_move(&game, x, y)?;
+        charge(&mut game, Ability::Move)?;
+        game.hunter_x = x;
+        game.hunter_y = y;
 
-        // Validate map_id in proof matches game state
-        let proof_map_id = extract_u8(&proof, 68);
-        assert!(
-            proof_map_id == game.map_index as u8,
-            "proof map_id does not match game state"
-        );
+        // Check if hunter stepped on a visible benched prey
+        if visible_prey_at(&game, x, y) {
+            end_round(&env, &key, &mut game, true);
+            return Ok(());
+        }
 
-        // Verify the jungle_move proof
-        Self::verify_proof(&env, &DataKey::MoveVk, &proof)?;
+        // Search adjacency is checked from the new position
+        validate_search_tile(&game, sx, sy)?;
+        charge(&mut game, Ability::Search)?;
 
-        game.prey_commitment = new_commitment;
+        game.searched_tiles_x = vec![&env, sx];
+        game.searched_tiles_y = vec![&env, sy];
+        game.phase = GamePhase::SearchPending;
 
-        check_prey_survival(&env, &key, &mut game);
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
         Ok(())
     }
 
-    /// Prey exits jungle (reveals position, becomes visible).
-    pub fn prey_exit_jungle(
+    /// Hunter uses power search to search ALL adjacent jungle tiles (limited uses).
+    pub fn hunter_power_search(env: Env, session_id: u32) -> Result<(), Error> {
+        Self::hunter_power_search_signed(env, session_id, None)
+    }
+
+    /// `hunter_power_search` signed by `signer`: the player or their session key.
+    pub fn hunter_power_search_as(env: Env, session_id: u32, signer: Address) -> Result<(), Error> {
+        Self::hunter_power_search_signed(env, session_id, Some(signer))
+    }
+
+    fn hunter_power_search_signed(
         env: Env,
         session_id: u32,
-        x: u32,
-        y: u32,
+        signer: Option<Address>,
     ) -> Result<(), Error> {
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -652,53 +1442,84 @@ impl ZkHuntContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.phase != GamePhase::PreyTurn {
+        if game.phase != GamePhase::HunterTurn {
             return Err(Error::WrongPhase);
         }
 
-        game.prey.require_auth();
-
-        if game.prey_is_frozen {
-            return Err(Error::PreyFrozen);
-        }
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
 
         if !game.prey_is_hidden {
             return Err(Error::PreyNotHidden);
         }
 
-        if x >= 8 || y >= 8 {
-            return Err(Error::OutOfBounds);
-        }
+        require_visible_hunter(&game)?;
+        charge(&mut game, Ability::PowerSearch)?;
 
-        // Must exit to plains
-        let idx = (y * 8 + x) as usize;
-        if MAPS[game.map_index as usize][idx] == 1 {
-            return Err(Error::IsJungle);
+        // Find all adjacent jungle tiles
+        let mut tiles_x: Vec<u32> = vec![&env];
+        let mut tiles_y: Vec<u32> = vec![&env];
+
+        let hx = game.hunter_x;
+        let hy = game.hunter_y;
+
+        // Check center + 4 cardinals + 4 diagonals (9 slots, matches ZK search_response circuit)
+        let offsets: [(i32, i32); 9] = [(0, 0), (-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (-1, 1), (1, -1), (1, 1)];
+        for (ox, oy) in offsets.iter() {
+            let nx = hx as i32 + ox;
+            let ny = hy as i32 + oy;
+            if nx >= 0 && nx < 8 && ny >= 0 && ny < 8 && is_jungle(&game, nx as u32, ny as u32) {
+                tiles_x.push_back(nx as u32);
+                tiles_y.push_back(ny as u32);
+            }
         }
 
-        game.prey_x = x;
-        game.prey_y = y;
-        game.prey_is_hidden = false;
-        game.prey_commitment = BytesN::from_array(&env, &[0u8; 32]);
+        game.searched_tiles_x = tiles_x;
+        game.searched_tiles_y = tiles_y;
+        game.phase = GamePhase::SearchPending;
 
-        check_prey_survival(&env, &key, &mut game);
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
         Ok(())
     }
 
-    /// Prey responds to a search with a single batched ZK proof of non-presence.
-    ///
-    /// The proof's public inputs must match the on-chain game state:
-    /// - commitment must equal game.prey_commitment
-    /// - searched_x/y arrays must match game.searched_tiles_x/y (padded with 255 to length 9)
+    /// Hunter enters jungle from a visible position (becomes hidden), with a
+    /// `jungle_enter` proof that the new commitment is a jungle tile next to
+    /// the hunter's public position. Requires the `hidden_hunter` rule.
     ///
     /// Proof blob layout (after 4-byte num_fields header):
-    ///   bytes 4..36:    commitment (32 bytes, Field)
-    ///   bytes 36..324:  searched_x[0..9] (9 * 32 bytes, u8 in last byte)
-    ///   bytes 324..612: searched_y[0..9] (9 * 32 bytes, u8 in last byte)
-    pub fn respond_search(
+    ///   bytes 4..36:    x (u8 in last byte)
+    ///   bytes 36..68:   y (u8 in last byte)
+    ///   bytes 68..100:  new_commitment
+    ///   bytes 100..132: map_id (u8 in last byte)
+    ///   bytes 132..164: burned tiles (u64 in last 8 bytes)
+    pub fn hunter_enter_jungle(
+        env: Env,
+        session_id: u32,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::hunter_enter_jungle_signed(env, session_id, None, new_commitment, proof)
+    }
+
+    /// `hunter_enter_jungle` signed by `signer`: the player or their session key.
+    pub fn hunter_enter_jungle_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::hunter_enter_jungle_signed(env, session_id, Some(signer), new_commitment, proof)
+    }
+
+    fn hunter_enter_jungle_signed(
         env: Env,
         session_id: u32,
+        signer: Option<Address>,
+        new_commitment: BytesN<32>,
         proof: Bytes,
     ) -> Result<(), Error> {
         let key = DataKey::Game(session_id);
@@ -708,69 +1529,143 @@ impl ZkHuntContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.phase != GamePhase::SearchPending {
+        if game.phase != GamePhase::HunterTurn {
             return Err(Error::WrongPhase);
         }
 
-        game.prey.require_auth();
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
 
-        // Empty proof = prey concedes (hunter found them)
-        if proof.len() == 0 {
-            game.searched_tiles_x = vec![&env];
-            game.searched_tiles_y = vec![&env];
-            end_round(&env, &key, &mut game, true);
-            return Ok(());
+        if !game.config.hidden_hunter {
+            return Err(Error::RuleDisabled);
         }
 
-        // --- Verify public inputs match game state ---
+        require_visible_hunter(&game)?;
+        charge(&mut game, Ability::Move)?;
 
-        // Extract commitment from proof (bytes 4..36)
-        let proof_commitment = extract_bytes32(&proof, 4);
+        // The step starts from where the hunter is seen
         assert!(
-            proof_commitment == game.prey_commitment,
-            "proof commitment does not match game state"
+            extract_u8(&proof, 4) == game.hunter_x as u8
+                && extract_u8(&proof, 36) == game.hunter_y as u8,
+            "proof position does not match game state"
+        );
+        let proof_new_commitment = extract_bytes32(&proof, 68);
+        assert!(
+            proof_new_commitment == new_commitment,
+            "proof new_commitment does not match argument"
+        );
+        let proof_map_id = extract_u8(&proof, 100);
+        assert!(
+            proof_map_id == game.map_index as u8,
+            "proof map_id does not match game state"
+        );
+        assert!(
+            extract_u64(&proof, 132) == game.burned_tiles,
+            "proof burned tiles do not match game state"
         );
 
-        // Extract searched_x[0..9] from proof (bytes 36..324, each 32 bytes, u8 in last byte)
-        let num_tiles = game.searched_tiles_x.len();
-        for i in 0..9u32 {
-            let proof_sx = extract_u8(&proof, 36 + i * 32);
-            let proof_sy = extract_u8(&proof, 324 + i * 32);
-
-            if i < num_tiles {
-                let expected_x = game.searched_tiles_x.get(i).unwrap() as u8;
-                let expected_y = game.searched_tiles_y.get(i).unwrap() as u8;
-                assert!(
-                    proof_sx == expected_x && proof_sy == expected_y,
-                    "proof searched tile does not match game state"
-                );
-            } else {
-                // Padded slots must be 255
-                assert!(
-                    proof_sx == 255 && proof_sy == 255,
-                    "proof padding must be 255"
-                );
-            }
-        }
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Enter), &proof)?;
 
-        // --- Verify the ZK proof ---
-        Self::verify_proof(&env, &DataKey::SearchVk, &proof)?;
+        game.hunter_commitment = new_commitment;
+        game.hunter_is_hidden = true;
+        end_hunter_turn(&env, &key, &mut game);
 
-        // All searches responded in one proof, prey is safe
-        game.searched_tiles_x = vec![&env];
-        game.searched_tiles_y = vec![&env];
-        game.phase = GamePhase::PreyTurn;
+        Ok(())
+    }
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
+    /// Hidden Hunter moves within jungle (hidden to hidden), proven by `jungle_move`.
+    pub fn hunter_move_jungle(
+        env: Env,
+        session_id: u32,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::hunter_move_jungle_signed(env, session_id, None, new_commitment, proof)
+    }
+
+    /// `hunter_move_jungle` signed by `signer`: the player or their session key.
+    pub fn hunter_move_jungle_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::hunter_move_jungle_signed(env, session_id, Some(signer), new_commitment, proof)
+    }
+
+    fn hunter_move_jungle_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
             .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::HunterTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
+
+        if !game.hunter_is_hidden {
+            return Err(Error::HunterNotHidden);
+        }
+
+        charge(&mut game, Ability::Move)?;
+
+        check_hidden_move_inputs(&game, &game.hunter_commitment, &new_commitment, &proof);
+
+        Self::verify_proof(&env, &DataKey::MoveVk, &proof)?;
+
+        game.hunter_commitment = new_commitment;
+        end_hunter_turn(&env, &key, &mut game);
 
         Ok(())
     }
 
-    /// Hunter uses EMP to freeze visible prey for 1 turn (global range, 1 use per round).
-    pub fn hunter_emp(env: Env, session_id: u32) -> Result<(), Error> {
+    /// Hidden Hunter exits jungle onto plains (reveals position, becomes visible),
+    /// with a `jungle_exit` proof that (x, y) is within one step of its commitment.
+    ///
+    /// Proof blob layout (after 4-byte num_fields header):
+    ///   bytes 4..36:   commitment
+    ///   bytes 36..68:  x (u8 in last byte)
+    ///   bytes 68..100: y (u8 in last byte)
+    pub fn hunter_exit_jungle(
+        env: Env,
+        session_id: u32,
+        x: u32,
+        y: u32,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::hunter_exit_jungle_signed(env, session_id, None, x, y, proof)
+    }
+
+    /// `hunter_exit_jungle` signed by `signer`: the player or their session key.
+    pub fn hunter_exit_jungle_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        x: u32,
+        y: u32,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::hunter_exit_jungle_signed(env, session_id, Some(signer), x, y, proof)
+    }
+
+    fn hunter_exit_jungle_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        x: u32,
+        y: u32,
+        proof: Bytes,
+    ) -> Result<(), Error> {
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -782,30 +1677,84 @@ impl ZkHuntContract {
             return Err(Error::WrongPhase);
         }
 
-        game.hunter.require_auth();
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
 
-        if game.emp_uses_remaining == 0 {
-            return Err(Error::NoEMP);
+        if !game.hunter_is_hidden {
+            return Err(Error::HunterNotHidden);
         }
 
-        if game.prey_is_hidden {
-            return Err(Error::EmpTargetHidden);
+        if x >= 8 || y >= 8 {
+            return Err(Error::OutOfBounds);
         }
 
-        game.prey_is_frozen = true;
-        game.emp_uses_remaining -= 1;
-        // Phase stays HunterTurn — hunter can still move this turn
+        // Must exit to plains
+        if is_jungle(&game, x, y) {
+            return Err(Error::IsJungle);
+        }
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        charge(&mut game, Ability::Move)?;
+
+        assert!(
+            extract_bytes32(&proof, 4) == game.hunter_commitment,
+            "proof commitment does not match game state"
+        );
+        assert!(
+            extract_u8(&proof, 36) == x as u8 && extract_u8(&proof, 68) == y as u8,
+            "proof exit tile does not match argument"
+        );
+
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Exit), &proof)?;
+
+        game.hunter_x = x;
+        game.hunter_y = y;
+        game.hunter_is_hidden = false;
+        game.hunter_commitment = BytesN::from_array(&env, &[0u8; 32]);
+
+        if visible_prey_at(&game, x, y) {
+            end_round(&env, &key, &mut game, true);
+            return Ok(());
+        }
+
+        end_hunter_turn(&env, &key, &mut game);
 
         Ok(())
     }
 
-    /// Frozen prey skips their turn (called automatically by frontend).
-    pub fn prey_pass_frozen(env: Env, session_id: u32) -> Result<(), Error> {
+    /// Hidden Hunter searches up to 9 jungle tiles, proving with a
+    /// `hunter_adjacency` proof that they surround its committed position.
+    /// One tile costs a search, more cost a power search.
+    ///
+    /// Proof blob layout matches `respond_search`, with the hunter's commitment.
+    pub fn hunter_search_hidden(
+        env: Env,
+        session_id: u32,
+        tiles_x: Vec<u32>,
+        tiles_y: Vec<u32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::hunter_search_hidden_signed(env, session_id, None, tiles_x, tiles_y, proof)
+    }
+
+    /// `hunter_search_hidden` signed by `signer`: the player or their session key.
+    pub fn hunter_search_hidden_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        tiles_x: Vec<u32>,
+        tiles_y: Vec<u32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::hunter_search_hidden_signed(env, session_id, Some(signer), tiles_x, tiles_y, proof)
+    }
+
+    fn hunter_search_hidden_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        tiles_x: Vec<u32>,
+        tiles_y: Vec<u32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -813,29 +1762,75 @@ impl ZkHuntContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.phase != GamePhase::PreyTurn {
+        if game.phase != GamePhase::HunterTurn {
             return Err(Error::WrongPhase);
         }
 
-        game.prey.require_auth();
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
 
-        if !game.prey_is_frozen {
-            return Err(Error::WrongPhase);
+        if !game.hunter_is_hidden {
+            return Err(Error::HunterNotHidden);
+        }
+
+        if !game.prey_is_hidden {
+            return Err(Error::PreyNotHidden);
         }
 
-        game.prey_is_frozen = false;
+        if tiles_x.is_empty() || tiles_x.len() > 9 || tiles_x.len() != tiles_y.len() {
+            return Err(Error::InvalidMove);
+        }
+        for (x, y) in tiles_x.iter().zip(tiles_y.iter()) {
+            if x >= 8 || y >= 8 {
+                return Err(Error::OutOfBounds);
+            }
+            if !is_jungle(&game, x, y) {
+                return Err(Error::NotJungle);
+            }
+        }
 
-        check_prey_survival(&env, &key, &mut game);
+        if tiles_x.len() == 1 {
+            charge(&mut game, Ability::Search)?;
+        } else {
+            charge(&mut game, Ability::PowerSearch)?;
+        }
+
+        game.searched_tiles_x = tiles_x;
+        game.searched_tiles_y = tiles_y;
+        check_search_inputs(&game, &game.hunter_commitment, &proof);
+
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Adjacency), &proof)?;
+
+        game.phase = GamePhase::SearchPending;
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
         Ok(())
     }
 
-    /// Prey dashes up to 2 tiles on plains in a single move (2 uses per turn).
-    pub fn prey_dash_public(
+    /// Hunter pings a Manhattan radius around their position; the Prey must
+    /// answer with a proof of whether they are inside it (limited uses).
+    pub fn hunter_sonar(env: Env, session_id: u32, radius: u32) -> Result<(), Error> {
+        Self::hunter_sonar_signed(env, session_id, None, radius)
+    }
+
+    /// `hunter_sonar` signed by `signer`: the player or their session key.
+    pub fn hunter_sonar_as(
         env: Env,
         session_id: u32,
-        x: u32,
-        y: u32,
+        signer: Address,
+        radius: u32,
+    ) -> Result<(), Error> {
+        Self::hunter_sonar_signed(env, session_id, Some(signer), radius)
+    }
+
+    fn hunter_sonar_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        radius: u32,
     ) -> Result<(), Error> {
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -844,49 +1839,120 @@ impl ZkHuntContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.phase != GamePhase::PreyTurn {
+        if game.phase != GamePhase::HunterTurn {
             return Err(Error::WrongPhase);
         }
 
-        game.prey.require_auth();
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
 
-        if game.prey_is_frozen {
-            return Err(Error::PreyFrozen);
+        if !game.prey_is_hidden {
+            return Err(Error::PreyNotHidden);
         }
 
-        if game.prey_dash_remaining == 0 {
-            return Err(Error::NoDashes);
+        require_visible_hunter(&game)?;
+
+        if radius == 0 || radius > MAX_SONAR_RADIUS {
+            return Err(Error::InvalidRadius);
         }
 
-        if x >= 8 || y >= 8 {
-            return Err(Error::OutOfBounds);
+        charge(&mut game, Ability::Sonar)?;
+
+        game.sonar_radius = radius;
+        game.last_sonar_inside = None;
+        game.response_deadline = env.ledger().sequence() + RESPONSE_TIMEOUT_LEDGERS;
+        game.phase = GamePhase::SonarPending;
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Hunter fires a flare over an arbitrary set of tiles given as a bitmask
+    /// (bit `y * 8 + x`, at most `MAX_FLARE_TILES` tiles, limited uses).
+    pub fn hunter_flare(env: Env, session_id: u32, mask: u64) -> Result<(), Error> {
+        Self::hunter_flare_signed(env, session_id, None, mask)
+    }
+
+    /// `hunter_flare` signed by `signer`: the player or their session key.
+    pub fn hunter_flare_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        mask: u64,
+    ) -> Result<(), Error> {
+        Self::hunter_flare_signed(env, session_id, Some(signer), mask)
+    }
+
+    fn hunter_flare_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        mask: u64,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::HunterTurn {
+            return Err(Error::WrongPhase);
         }
 
-        // Must move to plains
-        let idx = (y * 8 + x) as usize;
-        if MAPS[game.map_index as usize][idx] == 1 {
-            return Err(Error::IsJungle);
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
+
+        if !game.prey_is_hidden {
+            return Err(Error::PreyNotHidden);
         }
 
-        // Dash distance: Manhattan <= 2
-        let dx = abs_diff(x, game.prey_x);
-        let dy = abs_diff(y, game.prey_y);
-        if dx + dy > 2 {
-            return Err(Error::InvalidMove);
+        if mask == 0 || mask.count_ones() > MAX_FLARE_TILES {
+            return Err(Error::InvalidMask);
         }
 
-        game.prey_dash_remaining -= 1;
-        game.prey_x = x;
-        game.prey_y = y;
-        game.prey_is_hidden = false;
+        charge(&mut game, Ability::Flare)?;
 
-        check_prey_survival(&env, &key, &mut game);
+        game.flare_mask = mask;
+        game.response_deadline = env.ledger().sequence() + RESPONSE_TIMEOUT_LEDGERS;
+        game.phase = GamePhase::FlarePending;
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
         Ok(())
     }
 
-    /// Hunter claims catch (prey failed to respond to search).
-    pub fn claim_catch(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// Hunter hides a trap as `sha256(x || y || salt)` (up to `MAX_TRAPS` per round).
+    /// Does not use the hunter's turn.
+    pub fn hunter_place_trap(
+        env: Env,
+        session_id: u32,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::hunter_place_trap_signed(env, session_id, None, commitment)
+    }
+
+    /// `hunter_place_trap` signed by `signer`: the player or their session key.
+    pub fn hunter_place_trap_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::hunter_place_trap_signed(env, session_id, Some(signer), commitment)
+    }
+
+    fn hunter_place_trap_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -894,127 +1960,3244 @@ impl ZkHuntContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.phase != GamePhase::SearchPending {
+        if game.phase != GamePhase::HunterTurn {
             return Err(Error::WrongPhase);
         }
 
-        game.hunter.require_auth();
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
 
-        end_round(&env, &key, &mut game, true);
+        if game.traps.len() >= MAX_TRAPS {
+            return Err(Error::NoTraps);
+        }
 
-        let winner_or_hunter = game.winner.clone().unwrap_or(game.hunter.clone());
-        Ok(winner_or_hunter)
+        charge(&mut game, Ability::Trap)?;
+
+        game.traps.push_back(Trap {
+            commitment,
+            placed_turn: game.turn_number,
+            revealed: false,
+        });
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
     }
 
-    /// Read game state (for frontend polling).
-    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+    /// Hunter reveals a trap. If the prey's public trail crossed the trap tile
+    /// since it was placed, the hunter wins the round. Returns whether it sprang.
+    /// A hidden prey must then prove it is not on the tile (`prey_prove_traps`)
+    /// before acting again. Traps can also be revealed once the prey has
+    /// survived the turn limit (`TrapReveal`).
+    pub fn hunter_reveal_trap(
+        env: Env,
+        session_id: u32,
+        index: u32,
+        x: u32,
+        y: u32,
+        salt: BytesN<32>,
+    ) -> Result<bool, Error> {
+        Self::hunter_reveal_trap_signed(env, session_id, None, index, x, y, salt)
+    }
+
+    /// `hunter_reveal_trap` signed by `signer`: the player or their session key.
+    pub fn hunter_reveal_trap_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        index: u32,
+        x: u32,
+        y: u32,
+        salt: BytesN<32>,
+    ) -> Result<bool, Error> {
+        Self::hunter_reveal_trap_signed(env, session_id, Some(signer), index, x, y, salt)
+    }
+
+    fn hunter_reveal_trap_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        index: u32,
+        x: u32,
+        y: u32,
+        salt: BytesN<32>,
+    ) -> Result<bool, Error> {
         let key = DataKey::Game(session_id);
-        env.storage()
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::HunterTurn && game.phase != GamePhase::TrapReveal {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
+
+        let mut trap = match game.traps.get(index) {
+            Some(trap) if !trap.revealed => trap,
+            _ => return Err(Error::TrapNotFound),
+        };
+
+        if x >= 8 || y >= 8 {
+            return Err(Error::OutOfBounds);
+        }
+
+        let mut preimage = Bytes::from_array(&env, &x.to_be_bytes());
+        preimage.extend_from_array(&y.to_be_bytes());
+        preimage.append(&salt.into());
+        if sha256_bytes(&env, &preimage) != trap.commitment {
+            return Err(Error::TrapMismatch);
+        }
+
+        let sprung = game
+            .prey_trail
+            .iter()
+            .any(|step| step.turn >= trap.placed_turn && step.x == x && step.y == y);
+        if sprung {
+            end_round(&env, &key, &mut game, true);
+            return Ok(true);
+        }
+
+        trap.revealed = true;
+        game.traps.set(index, trap);
+
+        if game.prey_is_hidden {
+            game.trap_check_mask |= 1u64 << (y * 8 + x);
+            game.response_deadline = env.ledger().sequence() + RESPONSE_TIMEOUT_LEDGERS;
+        }
+
+        continue_trap_reveal(&env, &key, &mut game);
+
+        Ok(false)
+    }
+
+    /// Hidden prey proves with a `flare_response` proof that it is on none of
+    /// the revealed trap tiles in `trap_check_mask`. Required before acting
+    /// after a trap is revealed; does not use the turn. An empty proof
+    /// concedes the round (caught in the trap).
+    ///
+    /// Same proof blob layout as `respond_flare`, with the trap check mask.
+    pub fn prey_prove_traps(env: Env, session_id: u32, proof: Bytes) -> Result<(), Error> {
+        Self::prey_prove_traps_signed(env, session_id, None, proof)
+    }
+
+    /// `prey_prove_traps` signed by `signer`: the player or their session key.
+    pub fn prey_prove_traps_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_prove_traps_signed(env, session_id, Some(signer), proof)
+    }
+
+    fn prey_prove_traps_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn && game.phase != GamePhase::TrapReveal {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+
+        if game.trap_check_mask == 0 {
+            return Err(Error::NoTrapCheck);
+        }
+
+        if proof.is_empty() {
+            end_round(&env, &key, &mut game, true);
+            return Ok(());
+        }
+
+        assert!(
+            extract_bytes32(&proof, 4) == game.prey_commitment,
+            "proof commitment does not match game state"
+        );
+        assert!(
+            extract_u64(&proof, 36) == game.trap_check_mask,
+            "proof mask does not match game state"
+        );
+
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Flare), &proof)?;
+
+        game.trap_check_mask = 0;
+        continue_trap_reveal(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Score a round waiting in `TrapReveal`. The hunter can do it at any time
+    /// to skip revealing its remaining traps; after the deadline anyone can.
+    /// If the prey left a trap check unanswered past the deadline, the round
+    /// goes to the hunter.
+    pub fn close_trap_reveal(env: Env, session_id: u32) -> Result<(), Error> {
+        Self::close_trap_reveal_signed(env, session_id, None)
+    }
+
+    /// `close_trap_reveal` signed by `signer`: the player or their session key.
+    pub fn close_trap_reveal_as(env: Env, session_id: u32, signer: Address) -> Result<(), Error> {
+        Self::close_trap_reveal_signed(env, session_id, Some(signer))
+    }
+
+    fn close_trap_reveal_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::TrapReveal {
+            return Err(Error::WrongPhase);
+        }
+
+        let timed_out = env.ledger().sequence() > game.response_deadline;
+        if game.trap_check_mask != 0 {
+            if !timed_out {
+                return Err(Error::DeadlineNotReached);
+            }
+            end_round(&env, &key, &mut game, true);
+            return Ok(());
+        }
+        if !timed_out {
+            require_player_auth(&env, session_id, &game.hunter, &signer)?;
+        }
+
+        score_survival(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Hidden prey registers a decoy commitment that lasts `DECOY_LIFETIME_TURNS`
+    /// turns (up to `MAX_DECOYS` active). Does not use the prey's turn.
+    pub fn prey_place_decoy(
+        env: Env,
+        session_id: u32,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::prey_place_decoy_signed(env, session_id, None, commitment)
+    }
+
+    /// `prey_place_decoy` signed by `signer`: the player or their session key.
+    pub fn prey_place_decoy_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::prey_place_decoy_signed(env, session_id, Some(signer), commitment)
+    }
+
+    fn prey_place_decoy_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+
+        if !game.prey_is_hidden {
+            return Err(Error::PreyNotHidden);
+        }
+
+        if game.decoys.len() >= MAX_DECOYS {
+            return Err(Error::TooManyDecoys);
+        }
+
+        charge(&mut game, Ability::Decoy)?;
+
+        game.decoys.push_back(Decoy {
+            commitment,
+            expires_turn: game.turn_number + DECOY_LIFETIME_TURNS,
+        });
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Hunter burns an adjacent jungle tile to plains for the rest of the round.
+    /// If the prey is hidden it must prove it is not on the tile (`respond_burn`).
+    pub fn hunter_burn(env: Env, session_id: u32, x: u32, y: u32) -> Result<(), Error> {
+        Self::hunter_burn_signed(env, session_id, None, x, y)
+    }
+
+    /// `hunter_burn` signed by `signer`: the player or their session key.
+    pub fn hunter_burn_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        x: u32,
+        y: u32,
+    ) -> Result<(), Error> {
+        Self::hunter_burn_signed(env, session_id, Some(signer), x, y)
+    }
+
+    fn hunter_burn_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        x: u32,
+        y: u32,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::HunterTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
+
+        require_visible_hunter(&game)?;
+        validate_search_tile(&game, x, y)?;
+
+        if game.burned_tiles.count_ones() >= MAX_BURNS {
+            return Err(Error::NoBurns);
+        }
+
+        charge(&mut game, Ability::Burn)?;
+
+        game.burned_tiles |= 1u64 << (y * 8 + x);
+
+        if game.prey_is_hidden {
+            game.searched_tiles_x = vec![&env, x];
+            game.searched_tiles_y = vec![&env, y];
+            game.response_deadline = env.ledger().sequence() + RESPONSE_TIMEOUT_LEDGERS;
+            game.phase = GamePhase::BurnPending;
+        } else {
+            end_hunter_turn(&env, &key, &mut game);
+            return Ok(());
+        }
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Hidden prey answers a burn with a `search_response` proof that it is not
+    /// on the burned tile. An empty proof reveals the prey on that tile.
+    ///
+    /// Same proof blob layout as `respond_search`.
+    pub fn respond_burn(env: Env, session_id: u32, proof: Bytes) -> Result<(), Error> {
+        Self::respond_burn_signed(env, session_id, None, proof)
+    }
+
+    /// `respond_burn` signed by `signer`: the player or their session key.
+    pub fn respond_burn_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::respond_burn_signed(env, session_id, Some(signer), proof)
+    }
+
+    fn respond_burn_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::BurnPending {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+
+        if proof.is_empty() {
+            // Burned out of cover: the prey stands revealed on the burned tile
+            game.prey_x = game.searched_tiles_x.get(0).unwrap();
+            game.prey_y = game.searched_tiles_y.get(0).unwrap();
+            game.prey_is_hidden = false;
+            game.prey_commitment = BytesN::from_array(&env, &[0u8; 32]);
+        } else {
+            check_search_inputs(&game, &game.prey_commitment, &proof);
+            Self::verify_proof(&env, &DataKey::SearchVk, &proof)?;
+        }
+
+        game.searched_tiles_x = vec![&env];
+        game.searched_tiles_y = vec![&env];
+        end_hunter_turn(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Prey proves the commitment it is about to move to (`commitment`) is
+    /// inside this turn's safe zone. Once the zone starts shrinking, every
+    /// hidden move (and entering jungle) must end on a commitment proven this
+    /// turn, so the prey never ends a turn hidden outside the zone. An empty
+    /// proof concedes the round.
+    ///
+    /// Proof blob layout (after 4-byte num_fields header):
+    ///   bytes 4..36:  commitment
+    ///   bytes 36..68: inset (u8 in last byte)
+    pub fn prey_prove_zone(
+        env: Env,
+        session_id: u32,
+        commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_prove_zone_signed(env, session_id, None, commitment, proof)
+    }
+
+    /// `prey_prove_zone` signed by `signer`: the player or their session key.
+    pub fn prey_prove_zone_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_prove_zone_signed(env, session_id, Some(signer), commitment, proof)
+    }
+
+    fn prey_prove_zone_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+
+        let inset = zone_inset(&game);
+        if inset == 0 {
+            return Err(Error::RuleDisabled);
+        }
+
+        // Empty proof = prey concedes (caught outside the zone)
+        if proof.is_empty() {
+            end_round(&env, &key, &mut game, true);
+            return Ok(());
+        }
+
+        let proof_commitment = extract_bytes32(&proof, 4);
+        assert!(
+            proof_commitment == commitment,
+            "proof commitment does not match argument"
+        );
+        assert!(
+            extract_u8(&proof, 36) == inset as u8,
+            "proof inset does not match game state"
+        );
+
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Zone), &proof)?;
+
+        game.zone_proven_turn = game.turn_number;
+        game.zone_commitment = commitment;
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Hidden prey reveals the tile it held `scent_delay` moves ago, with a
+    /// `scent_reveal` proof chaining that commitment to its current one.
+    /// Required before acting once a scent is due; does not use the turn.
+    ///
+    /// Proof blob layout (after 4-byte num_fields header):
+    ///   bytes 4..36:    old_commitment
+    ///   bytes 36..68:   current_commitment
+    ///   bytes 68..100:  x (u8 in last byte)
+    ///   bytes 100..132: y (u8 in last byte)
+    ///   bytes 132..164: steps (u8 in last byte)
+    pub fn prey_reveal_scent(
+        env: Env,
+        session_id: u32,
+        x: u32,
+        y: u32,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_reveal_scent_signed(env, session_id, None, x, y, proof)
+    }
+
+    /// `prey_reveal_scent` signed by `signer`: the player or their session key.
+    pub fn prey_reveal_scent_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        x: u32,
+        y: u32,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_reveal_scent_signed(env, session_id, Some(signer), x, y, proof)
+    }
+
+    fn prey_reveal_scent_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        x: u32,
+        y: u32,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+
+        let record = scent_due(&game).ok_or(Error::NoScentDue)?;
+
+        if x >= 8 || y >= 8 {
+            return Err(Error::OutOfBounds);
+        }
+
+        assert!(
+            extract_bytes32(&proof, 4) == record.commitment,
+            "proof old_commitment does not match scent history"
+        );
+        assert!(
+            extract_bytes32(&proof, 36) == game.prey_commitment,
+            "proof current_commitment does not match game state"
+        );
+        assert!(
+            extract_u8(&proof, 68) == x as u8 && extract_u8(&proof, 100) == y as u8,
+            "proof position does not match arguments"
+        );
+        assert!(
+            extract_u8(&proof, 132) == game.config.scent_delay as u8,
+            "proof steps do not match scent delay"
+        );
+
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Scent), &proof)?;
+
+        game.scent_history.pop_front();
+        game.scent_trail.push_back(TrailStep {
+            turn: record.turn,
+            x,
+            y,
+        });
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Hidden prey proves it is on one of the map's extraction tiles and wins
+    /// the round (escape mode). Visible prey extract by ending a turn on one.
+    ///
+    /// Proof blob layout (after 4-byte num_fields header):
+    ///   bytes 4..36:  commitment
+    ///   bytes 36..68: extraction mask (u64 in last 8 bytes)
+    pub fn prey_extract_hidden(env: Env, session_id: u32, proof: Bytes) -> Result<(), Error> {
+        Self::prey_extract_hidden_signed(env, session_id, None, proof)
+    }
+
+    /// `prey_extract_hidden` signed by `signer`: the player or their session key.
+    pub fn prey_extract_hidden_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_extract_hidden_signed(env, session_id, Some(signer), proof)
+    }
+
+    fn prey_extract_hidden_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+        check_prey_obligations(&game)?;
+
+        if game.config.objective == Objective::Survive {
+            return Err(Error::RuleDisabled);
+        }
+
+        if !game.prey_is_hidden {
+            return Err(Error::PreyNotHidden);
+        }
+
+        let proof_commitment = extract_bytes32(&proof, 4);
+        assert!(
+            proof_commitment == game.prey_commitment,
+            "proof commitment does not match game state"
+        );
+        assert!(
+            extract_u64(&proof, 36) == EXTRACTION_TILES[game.map_index as usize],
+            "proof extraction tiles do not match the map"
+        );
+
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Extraction), &proof)?;
+
+        end_round(&env, &key, &mut game, false);
+
+        Ok(())
+    }
+
+    /// Prey commits to which `item_count` of the round's candidate tiles hold
+    /// items: `commitment = hash(mask, salt)` for a secret tile mask and salt.
+    /// Required before the prey's first action of the round; does not use
+    /// its turn.
+    pub fn prey_commit_items(
+        env: Env,
+        session_id: u32,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::prey_commit_items_signed(env, session_id, None, commitment)
+    }
+
+    /// `prey_commit_items` signed by `signer`: the player or their session key.
+    pub fn prey_commit_items_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::prey_commit_items_signed(env, session_id, Some(signer), commitment)
+    }
+
+    fn prey_commit_items_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+
+        if game.config.item_count == 0 {
+            return Err(Error::RuleDisabled);
+        }
+
+        if game.items_commitment.is_some() {
+            return Err(Error::ItemsAlreadyCommitted);
+        }
+
+        game.items_commitment = Some(commitment);
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Hidden prey claims an item by proving it stands on one of its
+    /// committed item tiles, without revealing which. `nullifier` is unique
+    /// per item tile, so each item is claimed once (`ItemAlreadyClaimed`).
+    /// Publishes `ItemCollected` with the nullifier of the item taken;
+    /// collecting `items_required` wins the round. Does not use the prey's turn.
+    ///
+    /// Proof blob layout (after 4-byte num_fields header):
+    ///   bytes 4..36:    commitment
+    ///   bytes 36..68:   candidates (u64 in last 8 bytes)
+    ///   bytes 68..100:  item_count (u8 in last byte)
+    ///   bytes 100..132: items_commitment
+    ///   bytes 132..164: nullifier
+    pub fn prey_claim_item(
+        env: Env,
+        session_id: u32,
+        nullifier: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_claim_item_signed(env, session_id, None, nullifier, proof)
+    }
+
+    /// `prey_claim_item` signed by `signer`: the player or their session key.
+    pub fn prey_claim_item_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        nullifier: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_claim_item_signed(env, session_id, Some(signer), nullifier, proof)
+    }
+
+    fn prey_claim_item_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        nullifier: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+        check_prey_obligations(&game)?;
+
+        if game.config.item_count == 0 {
+            return Err(Error::RuleDisabled);
+        }
+
+        if !game.prey_is_hidden {
+            return Err(Error::PreyNotHidden);
+        }
+
+        if game.item_nullifiers.contains(&nullifier) {
+            return Err(Error::ItemAlreadyClaimed);
+        }
+
+        let proof_commitment = extract_bytes32(&proof, 4);
+        assert!(
+            proof_commitment == game.prey_commitment,
+            "proof commitment does not match game state"
+        );
+        assert!(
+            extract_u64(&proof, 36) == game.item_candidates
+                && extract_u8(&proof, 68) == game.config.item_count as u8,
+            "proof item candidates do not match game state"
+        );
+        assert!(
+            Some(extract_bytes32(&proof, 100)) == game.items_commitment,
+            "proof items commitment does not match game state"
+        );
+        assert!(
+            extract_bytes32(&proof, 132) == nullifier,
+            "proof nullifier does not match argument"
+        );
+
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Item), &proof)?;
+
+        game.item_nullifiers.push_back(nullifier.clone());
+        game.items_collected += 1;
+
+        ItemCollected {
+            session_id,
+            nullifier,
+            items_collected: game.items_collected,
+        }
+        .publish(&env);
+
+        if game.items_collected >= game.config.items_required {
+            end_round(&env, &key, &mut game, false);
+            return Ok(());
+        }
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Prey moves publicly on plains (visible to visible).
+    pub fn prey_move_public(env: Env, session_id: u32, x: u32, y: u32) -> Result<(), Error> {
+        Self::prey_move_public_signed(env, session_id, None, x, y)
+    }
+
+    /// `prey_move_public` signed by `signer`: the player or their session key.
+    pub fn prey_move_public_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        x: u32,
+        y: u32,
+    ) -> Result<(), Error> {
+        Self::prey_move_public_signed(env, session_id, Some(signer), x, y)
+    }
+
+    fn prey_move_public_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        x: u32,
+        y: u32,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+        check_prey_obligations(&game)?;
+
+        if game.prey_is_frozen {
+            return Err(Error::PreyFrozen);
+        }
+
+        if x >= 8 || y >= 8 {
+            return Err(Error::OutOfBounds);
+        }
+
+        // Must move to plains
+        if is_jungle(&game, x, y) {
+            return Err(Error::IsJungle);
+        }
+
+        // Validate adjacency from last known position
+        let dx = abs_diff(x, game.prey_x);
+        let dy = abs_diff(y, game.prey_y);
+        if dx + dy > 1 {
+            return Err(Error::InvalidMove);
+        }
+
+        charge(&mut game, Ability::Move)?;
+
+        game.prey_x = x;
+        game.prey_y = y;
+        game.prey_is_hidden = false;
+
+        check_prey_survival(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Prey enters jungle from a visible position (becomes hidden).
+    pub fn prey_enter_jungle(
+        env: Env,
+        session_id: u32,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_enter_jungle_signed(env, session_id, None, new_commitment, proof)
+    }
+
+    /// `prey_enter_jungle` signed by `signer`: the player or their session key.
+    pub fn prey_enter_jungle_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_enter_jungle_signed(env, session_id, Some(signer), new_commitment, proof)
+    }
+
+    fn prey_enter_jungle_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+        check_prey_obligations(&game)?;
+
+        if game.prey_is_frozen {
+            return Err(Error::PreyFrozen);
+        }
+
+        if game.prey_is_hidden {
+            return Err(Error::PreyAlreadyHidden);
+        }
+
+        check_zone_proof(&game, &new_commitment)?;
+        charge(&mut game, Ability::Hide)?;
+
+        // Validate public inputs: new_commitment in proof must match the argument
+        let proof_new_commitment = extract_bytes32(&proof, 36);
+        assert!(
+            proof_new_commitment == new_commitment,
+            "proof new_commitment does not match argument"
+        );
+
+        // Validate map_id and burned tiles in proof match game state
+        let proof_map_id = extract_u8(&proof, 68);
+        assert!(
+            proof_map_id == game.map_index as u8,
+            "proof map_id does not match game state"
+        );
+        assert!(
+            extract_u64(&proof, 100) == game.burned_tiles,
+            "proof burned tiles do not match game state"
+        );
+
+        // Verify the jungle_move proof
+        Self::verify_proof(&env, &DataKey::MoveVk, &proof)?;
+
+        game.prey_commitment = new_commitment;
+        game.prey_is_hidden = true;
+        // Clear last known public position (prey now hidden)
+        // Keep prey_x/prey_y as last-known for UI reference
+
+        check_prey_survival(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Prey commits to a hidden spawn at the start of a hidden-spawn round.
+    ///
+    /// Proof blob layout (after 4-byte num_fields header):
+    ///   bytes 4..36:    commitment (32 bytes, Field)
+    ///   bytes 36..68:   map_id (u8 in last byte)
+    ///   bytes 68..100:  hunter_x (u8 in last byte)
+    ///   bytes 100..132: hunter_y (u8 in last byte)
+    ///   bytes 132..164: extraction (u64 in last 8 bytes)
+    pub fn prey_spawn_hidden(
+        env: Env,
+        session_id: u32,
+        commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_spawn_hidden_signed(env, session_id, None, commitment, proof)
+    }
+
+    /// `prey_spawn_hidden` signed by `signer`: the player or their session key.
+    pub fn prey_spawn_hidden_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_spawn_hidden_signed(env, session_id, Some(signer), commitment, proof)
+    }
+
+    fn prey_spawn_hidden_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreySpawn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+
+        let proof_commitment = extract_bytes32(&proof, 4);
+        assert!(
+            proof_commitment == commitment,
+            "proof commitment does not match argument"
+        );
+
+        let proof_map_id = extract_u8(&proof, 36);
+        assert!(
+            proof_map_id == game.map_index as u8,
+            "proof map_id does not match game state"
+        );
+
+        // Spawn distance is measured from the hunter's actual spawn
+        let proof_hunter_x = extract_u8(&proof, 68);
+        let proof_hunter_y = extract_u8(&proof, 100);
+        assert!(
+            proof_hunter_x == game.hunter_x as u8 && proof_hunter_y == game.hunter_y as u8,
+            "proof hunter position does not match game state"
+        );
+
+        // Nor may the spawn sit next to an extraction tile
+        assert!(
+            extract_u64(&proof, 132) == EXTRACTION_TILES[game.map_index as usize],
+            "proof extraction tiles do not match the map"
+        );
+
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Spawn), &proof)?;
+
+        // The audit checks the first hidden step against the spawn
+        if game.config.audit {
+            game.commitment_history.push_back(CommitmentRecord {
+                turn: 0,
+                commitment: commitment.clone(),
+            });
+        }
+
+        game.prey_commitment = commitment;
+        game.prey_is_hidden = true;
+        game.phase = GamePhase::HunterTurn;
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Prey moves within jungle (hidden to hidden).
+    /// In an `optimistic` match `proof` is not checked (pass it empty); the
+    /// move is proven with the round's batch instead.
+    pub fn prey_move_jungle(
+        env: Env,
+        session_id: u32,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_move_jungle_signed(env, session_id, None, new_commitment, proof)
+    }
+
+    /// `prey_move_jungle` signed by `signer`: the player or their session key.
+    pub fn prey_move_jungle_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_move_jungle_signed(env, session_id, Some(signer), new_commitment, proof)
+    }
+
+    fn prey_move_jungle_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+        check_prey_obligations(&game)?;
+
+        if game.prey_is_frozen {
+            return Err(Error::PreyFrozen);
+        }
+
+        if !game.prey_is_hidden {
+            return Err(Error::PreyNotHidden);
+        }
+
+        check_zone_proof(&game, &new_commitment)?;
+        charge(&mut game, Ability::Move)?;
+
+        if game.config.optimistic {
+            record_step(&mut game, &new_commitment, false);
+        } else {
+            check_hidden_move_inputs(&game, &game.prey_commitment, &new_commitment, &proof);
+
+            // Verify the jungle_move proof
+            Self::verify_proof(&env, &DataKey::MoveVk, &proof)?;
+        }
+
+        game.prey_commitment = new_commitment;
+
+        check_prey_survival(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Prey dashes up to 2 tiles through jungle while hidden (consumes a dash).
+    /// Same public inputs as `prey_move_jungle`, proven by the `jungle_dash` circuit.
+    pub fn prey_dash_jungle(
+        env: Env,
+        session_id: u32,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_dash_jungle_signed(env, session_id, None, new_commitment, proof)
+    }
+
+    /// `prey_dash_jungle` signed by `signer`: the player or their session key.
+    pub fn prey_dash_jungle_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::prey_dash_jungle_signed(env, session_id, Some(signer), new_commitment, proof)
+    }
+
+    fn prey_dash_jungle_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        new_commitment: BytesN<32>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+        check_prey_obligations(&game)?;
+
+        if game.prey_is_frozen {
+            return Err(Error::PreyFrozen);
+        }
+
+        if !game.prey_is_hidden {
+            return Err(Error::PreyNotHidden);
+        }
+
+        check_zone_proof(&game, &new_commitment)?;
+        charge(&mut game, Ability::Dash)?;
+
+        if game.config.optimistic {
+            record_step(&mut game, &new_commitment, true);
+        } else {
+            check_hidden_move_inputs(&game, &game.prey_commitment, &new_commitment, &proof);
+
+            Self::verify_proof(&env, &DataKey::Vk(Circuit::Dash), &proof)?;
+        }
+
+        if game.config.audit {
+            game.dash_turns.push_back(game.turn_number);
+        }
+        game.prey_commitment = new_commitment;
+
+        check_prey_survival(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Prey exits jungle (reveals position, becomes visible).
+    pub fn prey_exit_jungle(env: Env, session_id: u32, x: u32, y: u32) -> Result<(), Error> {
+        Self::prey_exit_jungle_signed(env, session_id, None, x, y)
+    }
+
+    /// `prey_exit_jungle` signed by `signer`: the player or their session key.
+    pub fn prey_exit_jungle_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        x: u32,
+        y: u32,
+    ) -> Result<(), Error> {
+        Self::prey_exit_jungle_signed(env, session_id, Some(signer), x, y)
+    }
+
+    fn prey_exit_jungle_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        x: u32,
+        y: u32,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+        check_prey_obligations(&game)?;
+
+        if game.prey_is_frozen {
+            return Err(Error::PreyFrozen);
+        }
+
+        if !game.prey_is_hidden {
+            return Err(Error::PreyNotHidden);
+        }
+
+        if x >= 8 || y >= 8 {
+            return Err(Error::OutOfBounds);
+        }
+
+        // Must exit to plains
+        if is_jungle(&game, x, y) {
+            return Err(Error::IsJungle);
+        }
+
+        charge(&mut game, Ability::Move)?;
+
+        game.prey_x = x;
+        game.prey_y = y;
+        game.prey_is_hidden = false;
+        game.prey_commitment = BytesN::from_array(&env, &[0u8; 32]);
+
+        check_prey_survival(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Prey responds to a search with a single batched ZK proof of non-presence.
+    ///
+    /// The proof's public inputs must match the on-chain game state:
+    /// - commitment must equal game.prey_commitment
+    /// - searched_x/y arrays must match game.searched_tiles_x/y (padded with 255 to length 9)
+    ///
+    /// Proof blob layout (after 4-byte num_fields header):
+    ///   bytes 4..36:    commitment (32 bytes, Field)
+    ///   bytes 36..324:  searched_x[0..9] (9 * 32 bytes, u8 in last byte)
+    ///   bytes 324..612: searched_y[0..9] (9 * 32 bytes, u8 in last byte)
+    pub fn respond_search(env: Env, session_id: u32, proof: Bytes) -> Result<(), Error> {
+        Self::respond_search_signed(env, session_id, None, proof)
+    }
+
+    /// `respond_search` signed by `signer`: the player or their session key.
+    pub fn respond_search_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::respond_search_signed(env, session_id, Some(signer), proof)
+    }
+
+    fn respond_search_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::SearchPending {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+
+        // Empty proof = prey concedes (hunter found them)
+        if proof.len() == 0 {
+            game.searched_tiles_x = vec![&env];
+            game.searched_tiles_y = vec![&env];
+            end_round(&env, &key, &mut game, true);
+            return Ok(());
+        }
+
+        // --- Verify public inputs match game state ---
+        check_search_inputs(&game, &game.prey_commitment, &proof);
+
+        // --- Verify the ZK proof ---
+        Self::verify_proof(&env, &DataKey::SearchVk, &proof)?;
+
+        // All searches responded in one proof, prey is safe
+        game.searched_tiles_x = vec![&env];
+        game.searched_tiles_y = vec![&env];
+        end_hunter_turn(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Prey answers a sonar ping with a ZK proof that their committed position
+    /// is (or is not) within `sonar_radius` of the hunter.
+    ///
+    /// Proof blob layout (after 4-byte num_fields header):
+    ///   bytes 4..36:    commitment
+    ///   bytes 36..68:   hunter_x (u8 in last byte)
+    ///   bytes 68..100:  hunter_y (u8 in last byte)
+    ///   bytes 100..132: radius (u8 in last byte)
+    ///   bytes 132..164: inside (bool in last byte)
+    pub fn respond_sonar(
+        env: Env,
+        session_id: u32,
+        inside: bool,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::respond_sonar_signed(env, session_id, None, inside, proof)
+    }
+
+    /// `respond_sonar` signed by `signer`: the player or their session key.
+    pub fn respond_sonar_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        inside: bool,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::respond_sonar_signed(env, session_id, Some(signer), inside, proof)
+    }
+
+    fn respond_sonar_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        inside: bool,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::SonarPending {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+
+        let proof_commitment = extract_bytes32(&proof, 4);
+        assert!(
+            proof_commitment == game.prey_commitment,
+            "proof commitment does not match game state"
+        );
+        assert!(
+            extract_u8(&proof, 36) == game.hunter_x as u8
+                && extract_u8(&proof, 68) == game.hunter_y as u8,
+            "proof hunter position does not match game state"
+        );
+        assert!(
+            extract_u8(&proof, 100) == game.sonar_radius as u8,
+            "proof radius does not match game state"
+        );
+        assert!(
+            extract_u8(&proof, 132) == inside as u8,
+            "proof answer does not match argument"
+        );
+
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Sonar), &proof)?;
+
+        game.last_sonar_inside = Some(inside);
+        end_hunter_turn(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Prey responds to a flare with a ZK proof that they are on none of the lit tiles.
+    /// An empty proof concedes the round.
+    ///
+    /// Proof blob layout (after 4-byte num_fields header):
+    ///   bytes 4..36:  commitment
+    ///   bytes 36..68: mask (u64 in last 8 bytes)
+    pub fn respond_flare(env: Env, session_id: u32, proof: Bytes) -> Result<(), Error> {
+        Self::respond_flare_signed(env, session_id, None, proof)
+    }
+
+    /// `respond_flare` signed by `signer`: the player or their session key.
+    pub fn respond_flare_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::respond_flare_signed(env, session_id, Some(signer), proof)
+    }
+
+    fn respond_flare_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::FlarePending {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+
+        // Empty proof = prey concedes (flare lit them up)
+        if proof.is_empty() {
+            game.flare_mask = 0;
+            end_round(&env, &key, &mut game, true);
+            return Ok(());
+        }
+
+        let proof_commitment = extract_bytes32(&proof, 4);
+        assert!(
+            proof_commitment == game.prey_commitment,
+            "proof commitment does not match game state"
+        );
+        assert!(
+            extract_u64(&proof, 36) == game.flare_mask,
+            "proof mask does not match game state"
+        );
+
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Flare), &proof)?;
+
+        game.flare_mask = 0;
+        end_hunter_turn(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Prey answers a search with a decoy instead: a `decoy_discard` proof that
+    /// the decoy at `index` sits on one of the searched tiles. The decoy absorbs
+    /// the search, whether or not the prey was hit too: it is discarded and the
+    /// search resolved as a miss.
+    ///
+    /// Same proof blob layout as `respond_search`, with the decoy's commitment.
+    pub fn respond_search_with_decoy(
+        env: Env,
+        session_id: u32,
+        index: u32,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::respond_search_with_decoy_signed(env, session_id, None, index, proof)
+    }
+
+    /// `respond_search_with_decoy` signed by `signer`: the player or their session key.
+    pub fn respond_search_with_decoy_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        index: u32,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::respond_search_with_decoy_signed(env, session_id, Some(signer), index, proof)
+    }
+
+    fn respond_search_with_decoy_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        index: u32,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::SearchPending {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+
+        let decoy = game.decoys.get(index).ok_or(Error::DecoyNotFound)?;
+        check_search_inputs(&game, &decoy.commitment, &proof);
+
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Decoy), &proof)?;
+
+        game.decoys.remove(index);
+        game.searched_tiles_x = vec![&env];
+        game.searched_tiles_y = vec![&env];
+        end_hunter_turn(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Hunter uses EMP to freeze visible prey for `emp_duration` turns (1 use per round).
+    /// With an `emp_range` the prey must be within that Chebyshev distance.
+    pub fn hunter_emp(env: Env, session_id: u32) -> Result<(), Error> {
+        Self::hunter_emp_signed(env, session_id, None)
+    }
+
+    /// `hunter_emp` signed by `signer`: the player or their session key.
+    pub fn hunter_emp_as(env: Env, session_id: u32, signer: Address) -> Result<(), Error> {
+        Self::hunter_emp_signed(env, session_id, Some(signer))
+    }
+
+    fn hunter_emp_signed(env: Env, session_id: u32, signer: Option<Address>) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::HunterTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
+
+        charge(&mut game, Ability::Emp)?;
+
+        if game.prey_is_hidden {
+            return Err(Error::EmpTargetHidden);
+        }
+
+        let range = game.config.emp_range;
+        if range > 0 {
+            require_visible_hunter(&game)?;
+            let distance = abs_diff(game.hunter_x, game.prey_x).max(abs_diff(game.hunter_y, game.prey_y));
+            if distance > range {
+                return Err(Error::EmpOutOfRange);
+            }
+        }
+
+        game.prey_is_frozen = true;
+        game.prey_frozen_turns = game.config.emp_duration.max(1);
+        // Phase stays HunterTurn — hunter can still move this turn; the
+        // frozen prey's turns are skipped when the hunter's turns end
+
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Skip a frozen prey's turn. Frozen turns are normally skipped as the
+    /// hunter's turn ends; this settles one left pending, and anyone may call it.
+    pub fn prey_pass_frozen(env: Env, session_id: u32) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        if !game.prey_is_frozen {
+            return Err(Error::WrongPhase);
+        }
+
+        skip_frozen_turn(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Prey dashes up to 2 tiles on plains in a single move (2 uses per round).
+    pub fn prey_dash_public(env: Env, session_id: u32, x: u32, y: u32) -> Result<(), Error> {
+        Self::prey_dash_public_signed(env, session_id, None, x, y)
+    }
+
+    /// `prey_dash_public` signed by `signer`: the player or their session key.
+    pub fn prey_dash_public_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        x: u32,
+        y: u32,
+    ) -> Result<(), Error> {
+        Self::prey_dash_public_signed(env, session_id, Some(signer), x, y)
+    }
+
+    fn prey_dash_public_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        x: u32,
+        y: u32,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::PreyTurn {
+            return Err(Error::WrongPhase);
+        }
+
+        require_player_auth(&env, session_id, &game.prey, &signer)?;
+        check_prey_obligations(&game)?;
+
+        if game.prey_is_frozen {
+            return Err(Error::PreyFrozen);
+        }
+
+        charge(&mut game, Ability::Dash)?;
+
+        if x >= 8 || y >= 8 {
+            return Err(Error::OutOfBounds);
+        }
+
+        // Must move to plains
+        if is_jungle(&game, x, y) {
+            return Err(Error::IsJungle);
+        }
+
+        // Dash distance: Manhattan <= 2
+        let dx = abs_diff(x, game.prey_x);
+        let dy = abs_diff(y, game.prey_y);
+        if dx + dy > 2 {
+            return Err(Error::InvalidMove);
+        }
+
+        game.prey_x = x;
+        game.prey_y = y;
+        game.prey_is_hidden = false;
+
+        check_prey_survival(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Hunter claims catch (prey failed to respond to search). A flare, sonar
+    /// ping or burn left unanswered past its deadline is claimed the same way.
+    pub fn claim_catch(env: Env, session_id: u32) -> Result<Address, Error> {
+        Self::claim_catch_signed(env, session_id, None)
+    }
+
+    /// `claim_catch` signed by `signer`: the player or their session key.
+    pub fn claim_catch_as(env: Env, session_id: u32, signer: Address) -> Result<Address, Error> {
+        Self::claim_catch_signed(env, session_id, Some(signer))
+    }
+
+    fn claim_catch_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+    ) -> Result<Address, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        match game.phase {
+            GamePhase::SearchPending => {}
+            GamePhase::FlarePending | GamePhase::SonarPending | GamePhase::BurnPending => {
+                if env.ledger().sequence() <= game.response_deadline {
+                    return Err(Error::DeadlineNotReached);
+                }
+            }
+            _ => return Err(Error::WrongPhase),
+        }
+
+        require_player_auth(&env, session_id, &game.hunter, &signer)?;
+
+        game.flare_mask = 0;
+        end_round(&env, &key, &mut game, true);
+
+        let winner_or_hunter = game.winner.clone().unwrap_or(game.hunter.clone());
+        Ok(winner_or_hunter)
+    }
+
+    /// Prey reveals where it was hidden during a finished round (`audit` rule).
+    /// Each revealed tile (`y * 8 + x`) and nonce must open the commitment
+    /// stored for that turn, checked by a `round_audit` proof. A tile that is
+    /// not jungle on the round's map fails the audit; in a staked match that
+    /// forfeits the round and the next round starts either way.
+    ///
+    /// Proof blob layout (after 4-byte num_fields header):
+    ///   bytes 4..356:     commitments[0..11] (unused slots 0)
+    ///   bytes 356..708:   positions[0..11] (u8 in last byte)
+    ///   bytes 708..1060:  nonces[0..11]
+    ///   bytes 1060..1092: count (u8 in last byte)
+    pub fn reveal_round(
+        env: Env,
+        session_id: u32,
+        round: u32,
+        positions: Vec<u32>,
+        nonces: Vec<BytesN<32>>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::reveal_round_signed(env, session_id, None, round, positions, nonces, proof)
+    }
+
+    /// `reveal_round` signed by `signer`: the player or their session key.
+    pub fn reveal_round_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        round: u32,
+        positions: Vec<u32>,
+        nonces: Vec<BytesN<32>>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::reveal_round_signed(env, session_id, Some(signer), round, positions, nonces, proof)
+    }
+
+    fn reveal_round_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        round: u32,
+        positions: Vec<u32>,
+        nonces: Vec<BytesN<32>>,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        let transcript_key = DataKey::Transcript(session_id, round);
+        let mut transcript: RoundTranscript = env
+            .storage()
+            .temporary()
+            .get(&transcript_key)
+            .ok_or(Error::TranscriptNotFound)?;
+
+        require_player_auth(&env, session_id, &transcript.prey, &signer)?;
+
+        if transcript.result != AuditResult::Pending {
+            return Err(Error::AlreadyAudited);
+        }
+
+        if positions.iter().any(|tile| tile >= 64) {
+            return Err(Error::OutOfBounds);
+        }
+
+        let count = transcript.commitments.len();
+        assert!(
+            positions.len() == count && nonces.len() == count,
+            "reveal length does not match transcript"
+        );
+        for i in 0..count {
+            let record = transcript.commitments.get(i).unwrap();
+            assert!(
+                extract_bytes32(&proof, 4 + i * 32) == record.commitment,
+                "proof commitment does not match transcript"
+            );
+            assert!(
+                extract_u8(&proof, 356 + i * 32) == positions.get(i).unwrap() as u8,
+                "proof position does not match argument"
+            );
+            assert!(
+                extract_bytes32(&proof, 708 + i * 32) == nonces.get(i).unwrap(),
+                "proof nonce does not match argument"
+            );
+        }
+        assert!(
+            extract_u8(&proof, 1060) == count as u8,
+            "proof count does not match transcript"
+        );
+
+        Self::verify_proof(&env, &DataKey::Vk(Circuit::Audit), &proof)?;
+
+        let map = &MAPS[transcript.map_index as usize];
+        let passed = positions.iter().all(|tile| map[tile as usize] == 1)
+            && revealed_path_is_legal(&transcript, &positions);
+        transcript.result = if passed {
+            AuditResult::Passed
+        } else {
+            AuditResult::Failed
+        };
+        env.storage().temporary().set(&transcript_key, &transcript);
+
+        RoundAudited {
+            session_id,
+            round,
+            passed,
+        }
+        .publish(&env);
+
+        if game.phase == GamePhase::AuditPending && game.round == round {
+            if !passed {
+                forfeit_round(&mut game, &transcript.hunter, &transcript.prey, &transcript.round_winner);
+            }
+            next_round(&env, &key, &mut game);
+        }
+
+        Ok(())
+    }
+
+    /// The hunter of a staked audited round claims it after the prey missed
+    /// the transcript reveal deadline. The round is forfeited to the hunter.
+    pub fn claim_audit_timeout(env: Env, session_id: u32) -> Result<(), Error> {
+        Self::claim_audit_timeout_signed(env, session_id, None)
+    }
+
+    /// `claim_audit_timeout` signed by `signer`: the player or their session key.
+    pub fn claim_audit_timeout_as(env: Env, session_id: u32, signer: Address) -> Result<(), Error> {
+        Self::claim_audit_timeout_signed(env, session_id, Some(signer))
+    }
+
+    fn claim_audit_timeout_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::AuditPending {
+            return Err(Error::WrongPhase);
+        }
+
+        let transcript_key = DataKey::Transcript(session_id, game.round);
+        let mut transcript: RoundTranscript = env
+            .storage()
+            .temporary()
+            .get(&transcript_key)
+            .ok_or(Error::TranscriptNotFound)?;
+
+        require_player_auth(&env, session_id, &transcript.hunter, &signer)?;
+
+        if env.ledger().sequence() <= transcript.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        transcript.result = AuditResult::Failed;
+        env.storage().temporary().set(&transcript_key, &transcript);
+
+        RoundAudited {
+            session_id,
+            round: game.round,
+            passed: false,
+        }
+        .publish(&env);
+
+        forfeit_round(&mut game, &transcript.hunter, &transcript.prey, &transcript.round_winner);
+        next_round(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Submit the proof of one hidden move of a finished optimistic round.
+    /// Each proof is stored on its own, as a whole round of them would not
+    /// fit in one entry or transaction. Only its public inputs are checked
+    /// here; the proof itself is verified when the hunter challenges the
+    /// step. The last proof opens the challenge window.
+    pub fn submit_step_proof(
+        env: Env,
+        session_id: u32,
+        step: u32,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::submit_step_proof_signed(env, session_id, None, step, proof)
+    }
+
+    /// `submit_step_proof` signed by `signer`: the player or their session key.
+    pub fn submit_step_proof_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        step: u32,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        Self::submit_step_proof_signed(env, session_id, Some(signer), step, proof)
+    }
+
+    fn submit_step_proof_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        step: u32,
+        proof: Bytes,
+    ) -> Result<(), Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::MovesPending {
+            return Err(Error::WrongPhase);
+        }
+
+        let batch_key = DataKey::MoveBatch(session_id);
+        let mut batch: MoveBatch = env.storage().temporary().get(&batch_key).unwrap();
+
+        require_player_auth(&env, session_id, &batch.prey, &signer)?;
+
+        let move_step = batch.steps.get(step).ok_or(Error::StepNotFound)?;
+        if batch.proven_steps & (1 << step) != 0 {
+            return Err(Error::ProofsAlreadySubmitted);
+        }
+        check_step_inputs(&batch, &move_step, &proof);
+
+        let proof_key = DataKey::StepProof(session_id, step);
+        env.storage().temporary().set(&proof_key, &proof);
+        env.storage()
+            .temporary()
+            .extend_ttl(&proof_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        batch.proven_steps |= 1 << step;
+        if all_steps_proven(&batch) {
+            batch.deadline = env.ledger().sequence() + CHALLENGE_WINDOW_LEDGERS;
+        }
+        env.storage().temporary().set(&batch_key, &batch);
+
+        Ok(())
+    }
+
+    /// The hunter challenges one step of the submitted batch. Its proof is
+    /// verified now; if it fails, the round is forfeited to the hunter and the
+    /// next round starts. Returns whether the challenge succeeded.
+    pub fn challenge_step(env: Env, session_id: u32, step: u32) -> Result<bool, Error> {
+        Self::challenge_step_signed(env, session_id, None, step)
+    }
+
+    /// `challenge_step` signed by `signer`: the player or their session key.
+    pub fn challenge_step_as(
+        env: Env,
+        session_id: u32,
+        signer: Address,
+        step: u32,
+    ) -> Result<bool, Error> {
+        Self::challenge_step_signed(env, session_id, Some(signer), step)
+    }
+
+    fn challenge_step_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+        step: u32,
+    ) -> Result<bool, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::MovesPending {
+            return Err(Error::WrongPhase);
+        }
+
+        let batch_key = DataKey::MoveBatch(session_id);
+        let batch: MoveBatch = env.storage().temporary().get(&batch_key).unwrap();
+
+        require_player_auth(&env, session_id, &batch.hunter, &signer)?;
+
+        if !all_steps_proven(&batch) {
+            return Err(Error::ProofsNotSubmitted);
+        }
+
+        if env.ledger().sequence() > batch.deadline {
+            return Err(Error::ChallengeWindowClosed);
+        }
+
+        let move_step = batch.steps.get(step).ok_or(Error::StepNotFound)?;
+        let vk_key = if move_step.dash {
+            DataKey::Vk(Circuit::Dash)
+        } else {
+            DataKey::MoveVk
+        };
+        let proof: Bytes = env
+            .storage()
+            .temporary()
+            .get(&DataKey::StepProof(session_id, step))
+            .unwrap();
+        let upheld = !Self::proof_is_valid(&env, &vk_key, &proof);
+
+        StepChallenged {
+            session_id,
+            round: batch.round,
+            step,
+            upheld,
+        }
+        .publish(&env);
+
+        if upheld {
+            forfeit_round(&mut game, &batch.hunter, &batch.prey, &batch.round_winner);
+            remove_move_batch(&env, session_id, &batch);
+            next_round(&env, &key, &mut game);
+        }
+
+        Ok(upheld)
+    }
+
+    /// Close an optimistic round and start the next one. Anyone can call it
+    /// after the deadline: if the prey never submitted its proofs, the round
+    /// is forfeited to the hunter. Before the deadline, the hunter can call it
+    /// to waive the rest of the challenge window.
+    pub fn settle_moves(env: Env, session_id: u32) -> Result<(), Error> {
+        Self::settle_moves_signed(env, session_id, None)
+    }
+
+    /// `settle_moves` signed by `signer`: the player or their session key.
+    pub fn settle_moves_as(env: Env, session_id: u32, signer: Address) -> Result<(), Error> {
+        Self::settle_moves_signed(env, session_id, Some(signer))
+    }
+
+    fn settle_moves_signed(
+        env: Env,
+        session_id: u32,
+        signer: Option<Address>,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase != GamePhase::MovesPending {
+            return Err(Error::WrongPhase);
+        }
+
+        let batch_key = DataKey::MoveBatch(session_id);
+        let batch: MoveBatch = env.storage().temporary().get(&batch_key).unwrap();
+
+        if env.ledger().sequence() <= batch.deadline {
+            if !all_steps_proven(&batch) {
+                return Err(Error::DeadlineNotReached);
+            }
+            require_player_auth(&env, session_id, &batch.hunter, &signer)?;
+        } else if !all_steps_proven(&batch) {
+            forfeit_round(&mut game, &batch.hunter, &batch.prey, &batch.round_winner);
+        }
+
+        remove_move_batch(&env, session_id, &batch);
+        next_round(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Resend a session's queued Game Hub notifications in order, stopping
+    /// at the first that fails again. Ones the hub rejects with a contract
+    /// error are dropped. Anyone can call it. Returns how many were delivered.
+    pub fn retry_hub_notifications(env: Env, session_id: u32) -> u32 {
+        let key = DataKey::PendingHub(session_id);
+        let mut pending: Vec<HubNotification> = env.storage().persistent().get(&key).unwrap_or(vec![&env]);
+
+        let mut delivered = 0;
+        while let Some(notification) = pending.first() {
+            match send_hub_notification(&env, session_id, &notification) {
+                HubDelivery::Delivered => delivered += 1,
+                HubDelivery::Rejected => {}
+                HubDelivery::Failed => break,
+            }
+            pending.pop_front();
+        }
+
+        if pending.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &pending);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+
+        delivered
+    }
+
+    pub fn get_pending_hub_notifications(env: Env, session_id: u32) -> Vec<HubNotification> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingHub(session_id))
+            .unwrap_or(vec![&env])
+    }
+
+    /// Read the optimistic round waiting to be settled, if any.
+    pub fn get_move_batch(env: Env, session_id: u32) -> Option<MoveBatch> {
+        env.storage().temporary().get(&DataKey::MoveBatch(session_id))
+    }
+
+    /// Read the transcript of a finished round (`audit` rule).
+    pub fn get_transcript(env: Env, session_id: u32, round: u32) -> Result<RoundTranscript, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Transcript(session_id, round))
+            .ok_or(Error::TranscriptNotFound)
+    }
+
+    /// Register a session key that signs `player`'s game actions in this
+    /// session until `expires_ledger`, through each action's `_as` entry point.
+    /// The plain entry points keep taking the player's own signature. The key
+    /// cannot act in any other session, nor join, reveal seeds or manage
+    /// session keys.
+    pub fn register_session_key(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        session_key: Address,
+        expires_ledger: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase == GamePhase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        if player != game.player1
+            && player != game.player2
+            && !game.teams.team1.contains(&player)
+            && !game.teams.team2.contains(&player)
+        {
+            return Err(Error::NotPlayer);
+        }
+
+        if expires_ledger <= env.ledger().sequence() {
+            return Err(Error::SessionKeyExpired);
+        }
+
+        let key = DataKey::SessionKey(session_id, player);
+        env.storage().temporary().set(
+            &key,
+            &SessionKey {
+                key: session_key,
+                expires_ledger,
+            },
+        );
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Remove `player`'s session key; only the player can sign its game actions again.
+    pub fn revoke_session_key(env: Env, session_id: u32, player: Address) {
+        player.require_auth();
+
+        env.storage()
+            .temporary()
+            .remove(&DataKey::SessionKey(session_id, player));
+    }
+
+    /// Read `player`'s session key for a session, if one is registered.
+    pub fn get_session_key(env: Env, session_id: u32, player: Address) -> Option<SessionKey> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::SessionKey(session_id, player))
+    }
+
+    /// Register `player`'s ed25519 key for channel play. Once both players
+    /// have one, they can exchange signed `ChannelUpdate`s off-chain and only
+    /// come back on-chain to settle or dispute. Keys cannot be changed later.
+    pub fn open_channel(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        channel_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase == GamePhase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        if game.config.team_mode != TeamMode::Solo {
+            return Err(Error::RuleDisabled);
+        }
+
+        let key = DataKey::Channel(session_id);
+        let mut channel: Channel = env.storage().temporary().get(&key).unwrap_or(Channel {
+            player1_key: None,
+            player2_key: None,
+            seq: 0,
+            challenger: None,
+            dispute_deadline: 0,
+            disputed_round: 0,
+            disputed_turn: 0,
+            disputed_phase: GamePhase::WaitingForPlayer2,
+        });
+
+        let slot = if player == game.player1 {
+            &mut channel.player1_key
+        } else if player == game.player2 {
+            &mut channel.player2_key
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if slot.is_some() {
+            return Err(Error::ChannelAlreadyOpen);
+        }
+        *slot = Some(channel_key);
+
+        env.storage().temporary().set(&key, &channel);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Settle a match played off-chain: post the final state (`phase == Ended`)
+    /// signed by both players. The winner follows from the signed scores and
+    /// the Game Hub is notified as for an on-chain match.
+    pub fn settle_match(
+        env: Env,
+        session_id: u32,
+        update: ChannelUpdate,
+        player1_sig: BytesN<64>,
+        player2_sig: BytesN<64>,
+    ) -> Result<(), Error> {
+        let key = DataKey::Game(session_id);
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase == GamePhase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let channel_key = DataKey::Channel(session_id);
+        let mut channel = verify_channel_update(&env, session_id, &game, &update, &player1_sig, &player2_sig)?;
+
+        if update.game.phase != GamePhase::Ended {
+            return Err(Error::WrongPhase);
+        }
+
+        channel.seq = update.seq;
+        channel.challenger = None;
+        env.storage().temporary().set(&channel_key, &channel);
+
+        let mut game = update.game;
+        finish_match(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /// Post the latest state signed by both players when the opponent stops
+    /// answering off-chain. It replaces the on-chain game state wholesale
+    /// (only the match config and rosters must match, and round and scores
+    /// cannot go back). Its `seq` must be above the channel's, which every
+    /// on-chain action advances, so it cannot roll back on-chain moves. The
+    /// opponent (who must be the one to move) has until the deadline to
+    /// answer with an on-chain move or a newer signed state handing the move
+    /// back.
+    pub fn dispute(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        update: ChannelUpdate,
+        player1_sig: BytesN<64>,
+        player2_sig: BytesN<64>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase == GamePhase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        let mut channel = verify_channel_update(&env, session_id, &game, &update, &player1_sig, &player2_sig)?;
+
+        // Only the player waiting on the opponent can dispute; whoever is to
+        // move just plays on-chain
+        match player_to_move(&update.game) {
+            Some(to_move) if to_move != player => {}
+            Some(_) => return Err(Error::NotYourTurn),
+            None => return Err(Error::WrongPhase),
+        }
+
+        let game = update.game;
+        channel.seq = update.seq;
+        channel.challenger = Some(player);
+        channel.dispute_deadline = env.ledger().sequence() + DISPUTE_TIMEOUT_LEDGERS;
+        channel.disputed_round = game.round;
+        channel.disputed_turn = game.turn_number;
+        channel.disputed_phase = game.phase.clone();
+
+        let channel_key = DataKey::Channel(session_id);
+        env.storage().temporary().set(&channel_key, &channel);
+        env.storage()
+            .temporary()
+            .extend_ttl(&channel_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        env.storage().temporary().set(&key, &game);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// After the dispute deadline, end the match in the challenger's favour if
+    /// the disputed state was never moved on.
+    pub fn finalize_dispute(env: Env, session_id: u32) -> Result<Address, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.phase == GamePhase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let channel: Channel = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Channel(session_id))
+            .ok_or(Error::ChannelNotOpen)?;
+        let challenger = channel.challenger.ok_or(Error::NoDispute)?;
+
+        if env.ledger().sequence() <= channel.dispute_deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        if game.round != channel.disputed_round
+            || game.turn_number != channel.disputed_turn
+            || game.phase != channel.disputed_phase
+        {
+            return Err(Error::DisputeAnswered);
+        }
+
+        let player1_won = challenger == game.player1;
+        game.winner = Some(challenger.clone());
+        game.phase = GamePhase::Ended;
+        env.storage().temporary().set(&key, &game);
+
+        notify_game_hub_end(&env, session_id, player1_won);
+
+        Ok(challenger)
+    }
+
+    /// Read a session's channel state, if channel play was opened.
+    pub fn get_channel(env: Env, session_id: u32) -> Option<Channel> {
+        env.storage().temporary().get(&DataKey::Channel(session_id))
+    }
+
+    /// Read the seed commitments of a commit-reveal game.
+    pub fn get_seeds(env: Env, session_id: u32) -> Result<SeedState, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Seeds(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    /// Read game state (for frontend polling).
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        let key = DataKey::Game(session_id);
+        env.storage()
             .temporary()
             .get(&key)
             .ok_or(Error::GameNotFound)
     }
 
-    // ========================================================================
-    // Internal helpers
-    // ========================================================================
+    // ========================================================================
+    // Internal helpers
+    // ========================================================================
+
+    fn verify_proof(env: &Env, vk_key: &DataKey, proof: &Bytes) -> Result<(), Error> {
+        let ultrahonk_addr = Address::from_str(env, ULTRAHONK_CONTRACT_ADDRESS);
+        let ultrahonk_client = ultrahonk_contract::Client::new(env, &ultrahonk_addr);
+
+        let vk: Bytes = env
+            .storage()
+            .instance()
+            .get(vk_key)
+            .expect("VK not set");
+
+        ultrahonk_client.verify_proof(&vk, proof);
+
+        Ok(())
+    }
+
+    /// Like `verify_proof`, but reports an invalid proof instead of failing the call.
+    fn proof_is_valid(env: &Env, vk_key: &DataKey, proof: &Bytes) -> bool {
+        let ultrahonk_addr = Address::from_str(env, ULTRAHONK_CONTRACT_ADDRESS);
+        let ultrahonk_client = ultrahonk_contract::Client::new(env, &ultrahonk_addr);
+
+        let vk: Bytes = env
+            .storage()
+            .instance()
+            .get(vk_key)
+            .expect("VK not set");
+
+        matches!(ultrahonk_client.try_verify_proof(&vk, proof), Ok(Ok(_)))
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Game Setup
+// ============================================================================
+
+/// Allocate a session id and store a new game waiting for the prey.
+fn new_game(
+    env: &Env,
+    hunter: Address,
+    config: GameConfig,
+    seed_hash: Option<BytesN<32>>,
+) -> u32 {
+    let session_id: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextSessionId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextSessionId, &(session_id + 1));
+
+    let energy_max = config.energy.max;
+    let item_count = config.item_count;
+
+    // Commit-reveal games re-roll map and spawns once both seeds are revealed
+    let map_index = select_random_map(env);
+    let (hx, hy, px, py) = random_starting_positions(env, map_index);
+    let item_candidates = random_item_tiles(env, map_index, item_count * ITEM_CANDIDATES_PER_ITEM);
+    let prey_trail = spawn_trail(env, &config, px, py);
+
+    let game = Game {
+        hunter: hunter.clone(),
+        prey: hunter.clone(), // placeholder until prey joins
+        hunter_x: hx,
+        hunter_y: hy,
+        prey_x: px,
+        prey_y: py,
+        prey_is_hidden: false,
+        prey_commitment: BytesN::from_array(env, &[0u8; 32]),
+        hunter_is_hidden: false,
+        hunter_commitment: BytesN::from_array(env, &[0u8; 32]),
+        phase: GamePhase::WaitingForPlayer2,
+        turn_number: 0,
+        power_searches_remaining: POWER_SEARCHES_INITIAL,
+        searched_tiles_x: vec![env],
+        searched_tiles_y: vec![env],
+        winner: None,
+        player1: hunter.clone(),
+        player2: hunter.clone(), // placeholder until prey joins
+        round: 1,
+        total_rounds: TOTAL_ROUNDS,
+        player1_score: 0,
+        player2_score: 0,
+        map_index,
+        emp_uses_remaining: 1,
+        prey_is_frozen: false,
+        prey_frozen_turns: 0,
+        prey_dash_remaining: 2,
+        config,
+        draft_pool: vec![env],
+        draft_deadline: 0,
+        hunter_energy: energy_max,
+        prey_energy: energy_max,
+        sonar_uses_remaining: SONAR_USES_INITIAL,
+        sonar_radius: 0,
+        last_sonar_inside: None,
+        response_deadline: 0,
+        flare_uses_remaining: FLARE_USES_INITIAL,
+        flare_mask: 0,
+        traps: vec![env],
+        trap_check_mask: 0,
+        prey_trail,
+        decoys: vec![env],
+        burned_tiles: 0,
+        zone_proven_turn: 0,
+        zone_commitment: BytesN::from_array(env, &[0u8; 32]),
+        scent_history: vec![env],
+        scent_trail: vec![env],
+        commitment_history: vec![env],
+        dash_turns: vec![env],
+        pending_steps: vec![env],
+        item_candidates,
+        items_commitment: None,
+        item_nullifiers: vec![env],
+        items_collected: 0,
+        teams: Teams {
+            team1: vec![env],
+            team2: vec![env],
+            bench: vec![env],
+        },
+    };
+
+    let key = DataKey::Game(session_id);
+    env.storage().temporary().set(&key, &game);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+    if let Some(hash) = seed_hash.filter(|_| game.config.commit_reveal) {
+        let seeds = SeedState {
+            player1_hash: hash,
+            player2_hash: BytesN::from_array(env, &[0u8; 32]),
+            player1_seed: None,
+            player2_seed: None,
+            reveal_deadline: 0,
+            match_seed: None,
+        };
+        let seeds_key = DataKey::Seeds(session_id);
+        env.storage().temporary().set(&seeds_key, &seeds);
+        env.storage()
+            .temporary()
+            .extend_ttl(&seeds_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    session_id
+}
+
+/// Seat the prey. Commit-reveal games require `seed_hash` and wait in
+/// `SeedReveal`; all others start round 1 immediately.
+fn join(
+    env: &Env,
+    session_id: u32,
+    prey: Address,
+    seed_hash: Option<BytesN<32>>,
+) -> Result<(), Error> {
+    let key = DataKey::Game(session_id);
+    let mut game: Game = env
+        .storage()
+        .temporary()
+        .get(&key)
+        .ok_or(Error::GameNotFound)?;
+
+    if game.phase != GamePhase::WaitingForPlayer2 {
+        return Err(Error::WrongPhase);
+    }
+    // Team games keep waiting for partners after player 2 is seated
+    if game.player2 != game.player1 {
+        return Err(Error::WrongPhase);
+    }
+    // Player 2 still holds player 1's address until seated, so player 1 can't take it
+    if prey == game.player1
+        || game.teams.team1.contains(&prey)
+        || game.teams.team2.contains(&prey)
+    {
+        return Err(Error::AlreadySeated);
+    }
+
+    game.prey = prey.clone();
+    game.player2 = prey;
+
+    if game.config.commit_reveal {
+        let seeds_key = DataKey::Seeds(session_id);
+        let mut seeds: SeedState = env
+            .storage()
+            .temporary()
+            .get(&seeds_key)
+            .ok_or(Error::GameNotFound)?;
+        seeds.player2_hash = seed_hash.ok_or(Error::SeedRequired)?;
+        env.storage().temporary().set(&seeds_key, &seeds);
+    } else if seed_hash.is_some() {
+        return Err(Error::RuleDisabled);
+    }
+
+    start_if_seated(env, session_id, &mut game);
+
+    env.storage().temporary().set(&key, &game);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+    Ok(())
+}
+
+/// Start the session once player 2 and every team partner are seated:
+/// commit-reveal games wait in `SeedReveal`, all others start round 1.
+fn start_if_seated(env: &Env, session_id: u32, game: &mut Game) {
+    let (team1_partners, team2_partners) = match game.config.team_mode {
+        TeamMode::Solo => (0, 0),
+        TeamMode::TwoVsOne => (1, 0),
+        TeamMode::TwoVsTwo => (1, 1),
+    };
+    let player2_seated = game.player2 != game.player1;
+    if !player2_seated
+        || game.teams.team1.len() < team1_partners
+        || game.teams.team2.len() < team2_partners
+    {
+        return;
+    }
+
+    if game.config.commit_reveal {
+        let seeds_key = DataKey::Seeds(session_id);
+        let mut seeds: SeedState = env
+            .storage()
+            .temporary()
+            .get(&seeds_key)
+            .expect("seeds not set");
+        seeds.reveal_deadline = env.ledger().sequence() + SEED_REVEAL_TIMEOUT_LEDGERS;
+        env.storage().temporary().set(&seeds_key, &seeds);
+        env.storage()
+            .temporary()
+            .extend_ttl(&seeds_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        game.phase = GamePhase::SeedReveal;
+    } else {
+        begin_match(env, game);
+    }
+
+    // Notify Game Hub that a game session started
+    if game.config.team_mode == TeamMode::Solo {
+        notify_game_hub_start(
+            env,
+            session_id,
+            game.player1.clone(),
+            game.player2.clone(),
+        );
+    } else {
+        let mut team1 = vec![env, game.player1.clone()];
+        team1.append(&game.teams.team1);
+        let mut team2 = vec![env, game.player2.clone()];
+        team2.append(&game.teams.team2);
+        notify_game_hub_start_teams(env, session_id, team1, team2);
+    }
+}
+
+/// Start the match once both players are seated and seeds (if any) are
+/// revealed: open the map draft if enabled, otherwise begin round 1.
+fn begin_match(env: &Env, game: &mut Game) {
+    if game.config.map_draft {
+        game.draft_pool = draw_draft_pool(env);
+        game.draft_deadline = env.ledger().sequence() + DRAFT_TURN_LEDGERS;
+        game.phase = GamePhase::MapDraft;
+    } else if game.config.commit_reveal {
+        // Re-roll map and spawns from the (already seeded) PRNG
+        reset_round(env, game);
+    } else {
+        game.phase = round_start_phase(game);
+        game.turn_number = 1;
+    }
+}
+
+/// Hidden-spawn rounds open with the Prey's spawn commitment, others with the Hunter.
+fn round_start_phase(game: &Game) -> GamePhase {
+    if game.config.hidden_spawn {
+        GamePhase::PreySpawn
+    } else {
+        GamePhase::HunterTurn
+    }
+}
+
+/// Draw `DRAFT_POOL_SIZE` distinct maps for a map draft.
+fn draw_draft_pool(env: &Env) -> Vec<u32> {
+    let mut pool: Vec<u32> = vec![env];
+    while pool.len() < DRAFT_POOL_SIZE {
+        let map_index = select_random_map(env);
+        if !pool.contains(map_index) {
+            pool.push_back(map_index);
+        }
+    }
+    pool
+}
+
+/// Player whose turn it is to ban: player2 makes the first ban, then alternate.
+fn draft_player(game: &Game) -> Address {
+    let bans_made = DRAFT_POOL_SIZE - game.draft_pool.len();
+    if bans_made.is_multiple_of(2) {
+        game.player2.clone()
+    } else {
+        game.player1.clone()
+    }
+}
+
+/// After a ban: start round 1 once the pool is down to one map per round,
+/// otherwise hand the next ban to the other player.
+fn advance_draft(env: &Env, session_id: u32, game: &mut Game) {
+    if game.draft_pool.len() <= game.total_rounds {
+        seed_round_prng(env, session_id, game.round);
+        reset_round(env, game);
+    } else {
+        game.draft_deadline = env.ledger().sequence() + DRAFT_TURN_LEDGERS;
+    }
+}
+
+// ============================================================================
+// Game Hub Notifications
+// ============================================================================
+
+/// Notify the Game Hub that a game session started.
+/// Silently skipped if no GameHub address is configured (local dev).
+fn notify_game_hub_start(env: &Env, session_id: u32, player1: Address, player2: Address) {
+    notify_game_hub(env, session_id, HubNotification::Start(session_id, player1, player2));
+}
+
+/// Team games report full rosters through a V2 hub's `start_team_game`.
+fn notify_game_hub_start_teams(
+    env: &Env,
+    session_id: u32,
+    team1: Vec<Address>,
+    team2: Vec<Address>,
+) {
+    notify_game_hub(env, session_id, HubNotification::StartTeams(session_id, team1, team2));
+}
+
+/// Notify the Game Hub that the game ended.
+fn notify_game_hub_end(env: &Env, session_id: u32, player1_won: bool) {
+    notify_game_hub(env, session_id, HubNotification::End(session_id, player1_won));
+}
+
+/// Send a notification to the Game Hub, or queue it for
+/// `retry_hub_notifications` if the hub call fails, so a hub problem never
+/// reverts a player's move. Each session has its own queue, kept in order:
+/// while any of its notifications are waiting, new ones go to the back, so
+/// a session's end never overtakes its start. A session sends at most a
+/// start and an end, so a queue never holds more than two.
+/// Silently skipped if no GameHub address is configured (local dev).
+fn notify_game_hub(env: &Env, session_id: u32, notification: HubNotification) {
+    if !env.storage().instance().has(&DataKey::GameHubAddress) {
+        return;
+    }
+
+    let key = DataKey::PendingHub(session_id);
+    let mut pending: Vec<HubNotification> = env.storage().persistent().get(&key).unwrap_or(vec![env]);
+    if pending.is_empty() && send_hub_notification(env, session_id, &notification) != HubDelivery::Failed {
+        return;
+    }
+
+    pending.push_back(notification);
+    env.storage().persistent().set(&key, &pending);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Outcome of one Game Hub call.
+#[derive(Clone, Copy, PartialEq)]
+enum HubDelivery {
+    Delivered,
+    /// The hub returned a contract error; retrying would not help.
+    Rejected,
+    /// The call failed some other way (trap, missing contract); worth retrying.
+    Failed,
+}
+
+/// Make one Game Hub call. A rejected notification is dropped with a
+/// `HubNotificationDropped` event.
+fn send_hub_notification(env: &Env, session_id: u32, notification: &HubNotification) -> HubDelivery {
+    let hub_addr: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::GameHubAddress)
+        .expect("GameHub not set");
+    let interface = env
+        .storage()
+        .instance()
+        .get(&DataKey::GameHubInterface)
+        .unwrap_or(HubInterface::V1);
+    let hub = GameHubClient::new(env, &hub_addr);
+    let hub_v2 = GameHubV2Client::new(env, &hub_addr);
+    let game_id = env.current_contract_address();
+
+    let result = match (notification, interface) {
+        (HubNotification::Start(session_id, player1, player2), _) => {
+            hub.try_start_game(&game_id, session_id, player1, player2, &0, &0)
+        }
+        (HubNotification::StartTeams(session_id, team1, team2), HubInterface::V1) => {
+            hub.try_start_game(&game_id, session_id, &team1.get(0).unwrap(), &team2.get(0).unwrap(), &0, &0)
+        }
+        (HubNotification::StartTeams(session_id, team1, team2), HubInterface::V2) => {
+            hub_v2.try_start_team_game(&game_id, session_id, team1, team2)
+        }
+        (HubNotification::End(session_id, player1_won), HubInterface::V1) => hub.try_end_game(session_id, player1_won),
+        (HubNotification::End(session_id, player1_won), HubInterface::V2) => {
+            hub_v2.try_end_game(&game_id, session_id, player1_won)
+        }
+    };
+    match result {
+        Ok(Ok(())) => HubDelivery::Delivered,
+        Err(Ok(error)) if error.is_type(ScErrorType::Contract) => {
+            HubNotificationDropped {
+                session_id,
+                notification: notification.clone(),
+            }
+            .publish(env);
+            HubDelivery::Rejected
+        }
+        _ => HubDelivery::Failed,
+    }
+}
+
+// ============================================================================
+// Utility
+// ============================================================================
+
+fn abs_diff(a: u32, b: u32) -> u32 {
+    if a > b { a - b } else { b - a }
+}
+
+/// Actions that cost energy under the energy economy.
+#[derive(Clone, Copy)]
+enum Ability {
+    Move,
+    Search,
+    PowerSearch,
+    Emp,
+    Dash,
+    Hide,
+    Sonar,
+    Flare,
+    Trap,
+    Decoy,
+    Burn,
+}
+
+/// Pay for an ability: from the acting role's energy pool when the energy
+/// economy is on, otherwise from the fixed per-round counters.
+fn charge(game: &mut Game, ability: Ability) -> Result<(), Error> {
+    let energy = &game.config.energy;
+    if energy.max > 0 {
+        let cost = match ability {
+            Ability::Move => energy.move_cost,
+            Ability::Search => energy.search_cost,
+            Ability::PowerSearch => energy.power_search_cost,
+            Ability::Emp => energy.emp_cost,
+            Ability::Dash => energy.dash_cost,
+            Ability::Hide => energy.hide_cost,
+            Ability::Sonar => energy.sonar_cost,
+            Ability::Flare => energy.flare_cost,
+            Ability::Trap => energy.trap_cost,
+            Ability::Decoy => energy.decoy_cost,
+            Ability::Burn => energy.burn_cost,
+        };
+        // Moves are paid by whoever's turn it is
+        let pool = match ability {
+            Ability::Move if game.phase == GamePhase::PreyTurn => &mut game.prey_energy,
+            Ability::Move
+            | Ability::Search
+            | Ability::PowerSearch
+            | Ability::Emp
+            | Ability::Sonar
+            | Ability::Flare
+            | Ability::Trap
+            | Ability::Burn => {
+                &mut game.hunter_energy
+            }
+            Ability::Dash | Ability::Hide | Ability::Decoy => &mut game.prey_energy,
+        };
+        if *pool < cost {
+            return Err(Error::NotEnoughEnergy);
+        }
+        *pool -= cost;
+        return Ok(());
+    }
+
+    match ability {
+        Ability::PowerSearch => {
+            if game.power_searches_remaining == 0 {
+                return Err(Error::NoPowerSearches);
+            }
+            game.power_searches_remaining -= 1;
+        }
+        Ability::Emp => {
+            if game.emp_uses_remaining == 0 {
+                return Err(Error::NoEMP);
+            }
+            game.emp_uses_remaining -= 1;
+        }
+        Ability::Dash => {
+            if game.prey_dash_remaining == 0 {
+                return Err(Error::NoDashes);
+            }
+            game.prey_dash_remaining -= 1;
+        }
+        Ability::Sonar => {
+            if game.sonar_uses_remaining == 0 {
+                return Err(Error::NoSonar);
+            }
+            game.sonar_uses_remaining -= 1;
+        }
+        Ability::Flare => {
+            if game.flare_uses_remaining == 0 {
+                return Err(Error::NoFlare);
+            }
+            game.flare_uses_remaining -= 1;
+        }
+        // Traps, decoys and burns are capped by MAX_TRAPS / MAX_DECOYS / MAX_BURNS in both modes
+        Ability::Move
+        | Ability::Search
+        | Ability::Hide
+        | Ability::Trap
+        | Ability::Decoy
+        | Ability::Burn => {}
+    }
+    Ok(())
+}
+
+/// Both energy pools regain `regen` at the start of every turn, capped at `max`.
+fn regen_energy(game: &mut Game) {
+    let energy = &game.config.energy;
+    game.hunter_energy = (game.hunter_energy + energy.regen).min(energy.max);
+    game.prey_energy = (game.prey_energy + energy.regen).min(energy.max);
+}
+
+/// Terrain of the current round: the map's jungle minus burned tiles.
+fn is_jungle(game: &Game, x: u32, y: u32) -> bool {
+    let idx = y * 8 + x;
+    MAPS[game.map_index as usize][idx as usize] == 1 && (game.burned_tiles >> idx) & 1 == 0
+}
+
+/// Rings of the board that are hazardous this turn (0 = whole board safe, 3 = only the central 2x2).
+fn zone_inset(game: &Game) -> u32 {
+    let start = game.config.zone_start_turn;
+    if start == 0 || game.turn_number < start {
+        return 0;
+    }
+    (game.turn_number - start + 1).min(3)
+}
+
+/// Whether a tile lies inside this turn's safe zone.
+fn in_zone(game: &Game, x: u32, y: u32) -> bool {
+    let inset = zone_inset(game);
+    x >= inset && y >= inset && x < 8 - inset && y < 8 - inset
+}
+
+/// What the prey owes before acting this turn: its item choice for the round
+/// (`prey_commit_items`), and for a hidden prey answers to revealed traps
+/// (`prey_prove_traps`) and due scent (`prey_reveal_scent`).
+fn check_prey_obligations(game: &Game) -> Result<(), Error> {
+    if game.config.item_count > 0 && game.items_commitment.is_none() {
+        return Err(Error::ItemsNotCommitted);
+    }
+    if game.trap_check_mask != 0 {
+        return Err(Error::TrapProofRequired);
+    }
+    if scent_due(game).is_some() {
+        return Err(Error::ScentRevealRequired);
+    }
+    Ok(())
+}
+
+/// While the zone shrinks, a hidden move must end on a commitment proven
+/// inside it this turn (`prey_prove_zone`).
+fn check_zone_proof(game: &Game, new_commitment: &BytesN<32>) -> Result<(), Error> {
+    if zone_inset(game) > 0
+        && (game.zone_proven_turn != game.turn_number || game.zone_commitment != *new_commitment)
+    {
+        return Err(Error::ZoneProofRequired);
+    }
+    Ok(())
+}
+
+/// The oldest unrevealed scent, once it is `scent_delay` moves old.
+fn scent_due(game: &Game) -> Option<CommitmentRecord> {
+    let delay = game.config.scent_delay;
+    game.scent_history
+        .first()
+        .filter(|record| delay > 0 && record.turn + delay < game.turn_number)
+}
 
-    fn verify_proof(env: &Env, vk_key: &DataKey, proof: &Bytes) -> Result<(), Error> {
-        let ultrahonk_addr = Address::from_str(env, ULTRAHONK_CONTRACT_ADDRESS);
-        let ultrahonk_client = ultrahonk_contract::Client::new(env, &ultrahonk_addr);
+/// Hunter may step to an in-bounds tile at Manhattan distance <= 1.
+fn validate_hunter_move(game: &Game, x: u32, y: u32) -> Result<(), Error> {
+    if x >= 8 || y >= 8 {
+        return Err(Error::OutOfBounds);
+    }
 
-        let vk: Bytes = env
-            .storage()
-            .instance()
-            .get(vk_key)
-            .expect("VK not set");
+    let dx = abs_diff(x, game.hunter_x);
+    let dy = abs_diff(y, game.hunter_y);
+    if dx + dy > 1 {
+        return Err(Error::InvalidMove);
+    }
 
-        ultrahonk_client.verify_proof(&vk, proof);
+    Ok(())
+}
 
-        Ok(())
+/// Hand the turn to the prey, or skip it straight back to the hunter while
+/// the prey is frozen.
+fn end_hunter_turn(env: &Env, key: &DataKey, game: &mut Game) {
+    game.phase = GamePhase::PreyTurn;
+    if game.prey_is_frozen {
+        skip_frozen_turn(env, key, game);
+        return;
     }
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
+    env.storage().temporary().set(key, game);
+    env.storage()
+        .temporary()
+        .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
 
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
-    }
+/// The frozen prey loses one turn.
+fn skip_frozen_turn(env: &Env, key: &DataKey, game: &mut Game) {
+    game.prey_frozen_turns = game.prey_frozen_turns.saturating_sub(1);
+    game.prey_is_frozen = game.prey_frozen_turns > 0;
+    check_prey_survival(env, key, game);
+}
 
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
+/// Game actions are signed by the player, or through their `_as` entry point
+/// by a named `signer`: the player itself, or the player's session key for
+/// this session while it is active.
+fn require_player_auth(
+    env: &Env,
+    session_id: u32,
+    player: &Address,
+    signer: &Option<Address>,
+) -> Result<(), Error> {
+    // Every game action starts here; a failed one reverts the bump
+    bump_channel_seq(env, session_id);
+    let Some(signer) = signer else {
+        player.require_auth();
+        return Ok(());
+    };
+    if signer != player {
+        let session_key: SessionKey = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+            .temporary()
+            .get(&DataKey::SessionKey(session_id, player.clone()))
+            .filter(|session_key: &SessionKey| session_key.key == *signer)
+            .ok_or(Error::NotPlayer)?;
+        if env.ledger().sequence() > session_key.expires_ledger {
+            return Err(Error::SessionKeyExpired);
+        }
     }
+    signer.require_auth();
+    Ok(())
+}
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+/// Advance an open channel's sequence for an on-chain action, so no update
+/// signed before it can be posted over it.
+fn bump_channel_seq(env: &Env, session_id: u32) {
+    let key = DataKey::Channel(session_id);
+    if let Some(mut channel) = env.storage().temporary().get::<_, Channel>(&key) {
+        channel.seq += 1;
+        env.storage().temporary().set(&key, &channel);
     }
 }
 
-// ============================================================================
-// Game Hub Notifications
-// ============================================================================
-
-/// Notify the Game Hub that a game session started.
-/// Silently skipped if no GameHub address is configured (local dev).
-fn notify_game_hub_start(env: &Env, session_id: u32, player1: Address, player2: Address) {
-    if let Some(hub_addr) = env
+/// Check a channel update against the session, the on-chain game and both
+/// players' channel keys and return the channel. Panics if a signature does
+/// not verify.
+fn verify_channel_update(
+    env: &Env,
+    session_id: u32,
+    game: &Game,
+    update: &ChannelUpdate,
+    player1_sig: &BytesN<64>,
+    player2_sig: &BytesN<64>,
+) -> Result<Channel, Error> {
+    let channel: Channel = env
         .storage()
-        .instance()
-        .get::<DataKey, Address>(&DataKey::GameHubAddress)
+        .temporary()
+        .get(&DataKey::Channel(session_id))
+        .ok_or(Error::ChannelNotOpen)?;
+    let (Some(player1_key), Some(player2_key)) = (&channel.player1_key, &channel.player2_key) else {
+        return Err(Error::ChannelNotOpen);
+    };
+
+    assert!(
+        update.contract == env.current_contract_address()
+            && update.session_id == session_id
+            && update.game.player1 == game.player1
+            && update.game.player2 == game.player2,
+        "channel update is for another session"
+    );
+    if update.seq <= channel.seq {
+        return Err(Error::StaleUpdate);
+    }
+
+    // The signed state replaces the on-chain game wholesale, so it must keep
+    // the match settings and cannot take back rounds or points
+    let signed = &update.game;
+    if signed.config != game.config
+        || signed.total_rounds != game.total_rounds
+        || signed.teams != game.teams
+        || signed.round < game.round
+        || signed.player1_score < game.player1_score
+        || signed.player2_score < game.player2_score
     {
-        let game_id = env.current_contract_address();
-        let args: Vec<Val> = vec![
-            env,
-            game_id.into_val(env),
-            session_id.into_val(env),
-            player1.into_val(env),
-            player2.into_val(env),
-            0i128.into_val(env),
-            0i128.into_val(env),
-        ];
-        env.invoke_contract::<Val>(&hub_addr, &Symbol::new(env, "start_game"), args);
+        return Err(Error::ChannelStateMismatch);
     }
+
+    let message = update.clone().to_xdr(env);
+    env.crypto().ed25519_verify(player1_key, &message, player1_sig);
+    env.crypto().ed25519_verify(player2_key, &message, player2_sig);
+
+    Ok(channel)
 }
 
-/// Notify the Game Hub that the game ended.
-/// Silently skipped if no GameHub address is configured (local dev).
-fn notify_game_hub_end(env: &Env, session_id: u32, player1_won: bool) {
-    if let Some(hub_addr) = env
-        .storage()
-        .instance()
-        .get::<DataKey, Address>(&DataKey::GameHubAddress)
-    {
-        let args: Vec<Val> = vec![
-            env,
-            session_id.into_val(env),
-            player1_won.into_val(env),
-        ];
-        env.invoke_contract::<Val>(&hub_addr, &Symbol::new(env, "end_game"), args);
+/// The player whose action the game is waiting on, if any.
+fn player_to_move(game: &Game) -> Option<Address> {
+    match game.phase {
+        GamePhase::HunterTurn => Some(game.hunter.clone()),
+        GamePhase::PreyTurn
+        | GamePhase::PreySpawn
+        | GamePhase::SearchPending
+        | GamePhase::SonarPending
+        | GamePhase::FlarePending
+        | GamePhase::BurnPending
+        | GamePhase::AuditPending => Some(game.prey.clone()),
+        GamePhase::TrapReveal if game.trap_check_mask != 0 => Some(game.prey.clone()),
+        GamePhase::TrapReveal => Some(game.hunter.clone()),
+        GamePhase::MapDraft => Some(draft_player(game)),
+        GamePhase::WaitingForPlayer2
+        | GamePhase::SeedReveal
+        | GamePhase::MovesPending
+        | GamePhase::Ended => None,
     }
 }
 
-// ============================================================================
-// Utility
-// ============================================================================
+/// Post a hidden prey move without its proof (`optimistic` rule).
+fn record_step(game: &mut Game, new_commitment: &BytesN<32>, dash: bool) {
+    game.pending_steps.push_back(MoveStep {
+        old_commitment: game.prey_commitment.clone(),
+        new_commitment: new_commitment.clone(),
+        burned_tiles: game.burned_tiles,
+        dash,
+    });
+}
 
-fn abs_diff(a: u32, b: u32) -> u32 {
-    if a > b { a - b } else { b - a }
+/// Abilities measured from the Hunter's position need it in the open.
+fn require_visible_hunter(game: &Game) -> Result<(), Error> {
+    if game.hunter_is_hidden {
+        return Err(Error::HunterHidden);
+    }
+    Ok(())
+}
+
+/// Hunter may search a jungle tile in the 3x3 area around its position.
+fn validate_search_tile(game: &Game, x: u32, y: u32) -> Result<(), Error> {
+    if x >= 8 || y >= 8 {
+        return Err(Error::OutOfBounds);
+    }
+
+    let dx = abs_diff(x, game.hunter_x);
+    let dy = abs_diff(y, game.hunter_y);
+    if dx > 1 || dy > 1 {
+        return Err(Error::InvalidMove);
+    }
+
+    if !is_jungle(game, x, y) {
+        return Err(Error::NotJungle);
+    }
+
+    Ok(())
 }
 
 /// Select a random map index from the pool using the environment PRNG.
@@ -1022,7 +5205,32 @@ fn select_random_map(env: &Env) -> u32 {
     (env.prng().gen_range::<u64>(0..MAP_COUNT as u64)) as u32
 }
 
-/// Pick random starting positions on plains with Manhattan distance >= MIN_SPAWN_DISTANCE.
+/// Reseed the PRNG from the match seed and round number, so map and spawns
+/// of every round are reproducible by both players. No-op until both seeds are revealed.
+fn seed_round_prng(env: &Env, session_id: u32, round: u32) {
+    let seeds: Option<SeedState> = env.storage().temporary().get(&DataKey::Seeds(session_id));
+    if let Some(match_seed) = seeds.and_then(|s| s.match_seed) {
+        let mut round_seed = Bytes::from_array(env, &match_seed.to_array());
+        round_seed.extend_from_array(&round.to_be_bytes());
+        env.prng()
+            .seed(Bytes::from_array(env, &sha256_bytes(env, &round_seed).to_array()));
+    }
+}
+
+/// Session id of a `DataKey::Game` key.
+fn session_id(key: &DataKey) -> u32 {
+    match key {
+        DataKey::Game(id) => *id,
+        _ => 0,
+    }
+}
+
+fn sha256_bytes(env: &Env, data: &Bytes) -> BytesN<32> {
+    env.crypto().sha256(data).to_bytes()
+}
+
+/// Pick random starting positions on plains with Manhattan distance >= MIN_SPAWN_DISTANCE,
+/// the prey's at least MIN_EXTRACTION_DISTANCE from every extraction tile.
 fn random_starting_positions(env: &Env, map_index: u32) -> (u32, u32, u32, u32) {
     let map = &MAPS[map_index as usize];
 
@@ -1050,12 +5258,216 @@ fn random_starting_positions(env: &Env, map_index: u32) -> (u32, u32, u32, u32)
         let bx = idx_b % 8;
         let by = idx_b / 8;
         let dist = abs_diff(ax, bx) + abs_diff(ay, by);
-        if dist >= MIN_SPAWN_DISTANCE {
+        if dist >= MIN_SPAWN_DISTANCE && !near_extraction(map_index, bx, by) {
             return (ax, ay, bx, by);
         }
     }
 }
 
+/// Whether (x, y) is within MIN_EXTRACTION_DISTANCE of one of the map's extraction tiles.
+fn near_extraction(map_index: u32, x: u32, y: u32) -> bool {
+    let tiles = EXTRACTION_TILES[map_index as usize];
+    (0u32..64).any(|i| {
+        tiles >> i & 1 == 1 && abs_diff(i % 8, x) + abs_diff(i / 8, y) < MIN_EXTRACTION_DISTANCE
+    })
+}
+
+/// Whether `player` plays for player 1's team.
+fn on_team1(game: &Game, player: &Address) -> bool {
+    *player == game.player1 || game.teams.team1.contains(player)
+}
+
+/// Whether any prey, active or benched, stands visible on (x, y).
+fn visible_prey_at(game: &Game, x: u32, y: u32) -> bool {
+    if !game.prey_is_hidden && game.prey_x == x && game.prey_y == y {
+        return true;
+    }
+    game.teams
+        .bench
+        .iter()
+        .any(|member| !member.is_hunter && !member.is_hidden && member.x == x && member.y == y)
+}
+
+/// Swap every benched team member into the active slot of their role.
+fn rotate_team_members(game: &mut Game) {
+    for i in 0..game.teams.bench.len() {
+        let mut member = game.teams.bench.get(i).unwrap();
+        if member.is_hunter {
+            core::mem::swap(&mut member.player, &mut game.hunter);
+            core::mem::swap(&mut member.x, &mut game.hunter_x);
+            core::mem::swap(&mut member.y, &mut game.hunter_y);
+            core::mem::swap(&mut member.is_hidden, &mut game.hunter_is_hidden);
+            core::mem::swap(&mut member.commitment, &mut game.hunter_commitment);
+        } else {
+            core::mem::swap(&mut member.player, &mut game.prey);
+            core::mem::swap(&mut member.x, &mut game.prey_x);
+            core::mem::swap(&mut member.y, &mut game.prey_y);
+            core::mem::swap(&mut member.is_hidden, &mut game.prey_is_hidden);
+            core::mem::swap(&mut member.commitment, &mut game.prey_commitment);
+            core::mem::swap(&mut member.frozen_turns, &mut game.prey_frozen_turns);
+            game.prey_is_frozen = game.prey_frozen_turns > 0;
+        }
+        game.teams.bench.set(i, member);
+    }
+}
+
+/// Give benched team members fresh public spawns for the round.
+fn place_bench(env: &Env, game: &mut Game) {
+    for i in 0..game.teams.bench.len() {
+        let mut member = game.teams.bench.get(i).unwrap();
+        (member.x, member.y) = random_bench_spawn(env, game, member.is_hunter, i);
+        member.is_hidden = false;
+        member.commitment = BytesN::from_array(env, &[0u8; 32]);
+        member.frozen_turns = 0;
+        game.teams.bench.set(i, member);
+    }
+}
+
+/// Pick a free plains spawn for a benched member, at least MIN_SPAWN_DISTANCE
+/// from every member of the other role already placed (the active players
+/// and the first `placed` bench members). A prey also keeps clear of the
+/// extraction tiles.
+fn random_bench_spawn(env: &Env, game: &Game, is_hunter: bool, placed: u32) -> (u32, u32) {
+    let map = &MAPS[game.map_index as usize];
+    loop {
+        let idx = env.prng().gen_range::<u64>(0..64) as u32;
+        if map[idx as usize] != 0 {
+            continue;
+        }
+        let (x, y) = (idx % 8, idx / 8);
+        if !is_hunter && near_extraction(game.map_index, x, y) {
+            continue;
+        }
+
+        // Same role: any other tile; other role: far enough apart
+        let clear_of = |other_is_hunter: bool, ox: u32, oy: u32| {
+            let dist = abs_diff(ox, x) + abs_diff(oy, y);
+            dist >= if other_is_hunter == is_hunter { 1 } else { MIN_SPAWN_DISTANCE }
+        };
+        if clear_of(true, game.hunter_x, game.hunter_y)
+            && clear_of(false, game.prey_x, game.prey_y)
+            && game
+                .teams
+                .bench
+                .iter()
+                .take(placed as usize)
+                .all(|member| clear_of(member.is_hunter, member.x, member.y))
+        {
+            return (x, y);
+        }
+    }
+}
+
+/// Pick `count` distinct jungle tiles of the map as item candidates (bit y*8 + x).
+fn random_item_tiles(env: &Env, map_index: u32, count: u32) -> u64 {
+    let map = &MAPS[map_index as usize];
+    let mut tiles: u64 = 0;
+    let mut placed = 0;
+    while placed < count {
+        let idx = env.prng().gen_range::<u64>(0..64) as u32;
+        if map[idx as usize] == 1 && (tiles >> idx) & 1 == 0 {
+            tiles |= 1 << idx;
+            placed += 1;
+        }
+    }
+    tiles
+}
+
+/// Check the public inputs of a hidden move proof (`jungle_move` / `jungle_dash`):
+/// old_commitment must match stored, new_commitment must match argument,
+/// map_id and burned tiles must match the current round.
+///
+/// Proof blob layout (after 4-byte num_fields header):
+///   bytes 4..36:    old_commitment
+///   bytes 36..68:   new_commitment
+///   bytes 68..100:  map_id (u8 in last byte)
+///   bytes 100..132: burned (u64 in last 8 bytes)
+fn check_hidden_move_inputs(
+    game: &Game,
+    old_commitment: &BytesN<32>,
+    new_commitment: &BytesN<32>,
+    proof: &Bytes,
+) {
+    check_move_inputs(game.map_index, game.burned_tiles, old_commitment, new_commitment, proof);
+}
+
+/// Same checks against an archived optimistic move.
+fn check_step_inputs(batch: &MoveBatch, step: &MoveStep, proof: &Bytes) {
+    check_move_inputs(
+        batch.map_index,
+        step.burned_tiles,
+        &step.old_commitment,
+        &step.new_commitment,
+        proof,
+    );
+}
+
+fn check_move_inputs(
+    map_index: u32,
+    burned_tiles: u64,
+    old_commitment: &BytesN<32>,
+    new_commitment: &BytesN<32>,
+    proof: &Bytes,
+) {
+    let proof_old_commitment = extract_bytes32(proof, 4);
+    assert!(
+        proof_old_commitment == *old_commitment,
+        "proof old_commitment does not match game state"
+    );
+    let proof_new_commitment = extract_bytes32(proof, 36);
+    assert!(
+        proof_new_commitment == *new_commitment,
+        "proof new_commitment does not match argument"
+    );
+
+    let proof_map_id = extract_u8(proof, 68);
+    assert!(
+        proof_map_id == map_index as u8,
+        "proof map_id does not match game state"
+    );
+    assert!(
+        extract_u64(proof, 100) == burned_tiles,
+        "proof burned tiles do not match game state"
+    );
+}
+
+/// Check the public inputs of a search proof (`search_response` / `decoy_discard`):
+/// the commitment being answered for, and searched_x/y matching
+/// game.searched_tiles_x/y padded with 255 to length 9.
+///
+/// Proof blob layout (after 4-byte num_fields header):
+///   bytes 4..36:    commitment (32 bytes, Field)
+///   bytes 36..324:  searched_x[0..9] (9 * 32 bytes, u8 in last byte)
+///   bytes 324..612: searched_y[0..9] (9 * 32 bytes, u8 in last byte)
+fn check_search_inputs(game: &Game, commitment: &BytesN<32>, proof: &Bytes) {
+    let proof_commitment = extract_bytes32(proof, 4);
+    assert!(
+        proof_commitment == *commitment,
+        "proof commitment does not match game state"
+    );
+
+    let num_tiles = game.searched_tiles_x.len();
+    for i in 0..9u32 {
+        let proof_sx = extract_u8(proof, 36 + i * 32);
+        let proof_sy = extract_u8(proof, 324 + i * 32);
+
+        if i < num_tiles {
+            let expected_x = game.searched_tiles_x.get(i).unwrap() as u8;
+            let expected_y = game.searched_tiles_y.get(i).unwrap() as u8;
+            assert!(
+                proof_sx == expected_x && proof_sy == expected_y,
+                "proof searched tile does not match game state"
+            );
+        } else {
+            // Padded slots must be 255
+            assert!(
+                proof_sx == 255 && proof_sy == 255,
+                "proof padding must be 255"
+            );
+        }
+    }
+}
+
 /// Extract a 32-byte value from the proof blob at the given byte offset.
 fn extract_bytes32(proof: &Bytes, offset: u32) -> BytesN<32> {
     let mut arr = [0u8; 32];
@@ -1071,14 +5483,82 @@ fn extract_u8(proof: &Bytes, offset: u32) -> u8 {
     proof.get(offset + 31).expect("proof too short")
 }
 
+/// Extract a u64 from the last 8 bytes of a 32-byte field in the proof blob.
+fn extract_u64(proof: &Bytes, offset: u32) -> u64 {
+    let mut arr = [0u8; 8];
+    for i in 0..8u32 {
+        arr[i as usize] = proof.get(offset + 24 + i).expect("proof too short");
+    }
+    u64::from_be_bytes(arr)
+}
+
 /// Check if prey survived enough turns; otherwise advance to HunterTurn.
 fn check_prey_survival(env: &Env, key: &DataKey, game: &mut Game) {
+    if !game.prey_is_hidden {
+        game.prey_trail.push_back(TrailStep {
+            turn: game.turn_number,
+            x: game.prey_x,
+            y: game.prey_y,
+        });
+
+        // Ending a turn in the hazard ring loses the round
+        if !in_zone(game, game.prey_x, game.prey_y) {
+            end_round(env, key, game, true);
+            return;
+        }
+
+        // Ending a turn on an extraction tile wins it in escape mode
+        if game.config.objective != Objective::Survive
+            && EXTRACTION_TILES[game.map_index as usize] >> (game.prey_y * 8 + game.prey_x) & 1 == 1
+        {
+            end_round(env, key, game, false);
+            return;
+        }
+    }
+
+    if game.config.audit && game.prey_is_hidden {
+        game.commitment_history.push_back(CommitmentRecord {
+            turn: game.turn_number,
+            commitment: game.prey_commitment.clone(),
+        });
+    }
+
+    if game.config.scent_delay > 0 {
+        if game.prey_is_hidden {
+            game.scent_history.push_back(CommitmentRecord {
+                turn: game.turn_number,
+                commitment: game.prey_commitment.clone(),
+            });
+        } else {
+            // A visible prey's position is public already
+            game.scent_history = vec![env];
+        }
+    }
+
     game.turn_number += 1;
+    regen_energy(game);
+
+    let turn = game.turn_number;
+    let mut decoys: Vec<Decoy> = vec![env];
+    for decoy in game.decoys.iter().filter(|decoy| decoy.expires_turn > turn) {
+        decoys.push_back(decoy);
+    }
+    game.decoys = decoys;
 
     if game.turn_number > MAX_TURNS {
-        // Prey survived — hunter loses this round
-        end_round(env, key, game, false);
+        if game.traps.iter().any(|trap| !trap.revealed) {
+            // The hunter gets a last chance to spring its traps
+            game.phase = GamePhase::TrapReveal;
+            game.response_deadline = env.ledger().sequence() + RESPONSE_TIMEOUT_LEDGERS;
+            env.storage().temporary().set(key, game);
+            env.storage()
+                .temporary()
+                .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        } else {
+            score_survival(env, key, game);
+        }
     } else {
+        rotate_team_members(game);
         game.phase = GamePhase::HunterTurn;
         env.storage().temporary().set(key, game);
         env.storage()
@@ -1087,47 +5567,246 @@ fn check_prey_survival(env: &Env, key: &DataKey, game: &mut Game) {
     }
 }
 
+/// Score a round the prey survived to the turn limit.
+fn score_survival(env: &Env, key: &DataKey, game: &mut Game) {
+    // Prey survived — what that earns depends on the objective
+    match game.config.objective {
+        Objective::Survive => end_round(env, key, game, false),
+        Objective::EscapeOrDraw => advance_round(env, key, game, None),
+        Objective::EscapeOrLose => end_round(env, key, game, true),
+    }
+}
+
+/// Save after a trap reveal or trap check. A `TrapReveal` round with nothing
+/// left to reveal or answer is scored right away.
+fn continue_trap_reveal(env: &Env, key: &DataKey, game: &mut Game) {
+    if game.phase == GamePhase::TrapReveal
+        && game.trap_check_mask == 0
+        && game.traps.iter().all(|trap| trap.revealed)
+    {
+        score_survival(env, key, game);
+        return;
+    }
+
+    env.storage().temporary().set(key, game);
+    env.storage()
+        .temporary()
+        .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
 /// End the current round and either start the next round or end the match.
 ///
 /// `hunter_won_round`: true if the hunter caught the prey this round.
 fn end_round(env: &Env, key: &DataKey, game: &mut Game, hunter_won_round: bool) {
     // Award point to the correct player (based on fixed identity, not current role)
+    let round_winner = if hunter_won_round {
+        game.hunter.clone()
+    } else {
+        game.prey.clone()
+    };
     if hunter_won_round {
         // The current hunter wins this round
-        if game.hunter == game.player1 {
+        if on_team1(game, &game.hunter) {
             game.player1_score += 1;
         } else {
             game.player2_score += 1;
         }
     } else {
         // The current prey wins this round (survived)
-        if game.prey == game.player1 {
+        if on_team1(game, &game.prey) {
             game.player1_score += 1;
         } else {
             game.player2_score += 1;
         }
     }
 
-    // Check if match is over
-    if game.round >= TOTAL_ROUNDS {
-        // Determine overall winner
-        let player1_won = game.player1_score >= game.player2_score;
-        if game.player1_score > game.player2_score {
-            game.winner = Some(game.player1.clone());
-        } else if game.player2_score > game.player1_score {
-            game.winner = Some(game.player2.clone());
-        }
-        // else: draw — winner stays None (but GameHub defaults to player1)
-        game.phase = GamePhase::Ended;
+    advance_round(env, key, game, Some(round_winner));
+}
+
+/// Start the next round, or end the match after the last one. Called
+/// directly (without awarding a point) when a round is drawn.
+fn advance_round(env: &Env, key: &DataKey, game: &mut Game, round_winner: Option<Address>) {
+    if archive_moves(env, key, game, &round_winner) {
+        // Optimistic moves are proven before the next round
+        game.phase = GamePhase::MovesPending;
+        env.storage().temporary().set(key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        return;
+    }
+
+    if archive_round(env, key, game, round_winner) {
+        // Staked matches wait for the prey's transcript reveal
+        game.phase = GamePhase::AuditPending;
         env.storage().temporary().set(key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        return;
+    }
+
+    next_round(env, key, game);
+}
+
+/// Store the finished round's transcript for `reveal_round` (`audit` rule).
+/// Returns whether the next round has to wait for the reveal.
+fn archive_round(env: &Env, key: &DataKey, game: &mut Game, round_winner: Option<Address>) -> bool {
+    if !game.config.audit || game.commitment_history.is_empty() {
+        return false;
+    }
+
+    let transcript = RoundTranscript {
+        hunter: game.hunter.clone(),
+        prey: game.prey.clone(),
+        map_index: game.map_index,
+        commitments: game.commitment_history.clone(),
+        trail: game.prey_trail.clone(),
+        dash_turns: game.dash_turns.clone(),
+        round_winner,
+        deadline: env.ledger().sequence() + AUDIT_TIMEOUT_LEDGERS,
+        result: AuditResult::Pending,
+    };
+    let transcript_key = DataKey::Transcript(session_id(key), game.round);
+    env.storage().temporary().set(&transcript_key, &transcript);
+    env.storage()
+        .temporary()
+        .extend_ttl(&transcript_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    game.commitment_history = vec![env];
+    game.dash_turns = vec![env];
+
+    game.config.commit_reveal
+}
 
-        // Notify Game Hub that the game ended
-        let session_id = match key {
-            DataKey::Game(id) => *id,
-            _ => 0,
+/// Whether a revealed transcript is a path the prey could have walked: in turn
+/// order with its public positions, every step into, within or out of the
+/// jungle covers at most one tile, or two through jungle on a dash turn.
+fn revealed_path_is_legal(transcript: &RoundTranscript, positions: &Vec<u32>) -> bool {
+    let map = &MAPS[transcript.map_index as usize];
+    let trail = &transcript.trail;
+    let commitments = &transcript.commitments;
+    let (mut i, mut j) = (0, 0);
+    // (tile, hidden) of the previous turn
+    let mut prev: Option<(u32, bool)> = None;
+    while i < trail.len() || j < commitments.len() {
+        let take_trail = j >= commitments.len()
+            || (i < trail.len() && trail.get(i).unwrap().turn < commitments.get(j).unwrap().turn);
+        let (turn, tile, hidden) = if take_trail {
+            let step = trail.get(i).unwrap();
+            i += 1;
+            (step.turn, step.y * 8 + step.x, false)
+        } else {
+            let record = commitments.get(j).unwrap();
+            j += 1;
+            (record.turn, positions.get(j - 1).unwrap(), true)
         };
-        notify_game_hub_end(env, session_id, player1_won);
 
+        if let Some((prev_tile, prev_hidden)) = prev {
+            // Public moves were checked when they were made
+            if (hidden || prev_hidden)
+                && !is_legal_step(map, prev_tile, tile, transcript.dash_turns.contains(turn))
+            {
+                return false;
+            }
+        }
+        prev = Some((tile, hidden));
+    }
+    true
+}
+
+/// One tile at most, or two through a jungle tile when dashing.
+fn is_legal_step(map: &[u8; 64], from: u32, to: u32, dashed: bool) -> bool {
+    let (fx, fy, tx, ty) = (from % 8, from / 8, to % 8, to / 8);
+    let dist = abs_diff(fx, tx) + abs_diff(fy, ty);
+    if dist <= 1 {
+        return true;
+    }
+    if !dashed || dist > 2 {
+        return false;
+    }
+    // Any tile next to both ends can be the one passed through
+    (0u32..64).any(|mid| {
+        let (mx, my) = (mid % 8, mid / 8);
+        map[mid as usize] == 1
+            && abs_diff(fx, mx) + abs_diff(fy, my) == 1
+            && abs_diff(mx, tx) + abs_diff(my, ty) == 1
+    })
+}
+
+/// A round's prey trail starts with its spawn, unless that spawn is hidden.
+fn spawn_trail(env: &Env, config: &GameConfig, x: u32, y: u32) -> Vec<TrailStep> {
+    if config.hidden_spawn {
+        vec![env]
+    } else {
+        vec![env, TrailStep { turn: 0, x, y }]
+    }
+}
+
+/// Store the finished round's unproven prey moves (`optimistic` rule).
+/// Returns whether there are any for the next round to wait on.
+fn archive_moves(env: &Env, key: &DataKey, game: &mut Game, round_winner: &Option<Address>) -> bool {
+    if game.pending_steps.is_empty() {
+        return false;
+    }
+
+    let batch = MoveBatch {
+        round: game.round,
+        hunter: game.hunter.clone(),
+        prey: game.prey.clone(),
+        map_index: game.map_index,
+        steps: game.pending_steps.clone(),
+        proven_steps: 0,
+        round_winner: round_winner.clone(),
+        deadline: env.ledger().sequence() + MOVE_PROOF_TIMEOUT_LEDGERS,
+    };
+    let batch_key = DataKey::MoveBatch(session_id(key));
+    env.storage().temporary().set(&batch_key, &batch);
+    env.storage()
+        .temporary()
+        .extend_ttl(&batch_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    game.pending_steps = vec![env];
+
+    true
+}
+
+/// Whether the prey has submitted the proof of every step of the batch.
+fn all_steps_proven(batch: &MoveBatch) -> bool {
+    batch.proven_steps == (1 << batch.steps.len()) - 1
+}
+
+/// Drop a settled batch and its step proofs.
+fn remove_move_batch(env: &Env, session_id: u32, batch: &MoveBatch) {
+    for step in 0..batch.steps.len() {
+        env.storage().temporary().remove(&DataKey::StepProof(session_id, step));
+    }
+    env.storage().temporary().remove(&DataKey::MoveBatch(session_id));
+}
+
+/// A failed audit or challenge hands the round to its hunter, taking back a
+/// point the prey scored.
+fn forfeit_round(game: &mut Game, hunter: &Address, prey: &Address, round_winner: &Option<Address>) {
+    if round_winner.as_ref() == Some(hunter) {
+        return;
+    }
+    if round_winner.is_some() {
+        if on_team1(game, prey) {
+            game.player1_score -= 1;
+        } else {
+            game.player2_score -= 1;
+        }
+    }
+    if on_team1(game, hunter) {
+        game.player1_score += 1;
+    } else {
+        game.player2_score += 1;
+    }
+}
+
+/// Start the next round, or end the match after the last one.
+fn next_round(env: &Env, key: &DataKey, game: &mut Game) {
+    // Check if match is over
+    if game.round >= TOTAL_ROUNDS {
+        finish_match(env, key, game);
         return;
     }
 
@@ -1139,10 +5818,50 @@ fn end_round(env: &Env, key: &DataKey, game: &mut Game, hunter_won_round: bool)
         let old_hunter = game.hunter.clone();
         game.hunter = game.prey.clone();
         game.prey = old_hunter;
+
+        let mut bench: Vec<BenchMember> = vec![env];
+        for mut member in game.teams.bench.iter() {
+            member.is_hunter = !member.is_hunter;
+            bench.push_back(member);
+        }
+        game.teams.bench = bench;
+    }
+
+    // Select new map and starting positions for next round
+    seed_round_prng(env, session_id(key), game.round);
+    reset_round(env, game);
+
+    env.storage().temporary().set(key, game);
+    env.storage()
+        .temporary()
+        .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// End the match on the current scores and notify the Game Hub.
+fn finish_match(env: &Env, key: &DataKey, game: &mut Game) {
+    // Determine overall winner
+    let player1_won = game.player1_score >= game.player2_score;
+    game.winner = None;
+    if game.player1_score > game.player2_score {
+        game.winner = Some(game.player1.clone());
+    } else if game.player2_score > game.player1_score {
+        game.winner = Some(game.player2.clone());
     }
+    // else: draw — winner stays None (but GameHub defaults to player1)
+    game.phase = GamePhase::Ended;
+    env.storage().temporary().set(key, game);
+
+    // Notify Game Hub that the game ended
+    notify_game_hub_end(env, session_id(key), player1_won);
+}
 
-    // Select new random map and starting positions for next round
-    let new_map_index = select_random_map(env);
+/// Place both players for the current round and reset per-round state.
+/// The map comes from the draft pool when one was drafted, otherwise at random.
+fn reset_round(env: &Env, game: &mut Game) {
+    let new_map_index = match game.draft_pool.get(game.round - 1) {
+        Some(map_index) => map_index,
+        None => select_random_map(env),
+    };
     let (hx, hy, px, py) = random_starting_positions(env, new_map_index);
     game.map_index = new_map_index;
     game.hunter_x = hx;
@@ -1151,19 +5870,45 @@ fn end_round(env: &Env, key: &DataKey, game: &mut Game, hunter_won_round: bool)
     game.prey_y = py;
     game.prey_is_hidden = false;
     game.prey_commitment = BytesN::from_array(env, &[0u8; 32]);
+    game.hunter_is_hidden = false;
+    game.hunter_commitment = BytesN::from_array(env, &[0u8; 32]);
     game.turn_number = 1;
     game.power_searches_remaining = POWER_SEARCHES_INITIAL;
     game.searched_tiles_x = vec![env];
     game.searched_tiles_y = vec![env];
     game.emp_uses_remaining = 1;
     game.prey_is_frozen = false;
+    game.prey_frozen_turns = 0;
     game.prey_dash_remaining = 2;
-    game.phase = GamePhase::HunterTurn;
-
-    env.storage().temporary().set(key, game);
-    env.storage()
-        .temporary()
-        .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    game.hunter_energy = game.config.energy.max;
+    game.prey_energy = game.config.energy.max;
+    game.sonar_uses_remaining = SONAR_USES_INITIAL;
+    game.sonar_radius = 0;
+    game.last_sonar_inside = None;
+    game.flare_uses_remaining = FLARE_USES_INITIAL;
+    game.flare_mask = 0;
+    game.traps = vec![env];
+    game.trap_check_mask = 0;
+    game.prey_trail = spawn_trail(env, &game.config, px, py);
+    game.decoys = vec![env];
+    game.burned_tiles = 0;
+    game.zone_proven_turn = 0;
+    game.zone_commitment = BytesN::from_array(env, &[0u8; 32]);
+    game.scent_history = vec![env];
+    game.scent_trail = vec![env];
+    game.commitment_history = vec![env];
+    game.dash_turns = vec![env];
+    game.pending_steps = vec![env];
+    game.item_candidates = random_item_tiles(
+        env,
+        new_map_index,
+        game.config.item_count * ITEM_CANDIDATES_PER_ITEM,
+    );
+    game.items_commitment = None;
+    game.item_nullifiers = vec![env];
+    game.items_collected = 0;
+    place_bench(env, game);
+    game.phase = round_start_phase(game);
 }
 
 // ============================================================================
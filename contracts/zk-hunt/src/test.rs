@@ -1,8 +1,15 @@
 #![cfg(test)]
 
-use crate::{Error, GamePhase, ZkHuntContract, ZkHuntContractClient, MAPS, MAP_COUNT};
+extern crate std;
+
+use crate::{
+    AuditResult, ChannelUpdate, Circuit, DataKey, EnergyConfig, Error, Game, GameConfig, GamePhase, HubNotification, Objective, TeamMode,
+    ZkHuntContract, ZkHuntContractClient, EXTRACTION_TILES, MAPS, MAP_COUNT, ULTRAHONK_CONTRACT_ADDRESS,
+};
+use ed25519_dalek::{Signer, SigningKey};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contract, contracterror, contractimpl, symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec};
 
 // ============================================================================
 // Mock Verifier (always succeeds)
@@ -39,12 +46,26 @@ fn setup_test() -> (Env, ZkHuntContractClient<'static>, Address, Address) {
     });
 
     let admin = Address::generate(&env);
-    let _verifier_addr = env.register(MockVerifier, ());
+    let verifier_addr = Address::from_str(&env, ULTRAHONK_CONTRACT_ADDRESS);
+    env.register_at(&verifier_addr, MockVerifier, ());
     let contract_id = env.register(ZkHuntContract, (&admin,));
     let client = ZkHuntContractClient::new(&env, &contract_id);
 
     let dummy_vk = Bytes::from_array(&env, &[0u8; 32]);
     client.set_vks(&dummy_vk, &dummy_vk);
+    client.set_vk(&Circuit::Spawn, &dummy_vk);
+    client.set_vk(&Circuit::Dash, &dummy_vk);
+    client.set_vk(&Circuit::Sonar, &dummy_vk);
+    client.set_vk(&Circuit::Flare, &dummy_vk);
+    client.set_vk(&Circuit::Decoy, &dummy_vk);
+    client.set_vk(&Circuit::Zone, &dummy_vk);
+    client.set_vk(&Circuit::Extraction, &dummy_vk);
+    client.set_vk(&Circuit::Item, &dummy_vk);
+    client.set_vk(&Circuit::Adjacency, &dummy_vk);
+    client.set_vk(&Circuit::Scent, &dummy_vk);
+    client.set_vk(&Circuit::Audit, &dummy_vk);
+    client.set_vk(&Circuit::Enter, &dummy_vk);
+    client.set_vk(&Circuit::Exit, &dummy_vk);
 
     let hunter = Address::generate(&env);
     let prey = Address::generate(&env);
@@ -60,6 +81,31 @@ fn dummy_proof(env: &Env) -> Bytes {
     Bytes::from_array(env, &[0u8; 64])
 }
 
+/// Field element holding a small integer (big-endian, value in the last bytes).
+fn field_u32(value: u32) -> [u8; 32] {
+    let mut field = [0u8; 32];
+    field[28..].copy_from_slice(&value.to_be_bytes());
+    field
+}
+
+/// Field element holding a u64 (big-endian, value in the last 8 bytes).
+fn field_u64(value: u64) -> [u8; 32] {
+    let mut field = [0u8; 32];
+    field[24..].copy_from_slice(&value.to_be_bytes());
+    field
+}
+
+/// Proof blob as the frontend assembles it: 4-byte num_fields header, one
+/// 32-byte field per public input, then the proof (any bytes for the mock verifier).
+fn proof_blob(env: &Env, public_inputs: &[[u8; 32]]) -> Bytes {
+    let mut blob = Bytes::from_array(env, &(public_inputs.len() as u32).to_be_bytes());
+    for field in public_inputs.iter() {
+        blob.extend_from_array(field);
+    }
+    blob.extend_from_array(&[0u8; 64]);
+    blob
+}
+
 fn assert_zk_hunt_error<T, E>(
     result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
     expected_error: Error,
@@ -426,6 +472,19 @@ fn test_join_wrong_phase() {
     assert_zk_hunt_error(&result, Error::WrongPhase);
 }
 
+#[test]
+fn test_join_own_game_rejected() {
+    let (_env, client, hunter, prey) = setup_test();
+    let session_id = client.create_game(&hunter);
+
+    let result = client.try_join_game(&session_id, &hunter);
+    assert_zk_hunt_error(&result, Error::AlreadySeated);
+
+    // The seat is still open
+    client.join_game(&session_id, &prey);
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::HunterTurn);
+}
+
 #[test]
 fn test_prey_exit_jungle_not_hidden() {
     let (_env, client, hunter, prey) = setup_test();
@@ -531,3 +590,2770 @@ fn test_role_swap_at_halfway() {
     assert_eq!(map[(game.hunter_y * 8 + game.hunter_x) as usize], 0);
     assert_eq!(map[(game.prey_y * 8 + game.prey_x) as usize], 0);
 }
+
+// ============================================================================
+// Commit-Reveal Seed Tests
+// ============================================================================
+
+fn seed_hash(env: &Env, seed: &BytesN<32>) -> BytesN<32> {
+    env.crypto()
+        .sha256(&Bytes::from_array(env, &seed.to_array()))
+        .to_bytes()
+}
+
+fn commit_reveal_config() -> GameConfig {
+    GameConfig {
+        commit_reveal: true,
+        ..Default::default()
+    }
+}
+
+/// Create and join a commit-reveal game without revealing any seed yet.
+fn create_and_join_committed(
+    env: &Env,
+    client: &ZkHuntContractClient,
+    hunter: &Address,
+    prey: &Address,
+    hunter_seed: &BytesN<32>,
+    prey_seed: &BytesN<32>,
+) -> u32 {
+    let session_id = client.create_game_with_config(
+        hunter,
+        &commit_reveal_config(),
+        &Some(seed_hash(env, hunter_seed)),
+    );
+    client.join_game_with_seed(&session_id, prey, &seed_hash(env, prey_seed));
+    session_id
+}
+
+#[test]
+fn test_commit_reveal_starts_after_both_reveals() {
+    let (env, client, hunter, prey) = setup_test();
+    let hunter_seed = BytesN::from_array(&env, &[7u8; 32]);
+    let prey_seed = BytesN::from_array(&env, &[9u8; 32]);
+    let session_id =
+        create_and_join_committed(&env, &client, &hunter, &prey, &hunter_seed, &prey_seed);
+
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::SeedReveal);
+
+    client.reveal_seed(&session_id, &hunter, &hunter_seed);
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::SeedReveal);
+
+    client.reveal_seed(&session_id, &prey, &prey_seed);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::HunterTurn);
+    assert_eq!(game.turn_number, 1);
+    assert!(client.get_seeds(&session_id).match_seed.is_some());
+    let map = &MAPS[game.map_index as usize];
+    assert_eq!(map[(game.hunter_y * 8 + game.hunter_x) as usize], 0);
+    assert_eq!(map[(game.prey_y * 8 + game.prey_x) as usize], 0);
+}
+
+#[test]
+fn test_commit_reveal_is_deterministic() {
+    let (env, client, hunter, prey) = setup_test();
+    let hunter_seed = BytesN::from_array(&env, &[3u8; 32]);
+    let prey_seed = BytesN::from_array(&env, &[5u8; 32]);
+
+    let play = || {
+        let session_id =
+            create_and_join_committed(&env, &client, &hunter, &prey, &hunter_seed, &prey_seed);
+        client.reveal_seed(&session_id, &prey, &prey_seed);
+        client.reveal_seed(&session_id, &hunter, &hunter_seed);
+        client.get_game(&session_id)
+    };
+    let games = [play(), play()];
+
+    assert_eq!(games[0].map_index, games[1].map_index);
+    assert_eq!((games[0].hunter_x, games[0].hunter_y), (games[1].hunter_x, games[1].hunter_y));
+    assert_eq!((games[0].prey_x, games[0].prey_y), (games[1].prey_x, games[1].prey_y));
+}
+
+#[test]
+fn test_reveal_seed_mismatch() {
+    let (env, client, hunter, prey) = setup_test();
+    let hunter_seed = BytesN::from_array(&env, &[7u8; 32]);
+    let prey_seed = BytesN::from_array(&env, &[9u8; 32]);
+    let session_id =
+        create_and_join_committed(&env, &client, &hunter, &prey, &hunter_seed, &prey_seed);
+
+    let result = client.try_reveal_seed(&session_id, &hunter, &prey_seed);
+    assert_zk_hunt_error(&result, Error::SeedMismatch);
+}
+
+#[test]
+fn test_join_commit_reveal_requires_seed() {
+    let (env, client, hunter, prey) = setup_test();
+    let hunter_seed = BytesN::from_array(&env, &[7u8; 32]);
+    let session_id = client.create_game_with_config(
+        &hunter,
+        &commit_reveal_config(),
+        &Some(seed_hash(&env, &hunter_seed)),
+    );
+
+    let result = client.try_join_game(&session_id, &prey);
+    assert_zk_hunt_error(&result, Error::SeedRequired);
+}
+
+#[test]
+fn test_claim_reveal_timeout() {
+    let (env, client, hunter, prey) = setup_test();
+    let hunter_seed = BytesN::from_array(&env, &[7u8; 32]);
+    let prey_seed = BytesN::from_array(&env, &[9u8; 32]);
+    let session_id =
+        create_and_join_committed(&env, &client, &hunter, &prey, &hunter_seed, &prey_seed);
+
+    client.reveal_seed(&session_id, &hunter, &hunter_seed);
+
+    let result = client.try_claim_reveal_timeout(&session_id, &hunter);
+    assert_zk_hunt_error(&result, Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|l| l.sequence_number += 721);
+
+    let result = client.try_claim_reveal_timeout(&session_id, &prey);
+    assert_zk_hunt_error(&result, Error::SeedNotRevealed);
+
+    client.claim_reveal_timeout(&session_id, &hunter);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::Ended);
+    assert_eq!(game.winner, Some(hunter));
+}
+
+#[test]
+fn test_reveal_timeout_cancels_when_neither_revealed() {
+    let (env, client, hunter, prey) = setup_test();
+    let hunter_seed = BytesN::from_array(&env, &[7u8; 32]);
+    let prey_seed = BytesN::from_array(&env, &[9u8; 32]);
+    let session_id =
+        create_and_join_committed(&env, &client, &hunter, &prey, &hunter_seed, &prey_seed);
+
+    let result = client.try_claim_reveal_timeout(&session_id, &prey);
+    assert_zk_hunt_error(&result, Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|l| l.sequence_number += 721);
+    client.claim_reveal_timeout(&session_id, &prey);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::Ended);
+    assert_eq!(game.winner, None);
+}
+
+#[test]
+fn test_seed_rejected_without_commit_reveal() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = client.create_game(&hunter);
+    let result = client.try_join_game_with_seed(&session_id, &prey, &BytesN::from_array(&env, &[1u8; 32]));
+    assert_zk_hunt_error(&result, Error::RuleDisabled);
+}
+
+// ============================================================================
+// Map Draft Tests
+// ============================================================================
+
+fn create_and_join_draft(
+    client: &ZkHuntContractClient,
+    hunter: &Address,
+    prey: &Address,
+) -> u32 {
+    let config = GameConfig {
+        map_draft: true,
+        ..Default::default()
+    };
+    let session_id = client.create_game_with_config(hunter, &config, &None);
+    client.join_game(&session_id, prey);
+    session_id
+}
+
+#[test]
+fn test_map_draft_plays_surviving_maps_in_order() {
+    let (_env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join_draft(&client, &hunter, &prey);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::MapDraft);
+    assert_eq!(game.draft_pool.len(), 5);
+
+    // player2 (prey) bans first, then players alternate
+    let bans = [&prey, &hunter, &prey];
+    for player in bans.iter() {
+        let pool = client.get_game(&session_id).draft_pool;
+        client.ban_map(&session_id, player, &pool.get(0).unwrap());
+    }
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::HunterTurn);
+    assert_eq!(game.turn_number, 1);
+    assert_eq!(game.draft_pool.len(), game.total_rounds);
+    assert_eq!(game.map_index, game.draft_pool.get(0).unwrap());
+    let map = &MAPS[game.map_index as usize];
+    assert_eq!(map[(game.hunter_y * 8 + game.hunter_x) as usize], 0);
+    assert_eq!(map[(game.prey_y * 8 + game.prey_x) as usize], 0);
+
+    for _turn in 1..=10 {
+        play_one_turn(&client, session_id);
+    }
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert_eq!(game.map_index, game.draft_pool.get(1).unwrap());
+}
+
+#[test]
+fn test_ban_map_turn_order_and_pool() {
+    let (_env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join_draft(&client, &hunter, &prey);
+
+    let pool = client.get_game(&session_id).draft_pool;
+    let result = client.try_ban_map(&session_id, &hunter, &pool.get(0).unwrap());
+    assert_zk_hunt_error(&result, Error::NotYourTurn);
+
+    let outside = (0..MAP_COUNT).find(|m| !pool.contains(*m)).unwrap();
+    let result = client.try_ban_map(&session_id, &prey, &outside);
+    assert_zk_hunt_error(&result, Error::MapNotInPool);
+}
+
+#[test]
+fn test_force_draft_ban_after_deadline() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join_draft(&client, &hunter, &prey);
+
+    let result = client.try_force_draft_ban(&session_id);
+    assert_zk_hunt_error(&result, Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|l| l.sequence_number += 121);
+    client.force_draft_ban(&session_id);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::MapDraft);
+    assert_eq!(game.draft_pool.len(), 4);
+    // The forced ban counted for the prey; the hunter is up next
+    let pool = game.draft_pool;
+    client.ban_map(&session_id, &hunter, &pool.get(0).unwrap());
+}
+
+// ============================================================================
+// Hidden Spawn Tests
+// ============================================================================
+
+fn create_and_join_hidden_spawn(
+    client: &ZkHuntContractClient,
+    hunter: &Address,
+    prey: &Address,
+) -> u32 {
+    let config = GameConfig {
+        hidden_spawn: true,
+        ..Default::default()
+    };
+    let session_id = client.create_game_with_config(hunter, &config, &None);
+    client.join_game(&session_id, prey);
+    session_id
+}
+
+fn spawn_proof(env: &Env, commitment: &BytesN<32>, map_id: u32, hx: u32, hy: u32) -> Bytes {
+    proof_blob(
+        env,
+        &[
+            commitment.to_array(),
+            field_u32(map_id),
+            field_u32(hx),
+            field_u32(hy),
+            field_u64(EXTRACTION_TILES[map_id as usize]),
+        ],
+    )
+}
+
+#[test]
+fn test_hidden_spawn_round_starts_hidden() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join_hidden_spawn(&client, &hunter, &prey);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::PreySpawn);
+    let result = client.try_hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    assert_zk_hunt_error(&result, Error::WrongPhase);
+
+    let commitment = dummy_commitment(&env);
+    let proof = spawn_proof(&env, &commitment, game.map_index, game.hunter_x, game.hunter_y);
+    client.prey_spawn_hidden(&session_id, &commitment, &proof);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::HunterTurn);
+    assert!(game.prey_is_hidden);
+    assert_eq!(game.prey_commitment, commitment);
+    assert_eq!(game.turn_number, 1);
+}
+
+#[test]
+fn test_hidden_spawn_rejects_wrong_hunter_position() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join_hidden_spawn(&client, &hunter, &prey);
+
+    let game = client.get_game(&session_id);
+    let commitment = dummy_commitment(&env);
+    let proof = spawn_proof(&env, &commitment, game.map_index, game.hunter_x + 8, game.hunter_y);
+    let result = client.try_prey_spawn_hidden(&session_id, &commitment, &proof);
+    assert!(result.is_err());
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::PreySpawn);
+}
+
+#[test]
+fn test_hidden_spawn_rejects_wrong_extraction_tiles() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join_hidden_spawn(&client, &hunter, &prey);
+
+    // A proof against no extraction tiles could spawn the prey on one
+    let game = client.get_game(&session_id);
+    let commitment = dummy_commitment(&env);
+    let proof = proof_blob(
+        &env,
+        &[
+            commitment.to_array(),
+            field_u32(game.map_index),
+            field_u32(game.hunter_x),
+            field_u32(game.hunter_y),
+            field_u64(0),
+        ],
+    );
+    let result = client.try_prey_spawn_hidden(&session_id, &commitment, &proof);
+    assert!(result.is_err());
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::PreySpawn);
+}
+
+// ============================================================================
+// Compound Turn Tests
+// ============================================================================
+
+/// Hidden-spawn game with the given extra rules, advanced to the hunter's
+/// first turn with the prey hidden.
+fn start_hidden_game(
+    env: &Env,
+    client: &ZkHuntContractClient,
+    hunter: &Address,
+    prey: &Address,
+    config: GameConfig,
+) -> u32 {
+    let config = GameConfig {
+        hidden_spawn: true,
+        ..config
+    };
+    let session_id = client.create_game_with_config(hunter, &config, &None);
+    client.join_game(&session_id, prey);
+
+    let game = client.get_game(&session_id);
+    let commitment = dummy_commitment(env);
+    let proof = spawn_proof(env, &commitment, game.map_index, game.hunter_x, game.hunter_y);
+    client.prey_spawn_hidden(&session_id, &commitment, &proof);
+    session_id
+}
+
+/// A hunter step (x, y) with a jungle tile (sx, sy) adjacent to it, if any.
+fn find_move_and_search(map_index: u32, hx: u32, hy: u32) -> Option<(u32, u32, u32, u32)> {
+    let map = &MAPS[map_index as usize];
+    let steps: [(i32, i32); 5] = [(0, 0), (-1, 0), (1, 0), (0, -1), (0, 1)];
+    for (ox, oy) in steps.iter() {
+        let (x, y) = (hx as i32 + ox, hy as i32 + oy);
+        if !(0..8).contains(&x) || !(0..8).contains(&y) || (*ox, *oy) == (0, 0) {
+            continue;
+        }
+        for sy in (y - 1).max(0)..=(y + 1).min(7) {
+            for sx in (x - 1).max(0)..=(x + 1).min(7) {
+                if map[(sy * 8 + sx) as usize] == 1 {
+                    return Some((x as u32, y as u32, sx as u32, sy as u32));
+                }
+            }
+        }
+    }
+    None
+}
+
+#[test]
+fn test_hunter_move_and_search() {
+    let (env, client, hunter, prey) = setup_test();
+    let config = GameConfig {
+        compound_turn: true,
+        ..Default::default()
+    };
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, config);
+
+    let game = client.get_game(&session_id);
+    let (x, y, sx, sy) = find_move_and_search(game.map_index, game.hunter_x, game.hunter_y)
+        .expect("no jungle near hunter spawn");
+    client.hunter_move_and_search(&session_id, &x, &y, &sx, &sy);
+
+    let game = client.get_game(&session_id);
+    assert_eq!((game.hunter_x, game.hunter_y), (x, y));
+    assert_eq!(game.phase, GamePhase::SearchPending);
+    assert_eq!(game.searched_tiles_x.get(0).unwrap(), sx);
+    assert_eq!(game.searched_tiles_y.get(0).unwrap(), sy);
+}
+
+#[test]
+fn test_hunter_move_and_search_requires_rule() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, GameConfig::default());
+
+    let game = client.get_game(&session_id);
+    let (x, y, sx, sy) = find_move_and_search(game.map_index, game.hunter_x, game.hunter_y)
+        .expect("no jungle near hunter spawn");
+    let result = client.try_hunter_move_and_search(&session_id, &x, &y, &sx, &sy);
+    assert_zk_hunt_error(&result, Error::RuleDisabled);
+}
+
+#[test]
+fn test_hunter_move_and_search_is_atomic() {
+    let (env, client, hunter, prey) = setup_test();
+    let config = GameConfig {
+        compound_turn: true,
+        ..Default::default()
+    };
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, config);
+
+    // Valid move, but the search tile is out of reach from the new position
+    let game = client.get_game(&session_id);
+    let (x, y, _, _) = find_move_and_search(game.map_index, game.hunter_x, game.hunter_y)
+        .expect("no jungle near hunter spawn");
+    let far_x = if x < 4 { x + 2 } else { x - 2 };
+    let result = client.try_hunter_move_and_search(&session_id, &x, &y, &far_x, &y);
+    assert_zk_hunt_error(&result, Error::InvalidMove);
+
+    let after = client.get_game(&session_id);
+    assert_eq!((after.hunter_x, after.hunter_y), (game.hunter_x, game.hunter_y));
+    assert_eq!(after.phase, GamePhase::HunterTurn);
+}
+
+// ============================================================================
+// Energy Economy Tests
+// ============================================================================
+
+fn energy_config(max: u32, regen: u32, move_cost: u32) -> GameConfig {
+    GameConfig {
+        energy: EnergyConfig {
+            max,
+            regen,
+            move_cost,
+            search_cost: 2,
+            power_search_cost: 3,
+            emp_cost: 3,
+            dash_cost: 2,
+            hide_cost: 1,
+            sonar_cost: 2,
+            flare_cost: 3,
+            trap_cost: 1,
+            decoy_cost: 2,
+            burn_cost: 2,
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_energy_charges_and_regenerates() {
+    let (_env, client, hunter, prey) = setup_test();
+    let session_id = client.create_game_with_config(&hunter, &energy_config(3, 1, 2), &None);
+    client.join_game(&session_id, &prey);
+
+    let game = client.get_game(&session_id);
+    assert_eq!((game.hunter_energy, game.prey_energy), (3, 3));
+
+    let (hx, hy) = find_adjacent_plains_avoiding(
+        game.map_index, game.hunter_x, game.hunter_y, game.prey_x, game.prey_y,
+    );
+    client.hunter_move(&session_id, &hx, &hy);
+    assert_eq!(client.get_game(&session_id).hunter_energy, 1);
+
+    let game = client.get_game(&session_id);
+    let (px, py) = find_adjacent_plains_avoiding(
+        game.map_index, game.prey_x, game.prey_y, game.hunter_x, game.hunter_y,
+    );
+    client.prey_move_public(&session_id, &px, &py);
+
+    // Both paid 2, then regained 1 at the start of turn 2
+    let game = client.get_game(&session_id);
+    assert_eq!(game.turn_number, 2);
+    assert_eq!((game.hunter_energy, game.prey_energy), (2, 2));
+}
+
+#[test]
+fn test_energy_insufficient() {
+    let (_env, client, hunter, prey) = setup_test();
+    let session_id = client.create_game_with_config(&hunter, &energy_config(1, 1, 2), &None);
+    client.join_game(&session_id, &prey);
+
+    let game = client.get_game(&session_id);
+    let (hx, hy) = find_adjacent_plains_avoiding(
+        game.map_index, game.hunter_x, game.hunter_y, game.prey_x, game.prey_y,
+    );
+    let result = client.try_hunter_move(&session_id, &hx, &hy);
+    assert_zk_hunt_error(&result, Error::NotEnoughEnergy);
+}
+
+#[test]
+fn test_energy_replaces_ability_counters() {
+    let (_env, client, hunter, prey) = setup_test();
+    let session_id = client.create_game_with_config(&hunter, &energy_config(5, 1, 0), &None);
+    client.join_game(&session_id, &prey);
+
+    // EMP is paid from energy; the fixed counter is left untouched
+    client.hunter_emp(&session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.hunter_energy, 2);
+    assert_eq!(game.emp_uses_remaining, 1);
+    assert!(game.prey_is_frozen);
+
+    let result = client.try_hunter_emp(&session_id);
+    assert_zk_hunt_error(&result, Error::NotEnoughEnergy);
+}
+
+// ============================================================================
+// Hidden Dash Tests
+// ============================================================================
+
+/// Hidden-spawn game advanced to the prey's first turn (hunter stayed put).
+fn start_hidden_prey_turn(
+    env: &Env,
+    client: &ZkHuntContractClient,
+    hunter: &Address,
+    prey: &Address,
+    config: GameConfig,
+) -> u32 {
+    let session_id = start_hidden_game(env, client, hunter, prey, config);
+    let game = client.get_game(&session_id);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    session_id
+}
+
+fn move_proof(env: &Env, old: &BytesN<32>, new: &BytesN<32>, map_id: u32, burned: u64) -> Bytes {
+    proof_blob(env, &[old.to_array(), new.to_array(), field_u32(map_id), field_u64(burned)])
+}
+
+#[test]
+fn test_prey_dash_jungle_consumes_dash() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, GameConfig::default());
+
+    let game = client.get_game(&session_id);
+    let new_commitment = BytesN::from_array(&env, &[43u8; 32]);
+    let proof = move_proof(&env, &game.prey_commitment, &new_commitment, game.map_index, game.burned_tiles);
+    client.prey_dash_jungle(&session_id, &new_commitment, &proof);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.prey_commitment, new_commitment);
+    assert_eq!(game.prey_dash_remaining, 1);
+    assert_eq!(game.phase, GamePhase::HunterTurn);
+    assert_eq!(game.turn_number, 2);
+}
+
+#[test]
+fn test_prey_dash_jungle_no_dashes_left() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, GameConfig::default());
+
+    for i in 0..2u8 {
+        let game = client.get_game(&session_id);
+        let new_commitment = BytesN::from_array(&env, &[50 + i; 32]);
+        let proof = move_proof(&env, &game.prey_commitment, &new_commitment, game.map_index, game.burned_tiles);
+        client.prey_dash_jungle(&session_id, &new_commitment, &proof);
+        let game = client.get_game(&session_id);
+        client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    }
+
+    let game = client.get_game(&session_id);
+    let new_commitment = BytesN::from_array(&env, &[60u8; 32]);
+    let proof = move_proof(&env, &game.prey_commitment, &new_commitment, game.map_index, game.burned_tiles);
+    let result = client.try_prey_dash_jungle(&session_id, &new_commitment, &proof);
+    assert_zk_hunt_error(&result, Error::NoDashes);
+}
+
+#[test]
+fn test_prey_dash_jungle_rejects_stale_commitment() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, GameConfig::default());
+
+    let game = client.get_game(&session_id);
+    let stale = BytesN::from_array(&env, &[1u8; 32]);
+    let new_commitment = BytesN::from_array(&env, &[43u8; 32]);
+    let proof = move_proof(&env, &stale, &new_commitment, game.map_index, game.burned_tiles);
+    let result = client.try_prey_dash_jungle(&session_id, &new_commitment, &proof);
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Sonar Tests
+// ============================================================================
+
+#[test]
+fn test_unanswered_sonar_claimed_after_deadline() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, GameConfig::default());
+
+    client.hunter_sonar(&session_id, &3);
+    assert_zk_hunt_error(&client.try_claim_catch(&session_id), Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|l| l.sequence_number += 121);
+    client.claim_catch(&session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.round, game.player1_score), (2, 1));
+}
+
+fn sonar_proof(env: &Env, commitment: &BytesN<32>, hx: u32, hy: u32, radius: u32, inside: bool) -> Bytes {
+    proof_blob(
+        env,
+        &[commitment.to_array(), field_u32(hx), field_u32(hy), field_u32(radius), field_u32(inside as u32)],
+    )
+}
+
+#[test]
+fn test_sonar_ping_and_response() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, GameConfig::default());
+
+    client.hunter_sonar(&session_id, &3);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::SonarPending);
+    assert_eq!(game.sonar_uses_remaining, 1);
+
+    let proof = sonar_proof(&env, &game.prey_commitment, game.hunter_x, game.hunter_y, 3, false);
+    client.respond_sonar(&session_id, &false, &proof);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::PreyTurn);
+    assert_eq!(game.last_sonar_inside, Some(false));
+}
+
+#[test]
+fn test_sonar_answer_must_match_proof() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, GameConfig::default());
+
+    client.hunter_sonar(&session_id, &3);
+    let game = client.get_game(&session_id);
+
+    let proof = sonar_proof(&env, &game.prey_commitment, game.hunter_x, game.hunter_y, 3, true);
+    assert!(client.try_respond_sonar(&session_id, &false, &proof).is_err());
+
+    let proof = sonar_proof(&env, &game.prey_commitment, game.hunter_x, game.hunter_y, 2, false);
+    assert!(client.try_respond_sonar(&session_id, &false, &proof).is_err());
+}
+
+#[test]
+fn test_sonar_limits() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, GameConfig::default());
+
+    assert_zk_hunt_error(&client.try_hunter_sonar(&session_id, &0), Error::InvalidRadius);
+    assert_zk_hunt_error(&client.try_hunter_sonar(&session_id, &15), Error::InvalidRadius);
+
+    for _ in 0..2 {
+        client.hunter_sonar(&session_id, &2);
+        let game = client.get_game(&session_id);
+        let proof = sonar_proof(&env, &game.prey_commitment, game.hunter_x, game.hunter_y, 2, false);
+        client.respond_sonar(&session_id, &false, &proof);
+        let new_commitment = BytesN::from_array(&env, &[70 + game.turn_number as u8; 32]);
+        let proof = move_proof(&env, &game.prey_commitment, &new_commitment, game.map_index, game.burned_tiles);
+        client.prey_move_jungle(&session_id, &new_commitment, &proof);
+    }
+
+    assert_zk_hunt_error(&client.try_hunter_sonar(&session_id, &2), Error::NoSonar);
+}
+
+// ============================================================================
+// Flare Tests
+// ============================================================================
+
+fn flare_proof(env: &Env, commitment: &BytesN<32>, mask: u64) -> Bytes {
+    proof_blob(env, &[commitment.to_array(), field_u64(mask)])
+}
+
+#[test]
+fn test_flare_response_clears_mask() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, GameConfig::default());
+
+    // Whole bottom row
+    let mask: u64 = 0xff << 56;
+    client.hunter_flare(&session_id, &mask);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::FlarePending);
+    assert_eq!(game.flare_mask, mask);
+    assert_eq!(game.flare_uses_remaining, 0);
+
+    // Proof for a different mask is rejected
+    let proof = flare_proof(&env, &game.prey_commitment, 0xff);
+    assert!(client.try_respond_flare(&session_id, &proof).is_err());
+
+    let proof = flare_proof(&env, &game.prey_commitment, mask);
+    client.respond_flare(&session_id, &proof);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::PreyTurn);
+    assert_eq!(game.flare_mask, 0);
+}
+
+#[test]
+fn test_flare_concede_ends_round() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, GameConfig::default());
+
+    client.hunter_flare(&session_id, &0x0404040404040404);
+    client.respond_flare(&session_id, &Bytes::new(&env));
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert_eq!(game.player1_score, 1);
+}
+
+#[test]
+fn test_flare_mask_limits() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, GameConfig::default());
+
+    assert_zk_hunt_error(&client.try_hunter_flare(&session_id, &0), Error::InvalidMask);
+    assert_zk_hunt_error(&client.try_hunter_flare(&session_id, &0x3ff), Error::InvalidMask);
+
+    client.hunter_flare(&session_id, &0x070707);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.response_deadline, env.ledger().sequence() + 120);
+}
+
+#[test]
+fn test_unanswered_flare_claimed_after_deadline() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, GameConfig::default());
+
+    // The prey gets the full window to answer
+    client.hunter_flare(&session_id, &0x070707);
+    assert_zk_hunt_error(&client.try_claim_catch(&session_id), Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|l| l.sequence_number += 121);
+    client.claim_catch(&session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert_eq!(game.player1_score, 1);
+}
+
+// ============================================================================
+// Trap Tests
+// ============================================================================
+
+fn trap_commitment(env: &Env, x: u32, y: u32, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &x.to_be_bytes());
+    preimage.extend_from_array(&y.to_be_bytes());
+    preimage.extend_from_array(&salt.to_array());
+    env.crypto().sha256(&preimage).to_bytes()
+}
+
+#[test]
+fn test_trap_springs_on_public_trail() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join(&client, &hunter, &prey);
+
+    let game = client.get_game(&session_id);
+    let (px, py) = find_adjacent_plains_avoiding(
+        game.map_index, game.prey_x, game.prey_y, game.hunter_x, game.hunter_y,
+    );
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    client.hunter_place_trap(&session_id, &trap_commitment(&env, px, py, &salt));
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    client.prey_move_public(&session_id, &px, &py);
+
+    let sprung = client.hunter_reveal_trap(&session_id, &0, &px, &py, &salt);
+    assert!(sprung);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert_eq!(game.player1_score, 1);
+    assert_eq!(game.traps.len(), 0);
+}
+
+#[test]
+fn test_trap_reveal_miss_and_mismatch() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join(&client, &hunter, &prey);
+
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    client.hunter_place_trap(&session_id, &trap_commitment(&env, 7, 7, &salt));
+
+    let wrong_salt = BytesN::from_array(&env, &[8u8; 32]);
+    let result = client.try_hunter_reveal_trap(&session_id, &0, &7, &7, &wrong_salt);
+    assert_zk_hunt_error(&result, Error::TrapMismatch);
+
+    assert!(!client.hunter_reveal_trap(&session_id, &0, &7, &7, &salt));
+    let game = client.get_game(&session_id);
+    assert!(game.traps.get(0).unwrap().revealed);
+    assert_eq!(game.phase, GamePhase::HunterTurn);
+
+    let result = client.try_hunter_reveal_trap(&session_id, &0, &7, &7, &salt);
+    assert_zk_hunt_error(&result, Error::TrapNotFound);
+}
+
+#[test]
+fn test_revealed_trap_checks_hidden_prey() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, GameConfig::default());
+    prey_jungle_step(&env, &client, session_id, 120);
+
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    client.hunter_place_trap(&session_id, &trap_commitment(&env, 3, 4, &salt));
+    assert!(!client.hunter_reveal_trap(&session_id, &0, &3, &4, &salt));
+    let game = client.get_game(&session_id);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+
+    // The hidden prey must prove it is off the trap before moving
+    let mask = 1u64 << (4 * 8 + 3);
+    assert_eq!(client.get_game(&session_id).trap_check_mask, mask);
+    let new_commitment = BytesN::from_array(&env, &[121u8; 32]);
+    let result = client.try_prey_move_jungle(&session_id, &new_commitment, &Bytes::new(&env));
+    assert_zk_hunt_error(&result, Error::TrapProofRequired);
+
+    client.prey_prove_traps(&session_id, &flare_proof(&env, &game.prey_commitment, mask));
+    assert_eq!(client.get_game(&session_id).trap_check_mask, 0);
+    assert_zk_hunt_error(&client.try_prey_prove_traps(&session_id, &Bytes::new(&env)), Error::NoTrapCheck);
+    prey_jungle_step(&env, &client, session_id, 121);
+}
+
+#[test]
+fn test_trap_check_concede_awards_round() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, GameConfig::default());
+    prey_jungle_step(&env, &client, session_id, 120);
+
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    client.hunter_place_trap(&session_id, &trap_commitment(&env, 3, 4, &salt));
+    client.hunter_reveal_trap(&session_id, &0, &3, &4, &salt);
+    let game = client.get_game(&session_id);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+
+    client.prey_prove_traps(&session_id, &Bytes::new(&env));
+    let game = client.get_game(&session_id);
+    assert_eq!((game.round, game.player1_score), (2, 1));
+}
+
+#[test]
+fn test_traps_revealed_after_turn_limit() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, GameConfig::default());
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    prey_jungle_step(&env, &client, session_id, 130);
+    client.hunter_place_trap(&session_id, &trap_commitment(&env, 3, 4, &salt));
+    let game = client.get_game(&session_id);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    hide_whole_round(&env, &client, session_id);
+
+    // The round waits for the hunter's last reveal instead of scoring at once
+    let game = client.get_game(&session_id);
+    assert_eq!((game.phase, game.round), (GamePhase::TrapReveal, 1));
+    client.hunter_reveal_trap(&session_id, &0, &3, &4, &salt);
+    assert_zk_hunt_error(&client.try_close_trap_reveal(&session_id), Error::DeadlineNotReached);
+
+    // The prey answers the check, so the survival counts
+    let game = client.get_game(&session_id);
+    client.prey_prove_traps(&session_id, &flare_proof(&env, &game.prey_commitment, game.trap_check_mask));
+    let game = client.get_game(&session_id);
+    assert_eq!((game.round, game.player1_score, game.player2_score), (2, 0, 1));
+}
+
+#[test]
+fn test_unanswered_trap_check_at_round_end() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, GameConfig::default());
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    prey_jungle_step(&env, &client, session_id, 130);
+    client.hunter_place_trap(&session_id, &trap_commitment(&env, 3, 4, &salt));
+    let game = client.get_game(&session_id);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    hide_whole_round(&env, &client, session_id);
+
+    client.hunter_reveal_trap(&session_id, &0, &3, &4, &salt);
+    env.ledger().with_mut(|l| l.sequence_number += 121);
+    client.close_trap_reveal(&session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.round, game.player1_score, game.player2_score), (2, 1, 0));
+}
+
+#[test]
+fn test_trap_limit() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join(&client, &hunter, &prey);
+
+    for i in 0..2u8 {
+        client.hunter_place_trap(&session_id, &BytesN::from_array(&env, &[i; 32]));
+    }
+    let result = client.try_hunter_place_trap(&session_id, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_zk_hunt_error(&result, Error::NoTraps);
+}
+
+// ============================================================================
+// Decoy Tests
+// ============================================================================
+
+/// Search proof blob for the pending search, answered for `commitment`.
+fn search_proof(env: &Env, client: &ZkHuntContractClient, session_id: u32, commitment: &BytesN<32>) -> Bytes {
+    let game = client.get_game(&session_id);
+    let mut fields = [[0u8; 32]; 19];
+    fields[0] = commitment.to_array();
+    for i in 0..9u32 {
+        let (x, y) = match (game.searched_tiles_x.get(i), game.searched_tiles_y.get(i)) {
+            (Some(x), Some(y)) => (x, y),
+            _ => (255, 255),
+        };
+        fields[1 + i as usize] = field_u32(x);
+        fields[10 + i as usize] = field_u32(y);
+    }
+    proof_blob(env, &fields)
+}
+
+/// Hidden prey takes a jungle move so the hunter gets the next turn.
+fn prey_jungle_step(env: &Env, client: &ZkHuntContractClient, session_id: u32, tag: u8) {
+    let game = client.get_game(&session_id);
+    let new_commitment = BytesN::from_array(env, &[tag; 32]);
+    let proof = move_proof(env, &game.prey_commitment, &new_commitment, game.map_index, game.burned_tiles);
+    client.prey_move_jungle(&session_id, &new_commitment, &proof);
+}
+
+#[test]
+fn test_decoy_absorbs_search() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, GameConfig::default());
+
+    let decoy = BytesN::from_array(&env, &[90u8; 32]);
+    client.prey_place_decoy(&session_id, &decoy);
+    prey_jungle_step(&env, &client, session_id, 91);
+
+    client.hunter_power_search(&session_id);
+
+    // A decoy proof for an unknown index or the wrong commitment is rejected
+    let proof = search_proof(&env, &client, session_id, &decoy);
+    let result = client.try_respond_search_with_decoy(&session_id, &1, &proof);
+    assert_zk_hunt_error(&result, Error::DecoyNotFound);
+    let game = client.get_game(&session_id);
+    let wrong = search_proof(&env, &client, session_id, &game.prey_commitment);
+    assert!(client.try_respond_search_with_decoy(&session_id, &0, &wrong).is_err());
+
+    client.respond_search_with_decoy(&session_id, &0, &proof);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::PreyTurn);
+    assert_eq!(game.decoys.len(), 0);
+    assert_eq!(game.searched_tiles_x.len(), 0);
+}
+
+/// Verifier that rejects proofs checked against a vk starting with 0xff,
+/// standing in for a circuit whose statement is false.
+#[contract]
+pub struct VkRejectingVerifier;
+
+#[contractimpl]
+impl VkRejectingVerifier {
+    pub fn verify_proof(env: Env, vk_json: Bytes, _proof_blob: Bytes) -> BytesN<32> {
+        if vk_json.get(0) == Some(0xff) {
+            panic!("invalid proof")
+        }
+        BytesN::from_array(&env, &[1u8; 32])
+    }
+}
+
+#[test]
+fn test_decoy_absorbs_a_real_hit() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, GameConfig::default());
+
+    let decoy = BytesN::from_array(&env, &[90u8; 32]);
+    client.prey_place_decoy(&session_id, &decoy);
+    prey_jungle_step(&env, &client, session_id, 91);
+    client.hunter_power_search(&session_id);
+
+    // The prey stands on a searched tile, so no search_response proof holds
+    env.register_at(&Address::from_str(&env, ULTRAHONK_CONTRACT_ADDRESS), VkRejectingVerifier, ());
+    let dummy_vk = Bytes::from_array(&env, &[0u8; 32]);
+    client.set_vks(&dummy_vk, &Bytes::from_array(&env, &[0xffu8; 32]));
+    let game = client.get_game(&session_id);
+    let proof = search_proof(&env, &client, session_id, &game.prey_commitment);
+    assert!(client.try_respond_search(&session_id, &proof).is_err());
+
+    // The decoy takes the hit instead, once
+    let proof = search_proof(&env, &client, session_id, &decoy);
+    client.respond_search_with_decoy(&session_id, &0, &proof);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::PreyTurn);
+    assert_eq!(game.round, 1);
+    assert!(game.decoys.is_empty());
+}
+
+#[test]
+fn test_decoys_expire_and_are_bounded() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, GameConfig::default());
+
+    client.prey_place_decoy(&session_id, &BytesN::from_array(&env, &[90u8; 32]));
+    client.prey_place_decoy(&session_id, &BytesN::from_array(&env, &[91u8; 32]));
+    let result = client.try_prey_place_decoy(&session_id, &BytesN::from_array(&env, &[92u8; 32]));
+    assert_zk_hunt_error(&result, Error::TooManyDecoys);
+    assert_eq!(client.get_game(&session_id).decoys.get(0).unwrap().expires_turn, 4);
+
+    for turn in 0..3u8 {
+        assert_eq!(client.get_game(&session_id).decoys.len(), 2);
+        prey_jungle_step(&env, &client, session_id, 100 + turn);
+        let game = client.get_game(&session_id);
+        client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    }
+    assert_eq!(client.get_game(&session_id).decoys.len(), 0);
+}
+
+#[test]
+fn test_decoy_requires_hidden_prey() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join(&client, &hunter, &prey);
+
+    let game = client.get_game(&session_id);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    let result = client.try_prey_place_decoy(&session_id, &BytesN::from_array(&env, &[90u8; 32]));
+    assert_zk_hunt_error(&result, Error::PreyNotHidden);
+}
+
+// ============================================================================
+// Burn Tests
+// ============================================================================
+
+/// Hidden game where the hunter has stepped next to a jungle tile; returns
+/// the session at the hunter's second turn and the tile to burn.
+fn start_burn_game(
+    env: &Env,
+    client: &ZkHuntContractClient,
+    hunter: &Address,
+    prey: &Address,
+) -> (u32, u32, u32) {
+    let session_id = start_hidden_game(env, client, hunter, prey, GameConfig::default());
+    let game = client.get_game(&session_id);
+    let (x, y, sx, sy) = find_move_and_search(game.map_index, game.hunter_x, game.hunter_y)
+        .expect("no jungle near hunter spawn");
+    client.hunter_move(&session_id, &x, &y);
+    prey_jungle_step(env, client, session_id, 110);
+    (session_id, sx, sy)
+}
+
+#[test]
+fn test_burn_hidden_prey_proves_absence() {
+    let (env, client, hunter, prey) = setup_test();
+    let (session_id, sx, sy) = start_burn_game(&env, &client, &hunter, &prey);
+
+    client.hunter_burn(&session_id, &sx, &sy);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::BurnPending);
+    assert_eq!(game.burned_tiles, 1u64 << (sy * 8 + sx));
+
+    let proof = search_proof(&env, &client, session_id, &game.prey_commitment);
+    client.respond_burn(&session_id, &proof);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::PreyTurn);
+    assert!(game.prey_is_hidden);
+
+    // Jungle moves must now commit to the burned overlay
+    let new_commitment = BytesN::from_array(&env, &[111u8; 32]);
+    let stale = move_proof(&env, &game.prey_commitment, &new_commitment, game.map_index, 0);
+    assert!(client.try_prey_move_jungle(&session_id, &new_commitment, &stale).is_err());
+    prey_jungle_step(&env, &client, session_id, 111);
+
+    // The burned tile is plains now
+    let result = client.try_hunter_burn(&session_id, &sx, &sy);
+    assert_zk_hunt_error(&result, Error::NotJungle);
+}
+
+#[test]
+fn test_burn_concede_reveals_prey() {
+    let (env, client, hunter, prey) = setup_test();
+    let (session_id, sx, sy) = start_burn_game(&env, &client, &hunter, &prey);
+
+    client.hunter_burn(&session_id, &sx, &sy);
+    client.respond_burn(&session_id, &Bytes::new(&env));
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::PreyTurn);
+    assert!(!game.prey_is_hidden);
+    assert_eq!((game.prey_x, game.prey_y), (sx, sy));
+}
+
+#[test]
+fn test_unanswered_burn_claimed_after_deadline() {
+    let (env, client, hunter, prey) = setup_test();
+    let (session_id, sx, sy) = start_burn_game(&env, &client, &hunter, &prey);
+
+    client.hunter_burn(&session_id, &sx, &sy);
+    assert_zk_hunt_error(&client.try_claim_catch(&session_id), Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|l| l.sequence_number += 121);
+    client.claim_catch(&session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.round, game.player1_score), (2, 1));
+}
+
+// ============================================================================
+// Shrinking Zone Tests
+// ============================================================================
+
+fn zone_config(start: u32) -> GameConfig {
+    GameConfig {
+        zone_start_turn: start,
+        ..Default::default()
+    }
+}
+
+fn zone_proof(env: &Env, commitment: &BytesN<32>, inset: u32) -> Bytes {
+    proof_blob(env, &[commitment.to_array(), field_u32(inset)])
+}
+
+#[test]
+fn test_public_prey_outside_zone_loses_round() {
+    let (_env, client, hunter, prey) = setup_test();
+
+    // Find a game where the prey can step onto the outer ring (hazardous from turn 1)
+    for _ in 0..20 {
+        let session_id = client.create_game_with_config(&hunter, &zone_config(1), &None);
+        client.join_game(&session_id, &prey);
+        let game = client.get_game(&session_id);
+        let map = &MAPS[game.map_index as usize];
+        let offsets: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+        let edge = offsets.iter().find_map(|(ox, oy)| {
+            let (x, y) = (game.prey_x as i32 + ox, game.prey_y as i32 + oy);
+            let on_board = (0..8).contains(&x) && (0..8).contains(&y);
+            let on_edge = x == 0 || y == 0 || x == 7 || y == 7;
+            let clear = (x as u32, y as u32) != (game.hunter_x, game.hunter_y);
+            (on_board && on_edge && clear && map[(y * 8 + x) as usize] == 0).then_some((x as u32, y as u32))
+        });
+        let Some((px, py)) = edge else { continue };
+
+        client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+        client.prey_move_public(&session_id, &px, &py);
+
+        let game = client.get_game(&session_id);
+        assert_eq!(game.round, 2);
+        assert_eq!(game.player1_score, 1);
+        return;
+    }
+    panic!("no prey spawn next to the board edge");
+}
+
+#[test]
+fn test_hidden_prey_must_prove_zone() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, zone_config(1));
+
+    let game = client.get_game(&session_id);
+    let new_commitment = BytesN::from_array(&env, &[120u8; 32]);
+    let proof = move_proof(&env, &game.prey_commitment, &new_commitment, game.map_index, game.burned_tiles);
+    let result = client.try_prey_move_jungle(&session_id, &new_commitment, &proof);
+    assert_zk_hunt_error(&result, Error::ZoneProofRequired);
+
+    // Proof for the wrong inset or another commitment is rejected
+    let wrong = zone_proof(&env, &new_commitment, 2);
+    assert!(client.try_prey_prove_zone(&session_id, &new_commitment, &wrong).is_err());
+    let wrong = zone_proof(&env, &game.prey_commitment, 1);
+    assert!(client.try_prey_prove_zone(&session_id, &new_commitment, &wrong).is_err());
+
+    // Proving the position before the move does not cover the move
+    let zone = zone_proof(&env, &game.prey_commitment, 1);
+    client.prey_prove_zone(&session_id, &game.prey_commitment, &zone);
+    let result = client.try_prey_move_jungle(&session_id, &new_commitment, &proof);
+    assert_zk_hunt_error(&result, Error::ZoneProofRequired);
+
+    let zone = zone_proof(&env, &new_commitment, 1);
+    client.prey_prove_zone(&session_id, &new_commitment, &zone);
+    client.prey_move_jungle(&session_id, &new_commitment, &proof);
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::HunterTurn);
+}
+
+#[test]
+fn test_zone_concede_and_rule_off() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, GameConfig::default());
+    let commitment = client.get_game(&session_id).prey_commitment;
+    let result = client.try_prey_prove_zone(&session_id, &commitment, &Bytes::new(&env));
+    assert_zk_hunt_error(&result, Error::RuleDisabled);
+
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, zone_config(1));
+    client.prey_prove_zone(&session_id, &commitment, &Bytes::new(&env));
+    assert_eq!(client.get_game(&session_id).round, 2);
+}
+
+// ============================================================================
+// Escape Mode Tests
+// ============================================================================
+
+fn escape_config(objective: Objective) -> GameConfig {
+    GameConfig {
+        objective,
+        ..Default::default()
+    }
+}
+
+/// Hidden prey sits out the whole round in the jungle while the hunter stays put.
+fn survive_hidden_round(env: &Env, client: &ZkHuntContractClient, session_id: u32) {
+    for turn in 0..10u8 {
+        prey_jungle_step(env, client, session_id, 130 + turn);
+        let game = client.get_game(&session_id);
+        if game.round > 1 {
+            return;
+        }
+        client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    }
+}
+
+#[test]
+fn test_escape_survival_is_draw_or_loss() {
+    let (env, client, hunter, prey) = setup_test();
+
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, escape_config(Objective::EscapeOrDraw));
+    survive_hidden_round(&env, &client, session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert_eq!((game.player1_score, game.player2_score), (0, 0));
+
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, escape_config(Objective::EscapeOrLose));
+    survive_hidden_round(&env, &client, session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert_eq!((game.player1_score, game.player2_score), (1, 0));
+}
+
+#[test]
+fn test_hidden_extraction_wins_round() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, escape_config(Objective::EscapeOrLose));
+
+    let game = client.get_game(&session_id);
+    let wrong = proof_blob(&env, &[game.prey_commitment.to_array(), field_u64(1 << 20)]);
+    assert!(client.try_prey_extract_hidden(&session_id, &wrong).is_err());
+
+    let mask = EXTRACTION_TILES[game.map_index as usize];
+    let proof = proof_blob(&env, &[game.prey_commitment.to_array(), field_u64(mask)]);
+    client.prey_extract_hidden(&session_id, &proof);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert_eq!((game.player1_score, game.player2_score), (0, 1));
+}
+
+#[test]
+fn test_prey_never_spawns_on_extraction_tile() {
+    let (_env, client, hunter, prey) = setup_test();
+
+    for _ in 0..40 {
+        let session_id = client.create_game_with_config(&hunter, &escape_config(Objective::EscapeOrLose), &None);
+        client.join_game(&session_id, &prey);
+        let game = client.get_game(&session_id);
+        let tiles = EXTRACTION_TILES[game.map_index as usize];
+        for i in 0u32..64 {
+            if tiles >> i & 1 == 1 {
+                let dist = abs_diff(i % 8, game.prey_x) + abs_diff(i / 8, game.prey_y);
+                assert!(dist >= 3, "prey spawned {} tiles from extraction", dist);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_extraction_requires_escape_mode() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, GameConfig::default());
+
+    let game = client.get_game(&session_id);
+    let mask = EXTRACTION_TILES[game.map_index as usize];
+    let proof = proof_blob(&env, &[game.prey_commitment.to_array(), field_u64(mask)]);
+    let result = client.try_prey_extract_hidden(&session_id, &proof);
+    assert_zk_hunt_error(&result, Error::RuleDisabled);
+}
+
+// ============================================================================
+// Collectible Tests
+// ============================================================================
+
+fn item_config(item_count: u32, items_required: u32) -> GameConfig {
+    GameConfig {
+        item_count,
+        items_required,
+        ..Default::default()
+    }
+}
+
+/// Item claim proof for the prey's current commitment, with nullifier `tag`.
+fn item_proof(env: &Env, game: &Game, tag: u8) -> (BytesN<32>, Bytes) {
+    let nullifier = BytesN::from_array(env, &[tag; 32]);
+    let proof = proof_blob(
+        env,
+        &[
+            game.prey_commitment.to_array(),
+            field_u64(game.item_candidates),
+            field_u32(game.config.item_count),
+            game.items_commitment.clone().unwrap().to_array(),
+            nullifier.to_array(),
+        ],
+    );
+    (nullifier, proof)
+}
+
+#[test]
+fn test_item_candidates_placed_on_jungle() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, item_config(3, 2));
+
+    // Twice as many candidates as items; the hunter cannot tell which hold one
+    let game = client.get_game(&session_id);
+    assert_eq!(game.item_candidates.count_ones(), 6);
+    for idx in 0..64u32 {
+        if (game.item_candidates >> idx) & 1 == 1 {
+            assert_eq!(MAPS[game.map_index as usize][idx as usize], 1);
+        }
+    }
+}
+
+#[test]
+fn test_prey_must_commit_items_first() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, item_config(3, 2));
+
+    let result = client.try_prey_move_jungle(
+        &session_id,
+        &BytesN::from_array(&env, &[7u8; 32]),
+        &Bytes::new(&env),
+    );
+    assert_zk_hunt_error(&result, Error::ItemsNotCommitted);
+
+    client.prey_commit_items(&session_id, &BytesN::from_array(&env, &[9u8; 32]));
+    let result = client.try_prey_commit_items(&session_id, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_zk_hunt_error(&result, Error::ItemsAlreadyCommitted);
+}
+
+#[test]
+fn test_collecting_items_wins_round() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, item_config(3, 2));
+    client.prey_commit_items(&session_id, &BytesN::from_array(&env, &[9u8; 32]));
+
+    let game = client.get_game(&session_id);
+    let (nullifier, proof) = item_proof(&env, &game, 1);
+    client.prey_claim_item(&session_id, &nullifier, &proof);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.items_collected, 1);
+    assert_eq!(game.phase, GamePhase::PreyTurn);
+    assert!(game.prey_is_hidden);
+
+    // A taken item cannot be claimed again
+    let result = client.try_prey_claim_item(&session_id, &nullifier, &proof);
+    assert_zk_hunt_error(&result, Error::ItemAlreadyClaimed);
+
+    let (nullifier, proof) = item_proof(&env, &game, 2);
+    client.prey_claim_item(&session_id, &nullifier, &proof);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert_eq!((game.player1_score, game.player2_score), (0, 1));
+}
+
+#[test]
+fn test_item_claim_bound_to_committed_pick() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, item_config(3, 2));
+    client.prey_commit_items(&session_id, &BytesN::from_array(&env, &[9u8; 32]));
+
+    // A proof against another pick is rejected
+    let mut game = client.get_game(&session_id);
+    game.items_commitment = Some(BytesN::from_array(&env, &[8u8; 32]));
+    let (nullifier, proof) = item_proof(&env, &game, 1);
+    assert!(client.try_prey_claim_item(&session_id, &nullifier, &proof).is_err());
+    assert_eq!(client.get_game(&session_id).items_collected, 0);
+}
+
+#[test]
+fn test_item_config_validation() {
+    let (_env, client, hunter, _prey) = setup_test();
+    for config in [item_config(3, 4), item_config(9, 1), item_config(2, 0)] {
+        let result = client.try_create_game_with_config(&hunter, &config, &None);
+        assert_zk_hunt_error(&result, Error::InvalidConfig);
+    }
+}
+
+// ============================================================================
+// Team Mode Tests
+// ============================================================================
+
+fn team_config(team_mode: TeamMode) -> GameConfig {
+    GameConfig {
+        team_mode,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_team_game_starts_when_seats_filled() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = client.create_game_with_config(&hunter, &team_config(TeamMode::TwoVsTwo), &None);
+    client.join_game(&session_id, &prey);
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::WaitingForPlayer2);
+    assert_zk_hunt_error(&client.try_join_game(&session_id, &Address::generate(&env)), Error::WrongPhase);
+
+    let hunter2 = Address::generate(&env);
+    client.join_team(&session_id, &hunter2, &1);
+    assert_zk_hunt_error(&client.try_join_team(&session_id, &Address::generate(&env), &1), Error::TeamFull);
+    assert_zk_hunt_error(&client.try_join_team(&session_id, &prey, &2), Error::AlreadySeated);
+
+    client.join_team(&session_id, &Address::generate(&env), &2);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::HunterTurn);
+    assert_eq!(game.teams.team1.len(), 1);
+    assert_eq!(game.teams.team2.len(), 1);
+    assert_eq!(game.teams.bench.len(), 2);
+
+    let solo = client.create_game(&hunter);
+    assert_zk_hunt_error(&client.try_join_team(&solo, &hunter2, &1), Error::RuleDisabled);
+}
+
+#[test]
+fn test_team_members_alternate_turns() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = client.create_game_with_config(&hunter, &team_config(TeamMode::TwoVsTwo), &None);
+    client.join_game(&session_id, &prey);
+    let hunter2 = Address::generate(&env);
+    let prey2 = Address::generate(&env);
+    client.join_team(&session_id, &hunter2, &1);
+    client.join_team(&session_id, &prey2, &2);
+
+    assert!(play_one_turn(&client, session_id));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::HunterTurn);
+    assert_eq!((game.hunter, game.prey), (hunter2, prey2));
+    assert!(game.teams.bench.iter().any(|member| member.player == hunter && member.is_hunter));
+    assert!(game.teams.bench.iter().any(|member| member.player == prey && !member.is_hunter));
+
+    play_one_turn(&client, session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.hunter, game.prey), (hunter, prey));
+}
+
+#[test]
+fn test_team_partner_scores_for_team() {
+    let (env, client, hunter, prey) = setup_test();
+    let config = GameConfig {
+        hidden_spawn: true,
+        ..team_config(TeamMode::TwoVsOne)
+    };
+    let session_id = client.create_game_with_config(&hunter, &config, &None);
+    client.join_game(&session_id, &prey);
+    let hunter2 = Address::generate(&env);
+    client.join_team(&session_id, &hunter2, &1);
+
+    let game = client.get_game(&session_id);
+    let commitment = dummy_commitment(&env);
+    let proof = spawn_proof(&env, &commitment, game.map_index, game.hunter_x, game.hunter_y);
+    client.prey_spawn_hidden(&session_id, &commitment, &proof);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    prey_jungle_step(&env, &client, session_id, 150);
+
+    // Player 1's partner hunts with the team's shared flare and wins the round for team 1
+    let game = client.get_game(&session_id);
+    assert_eq!(game.hunter, hunter2);
+    client.hunter_flare(&session_id, &0x0404040404040404);
+    client.respond_flare(&session_id, &Bytes::new(&env));
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert_eq!((game.player1_score, game.player2_score), (1, 0));
+    assert_eq!(game.flare_uses_remaining, 1);
+}
+
+#[test]
+fn test_bench_spawns_clear_of_other_players() {
+    let (env, client, hunter, prey) = setup_test();
+
+    for _ in 0..20 {
+        let session_id = client.create_game_with_config(&hunter, &team_config(TeamMode::TwoVsTwo), &None);
+        client.join_game(&session_id, &prey);
+        client.join_team(&session_id, &Address::generate(&env), &1);
+        client.join_team(&session_id, &Address::generate(&env), &2);
+
+        let game = client.get_game(&session_id);
+        let mut players = std::vec![(true, game.hunter_x, game.hunter_y), (false, game.prey_x, game.prey_y)];
+        for member in game.teams.bench.iter() {
+            players.push((member.is_hunter, member.x, member.y));
+        }
+        for (i, a) in players.iter().enumerate() {
+            for b in players.iter().skip(i + 1) {
+                let dist = abs_diff(a.1, b.1) + abs_diff(a.2, b.2);
+                assert!(dist > 0, "two players spawned on one tile");
+                if a.0 != b.0 {
+                    assert!(dist >= 3, "hunter and prey spawned {} tiles apart", dist);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_hidden_bench_prey_out_of_reach() {
+    let (env, client, hunter, prey) = setup_test();
+    let config = GameConfig {
+        hidden_spawn: true,
+        ..team_config(TeamMode::TwoVsTwo)
+    };
+    let session_id = client.create_game_with_config(&hunter, &config, &None);
+    client.join_game(&session_id, &prey);
+    client.join_team(&session_id, &Address::generate(&env), &1);
+    let prey2 = Address::generate(&env);
+    client.join_team(&session_id, &prey2, &2);
+
+    let game = client.get_game(&session_id);
+    let commitment = dummy_commitment(&env);
+    let proof = spawn_proof(&env, &commitment, game.map_index, game.hunter_x, game.hunter_y);
+    client.prey_spawn_hidden(&session_id, &commitment, &proof);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    prey_jungle_step(&env, &client, session_id, 150);
+
+    // The hidden prey rotated to the bench; flares only reach the visible partner
+    let game = client.get_game(&session_id);
+    assert_eq!(game.prey, prey2);
+    assert!(!game.prey_is_hidden);
+    let benched = game.teams.bench.iter().find(|member| member.player == prey).unwrap();
+    assert!(benched.is_hidden);
+    let result = client.try_hunter_flare(&session_id, &0x0404040404040404);
+    assert_zk_hunt_error(&result, Error::PreyNotHidden);
+
+    // Back in the active slot it can be flared again
+    play_one_turn(&client, session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.prey, prey);
+    assert!(game.prey_is_hidden);
+    client.hunter_flare(&session_id, &0x0404040404040404);
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::FlarePending);
+}
+
+#[test]
+fn test_move_and_search_catches_visible_bench_prey() {
+    let (env, client, hunter, prey) = setup_test();
+    let config = GameConfig {
+        hidden_spawn: true,
+        compound_turn: true,
+        ..team_config(TeamMode::TwoVsTwo)
+    };
+    let session_id = client.create_game_with_config(&hunter, &config, &None);
+    client.join_game(&session_id, &prey);
+    client.join_team(&session_id, &Address::generate(&env), &1);
+    client.join_team(&session_id, &Address::generate(&env), &2);
+    let game = client.get_game(&session_id);
+    let commitment = dummy_commitment(&env);
+    let proof = spawn_proof(&env, &commitment, game.map_index, game.hunter_x, game.hunter_y);
+    client.prey_spawn_hidden(&session_id, &commitment, &proof);
+
+    // Put the visible benched prey on the hunter's next step
+    let mut game = client.get_game(&session_id);
+    let (x, y, sx, sy) = find_move_and_search(game.map_index, game.hunter_x, game.hunter_y)
+        .expect("no jungle near hunter spawn");
+    let index = game.teams.bench.iter().position(|member| !member.is_hunter).unwrap() as u32;
+    let mut member = game.teams.bench.get(index).unwrap();
+    (member.x, member.y) = (x, y);
+    game.teams.bench.set(index, member);
+    env.as_contract(&client.address, || {
+        env.storage().temporary().set(&DataKey::Game(session_id), &game);
+    });
+
+    client.hunter_move_and_search(&session_id, &x, &y, &sx, &sy);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert_eq!((game.player1_score, game.player2_score), (1, 0));
+}
+
+// ============================================================================
+// Hidden Hunter Tests
+// ============================================================================
+
+fn hidden_hunter_config() -> GameConfig {
+    GameConfig {
+        hidden_hunter: true,
+        ..Default::default()
+    }
+}
+
+/// Adjacency proof blob for a hidden hunter searching `tiles` (padded with 255 to 9).
+fn adjacency_proof(env: &Env, commitment: &BytesN<32>, tiles: &[(u32, u32)]) -> Bytes {
+    let mut fields = [[0u8; 32]; 19];
+    fields[0] = commitment.to_array();
+    for i in 0..9 {
+        let (x, y) = tiles.get(i).copied().unwrap_or((255, 255));
+        fields[1 + i] = field_u32(x);
+        fields[10 + i] = field_u32(y);
+    }
+    proof_blob(env, &fields)
+}
+
+/// `jungle_enter` proof blob for stepping from (x, y) into `commitment`.
+fn enter_proof(env: &Env, x: u32, y: u32, commitment: &BytesN<32>, map_id: u32, burned: u64) -> Bytes {
+    proof_blob(
+        env,
+        &[field_u32(x), field_u32(y), commitment.to_array(), field_u32(map_id), field_u64(burned)],
+    )
+}
+
+/// `jungle_exit` proof blob for leaving `commitment` onto (x, y).
+fn exit_proof(env: &Env, commitment: &BytesN<32>, x: u32, y: u32) -> Bytes {
+    proof_blob(env, &[commitment.to_array(), field_u32(x), field_u32(y)])
+}
+
+fn hunter_hide(env: &Env, client: &ZkHuntContractClient, session_id: u32, tag: u8) -> BytesN<32> {
+    let game = client.get_game(&session_id);
+    let commitment = BytesN::from_array(env, &[tag; 32]);
+    let proof = enter_proof(env, game.hunter_x, game.hunter_y, &commitment, game.map_index, game.burned_tiles);
+    client.hunter_enter_jungle(&session_id, &commitment, &proof);
+    commitment
+}
+
+#[test]
+fn test_hidden_hunter_moves_under_commitment() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = client.create_game_with_config(&hunter, &hidden_hunter_config(), &None);
+    client.join_game(&session_id, &prey);
+
+    let commitment = hunter_hide(&env, &client, session_id, 160);
+    let game = client.get_game(&session_id);
+    assert!(game.hunter_is_hidden);
+    assert_eq!(game.hunter_commitment, commitment);
+    assert_eq!(game.phase, GamePhase::PreyTurn);
+
+    let (px, py) = find_adjacent_plains_avoiding(game.map_index, game.prey_x, game.prey_y, 8, 8);
+    client.prey_move_public(&session_id, &px, &py);
+
+    // Public abilities are off while hidden; jungle moves are proven
+    assert_zk_hunt_error(&client.try_hunter_move(&session_id, &0, &0), Error::HunterHidden);
+    let next = BytesN::from_array(&env, &[161u8; 32]);
+    let game = client.get_game(&session_id);
+    let wrong = move_proof(&env, &next, &next, game.map_index, game.burned_tiles);
+    assert!(client.try_hunter_move_jungle(&session_id, &next, &wrong).is_err());
+    let proof = move_proof(&env, &commitment, &next, game.map_index, game.burned_tiles);
+    client.hunter_move_jungle(&session_id, &next, &proof);
+    assert_eq!(client.get_game(&session_id).hunter_commitment, next);
+}
+
+#[test]
+fn test_hidden_hunter_searches_by_proof() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, hidden_hunter_config());
+    let commitment = hunter_hide(&env, &client, session_id, 170);
+    prey_jungle_step(&env, &client, session_id, 171);
+
+    let game = client.get_game(&session_id);
+    let map = &MAPS[game.map_index as usize];
+    let mut tiles = [(0u32, 0u32); 2];
+    let mut found = 0;
+    for idx in 0..64u32 {
+        if found < 2 && map[idx as usize] == 1 {
+            tiles[found] = (idx % 8, idx / 8);
+            found += 1;
+        }
+    }
+    let tiles_x = Vec::from_array(&env, [tiles[0].0, tiles[1].0]);
+    let tiles_y = Vec::from_array(&env, [tiles[0].1, tiles[1].1]);
+
+    // The proof must be for the hunter's own commitment
+    let wrong = adjacency_proof(&env, &game.prey_commitment, &tiles);
+    assert!(client.try_hunter_search_hidden(&session_id, &tiles_x, &tiles_y, &wrong).is_err());
+
+    let proof = adjacency_proof(&env, &commitment, &tiles);
+    client.hunter_search_hidden(&session_id, &tiles_x, &tiles_y, &proof);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::SearchPending);
+    assert_eq!(game.searched_tiles_x, tiles_x);
+    assert_eq!(game.power_searches_remaining, 1);
+
+    let proof = search_proof(&env, &client, session_id, &game.prey_commitment);
+    client.respond_search(&session_id, &proof);
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::PreyTurn);
+}
+
+#[test]
+fn test_hidden_hunter_rule_and_state_checks() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_game(&env, &client, &hunter, &prey, GameConfig::default());
+    let game = client.get_game(&session_id);
+    let commitment = dummy_commitment(&env);
+    let proof = enter_proof(&env, game.hunter_x, game.hunter_y, &commitment, game.map_index, game.burned_tiles);
+    assert_zk_hunt_error(
+        &client.try_hunter_enter_jungle(&session_id, &commitment, &proof),
+        Error::RuleDisabled,
+    );
+
+    let tiles = Vec::from_array(&env, [0u32]);
+    let proof = adjacency_proof(&env, &commitment, &[(0, 0)]);
+    assert_zk_hunt_error(
+        &client.try_hunter_search_hidden(&session_id, &tiles, &tiles, &proof),
+        Error::HunterNotHidden,
+    );
+    let proof = exit_proof(&env, &commitment, 0, 0);
+    assert_zk_hunt_error(&client.try_hunter_exit_jungle(&session_id, &0, &0, &proof), Error::HunterNotHidden);
+}
+
+#[test]
+fn test_hidden_hunter_enters_from_its_own_tile() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = client.create_game_with_config(&hunter, &hidden_hunter_config(), &None);
+    client.join_game(&session_id, &prey);
+
+    let game = client.get_game(&session_id);
+    let commitment = dummy_commitment(&env);
+    let proof = enter_proof(&env, game.prey_x, game.prey_y, &commitment, game.map_index, game.burned_tiles);
+    assert!(client.try_hunter_enter_jungle(&session_id, &commitment, &proof).is_err());
+    assert!(!client.get_game(&session_id).hunter_is_hidden);
+}
+
+#[test]
+fn test_hidden_hunter_far_exit_rejected() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = client.create_game_with_config(&hunter, &hidden_hunter_config(), &None);
+    client.join_game(&session_id, &prey);
+    let commitment = hunter_hide(&env, &client, session_id, 165);
+    let game = client.get_game(&session_id);
+    let (px, py) = find_adjacent_plains_avoiding(game.map_index, game.prey_x, game.prey_y, 8, 8);
+    client.prey_move_public(&session_id, &px, &py);
+
+    let game = client.get_game(&session_id);
+    let map = &MAPS[game.map_index as usize];
+    let far = (0..64u32)
+        .find(|&idx| map[idx as usize] == 0 && !(idx % 8 == game.prey_x && idx / 8 == game.prey_y))
+        .unwrap();
+    let (x, y) = (far % 8, far / 8);
+
+    // The proof must name the exit tile
+    let proof = exit_proof(&env, &commitment, (x + 1) % 8, y);
+    assert!(client.try_hunter_exit_jungle(&session_id, &x, &y, &proof).is_err());
+
+    // A tile more than a step from the commitment fails the jungle_exit proof
+    env.register_at(&Address::from_str(&env, ULTRAHONK_CONTRACT_ADDRESS), RejectingVerifier, ());
+    let proof = exit_proof(&env, &commitment, x, y);
+    assert!(client.try_hunter_exit_jungle(&session_id, &x, &y, &proof).is_err());
+    let game = client.get_game(&session_id);
+    assert!(game.hunter_is_hidden);
+    assert_eq!(game.phase, GamePhase::HunterTurn);
+}
+
+// ============================================================================
+// Scent Tests
+// ============================================================================
+
+fn scent_config(scent_delay: u32) -> GameConfig {
+    GameConfig {
+        scent_delay,
+        ..Default::default()
+    }
+}
+
+fn scent_proof(env: &Env, old: &BytesN<32>, current: &BytesN<32>, x: u32, y: u32, steps: u32) -> Bytes {
+    proof_blob(
+        env,
+        &[old.to_array(), current.to_array(), field_u32(x), field_u32(y), field_u32(steps)],
+    )
+}
+
+/// Hidden prey moves twice in jungle (hunter standing still) until its first scent is due.
+fn play_until_scent_due(env: &Env, client: &ZkHuntContractClient, session_id: u32) {
+    for tag in [180u8, 181] {
+        prey_jungle_step(env, client, session_id, tag);
+        let game = client.get_game(&session_id);
+        client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    }
+}
+
+#[test]
+fn test_scent_must_be_revealed_before_acting() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, scent_config(1));
+    play_until_scent_due(&env, &client, session_id);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.scent_history.len(), 2);
+    let next = BytesN::from_array(&env, &[182u8; 32]);
+    let proof = move_proof(&env, &game.prey_commitment, &next, game.map_index, game.burned_tiles);
+    let result = client.try_prey_move_jungle(&session_id, &next, &proof);
+    assert_zk_hunt_error(&result, Error::ScentRevealRequired);
+
+    let old = BytesN::from_array(&env, &[180u8; 32]);
+    let current = BytesN::from_array(&env, &[181u8; 32]);
+    client.prey_reveal_scent(&session_id, &2, &3, &scent_proof(&env, &old, &current, 2, 3, 1));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.scent_history.len(), 1);
+    assert_eq!(game.scent_trail.len(), 1);
+    let step = game.scent_trail.get(0).unwrap();
+    assert_eq!((step.turn, step.x, step.y), (1, 2, 3));
+
+    // Acting is possible again
+    client.prey_move_jungle(&session_id, &next, &proof);
+}
+
+#[test]
+fn test_scent_proof_inputs_checked() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, scent_config(1));
+    let commitment = dummy_commitment(&env);
+    let result = client.try_prey_reveal_scent(&session_id, &0, &0, &scent_proof(&env, &commitment, &commitment, 0, 0, 1));
+    assert_zk_hunt_error(&result, Error::NoScentDue);
+
+    play_until_scent_due(&env, &client, session_id);
+    let old = BytesN::from_array(&env, &[180u8; 32]);
+    let current = BytesN::from_array(&env, &[181u8; 32]);
+    for proof in [
+        scent_proof(&env, &current, &current, 2, 3, 1),
+        scent_proof(&env, &old, &old, 2, 3, 1),
+        scent_proof(&env, &old, &current, 2, 4, 1),
+        scent_proof(&env, &old, &current, 2, 3, 2),
+    ] {
+        assert!(client.try_prey_reveal_scent(&session_id, &2, &3, &proof).is_err());
+    }
+}
+
+#[test]
+fn test_scent_config_validation() {
+    let (_env, client, hunter, _prey) = setup_test();
+    let result = client.try_create_game_with_config(&hunter, &scent_config(5), &None);
+    assert_zk_hunt_error(&result, Error::InvalidConfig);
+    // 2v1 teams swap into the prey role in the second half
+    for team_mode in [TeamMode::TwoVsOne, TeamMode::TwoVsTwo] {
+        let config = GameConfig {
+            team_mode,
+            ..scent_config(2)
+        };
+        let result = client.try_create_game_with_config(&hunter, &config, &None);
+        assert_zk_hunt_error(&result, Error::InvalidConfig);
+    }
+}
+
+// ============================================================================
+// Round Audit Tests
+// ============================================================================
+
+fn audit_config(commit_reveal: bool) -> GameConfig {
+    GameConfig {
+        audit: true,
+        commit_reveal,
+        ..Default::default()
+    }
+}
+
+/// Hidden prey moves through jungle every turn (hunter standing still) until the round ends.
+fn hide_whole_round(env: &Env, client: &ZkHuntContractClient, session_id: u32) {
+    for turn in 0..10u8 {
+        prey_jungle_step(env, client, session_id, 190 + turn);
+        let game = client.get_game(&session_id);
+        if game.phase != GamePhase::HunterTurn {
+            return;
+        }
+        client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    }
+}
+
+/// Reveal every turn of a transcript on `tile`, with a matching `round_audit` proof blob.
+fn reveal_on(env: &Env, client: &ZkHuntContractClient, session_id: u32, round: u32, tile: u32) {
+    let count = client.get_transcript(&session_id, &round).commitments.len();
+    reveal_path(env, client, session_id, round, &std::vec![tile; count as usize]);
+}
+
+/// Reveal a transcript turn by turn on `tiles`, with a matching `round_audit` proof blob.
+fn reveal_path(env: &Env, client: &ZkHuntContractClient, session_id: u32, round: u32, tiles: &[u32]) {
+    let transcript = client.get_transcript(&session_id, &round);
+    let count = transcript.commitments.len();
+    let mut fields = [[0u8; 32]; 34];
+    let mut positions: Vec<u32> = Vec::new(env);
+    let mut nonces: Vec<BytesN<32>> = Vec::new(env);
+    for i in 0..count {
+        let nonce = BytesN::from_array(env, &[i as u8 + 1; 32]);
+        fields[i as usize] = transcript.commitments.get(i).unwrap().commitment.to_array();
+        fields[11 + i as usize] = field_u32(tiles[i as usize]);
+        fields[22 + i as usize] = nonce.to_array();
+        positions.push_back(tiles[i as usize]);
+        nonces.push_back(nonce);
+    }
+    fields[33] = field_u32(count);
+    client.reveal_round(&session_id, &round, &positions, &nonces, &proof_blob(env, &fields));
+}
+
+fn tile_distance(a: u32, b: u32) -> u32 {
+    abs_diff(a % 8, b % 8) + abs_diff(a / 8, b / 8)
+}
+
+/// Jungle tiles (a, b, c): b two tiles from a through jungle, c at least three from a.
+fn audit_tiles(map_index: u32) -> (u32, u32, u32) {
+    let map = &MAPS[map_index as usize];
+    let jungle = |t: u32| map[t as usize] == 1;
+    for a in (0..64u32).filter(|&t| jungle(t)) {
+        let dash = (0..64u32).find(|&b| {
+            jungle(b)
+                && tile_distance(a, b) == 2
+                && (0..64u32).any(|m| jungle(m) && tile_distance(a, m) == 1 && tile_distance(m, b) == 1)
+        });
+        let far = (0..64u32).find(|&c| jungle(c) && tile_distance(a, c) >= 3);
+        if let (Some(b), Some(c)) = (dash, far) {
+            return (a, b, c);
+        }
+    }
+    panic!("no audit tiles on map {}", map_index);
+}
+
+fn first_tile(map_index: u32, kind: u8) -> u32 {
+    (0..64u32).find(|&idx| MAPS[map_index as usize][idx as usize] == kind).unwrap()
+}
+
+/// Start a staked (commit-reveal) audited match with a hidden-spawned prey.
+fn start_staked_audit(env: &Env, client: &ZkHuntContractClient, hunter: &Address, prey: &Address) -> u32 {
+    let hunter_seed = BytesN::from_array(env, &[7u8; 32]);
+    let prey_seed = BytesN::from_array(env, &[9u8; 32]);
+    let config = GameConfig {
+        hidden_spawn: true,
+        ..audit_config(true)
+    };
+    let session_id = client.create_game_with_config(hunter, &config, &Some(seed_hash(env, &hunter_seed)));
+    client.join_game_with_seed(&session_id, prey, &seed_hash(env, &prey_seed));
+    client.reveal_seed(&session_id, hunter, &hunter_seed);
+    client.reveal_seed(&session_id, prey, &prey_seed);
+
+    let game = client.get_game(&session_id);
+    let commitment = dummy_commitment(env);
+    let proof = spawn_proof(env, &commitment, game.map_index, game.hunter_x, game.hunter_y);
+    client.prey_spawn_hidden(&session_id, &commitment, &proof);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    session_id
+}
+
+#[test]
+fn test_round_transcript_archived_and_revealed() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, audit_config(false));
+    let map_index = client.get_game(&session_id).map_index;
+    hide_whole_round(&env, &client, session_id);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert!(game.commitment_history.is_empty());
+    let transcript = client.get_transcript(&session_id, &1);
+    assert_eq!(transcript.commitments.len(), 11);
+    assert_eq!(transcript.commitments.get(0).unwrap().turn, 0);
+    assert_eq!(transcript.commitments.get(10).unwrap().turn, 10);
+    assert_eq!(transcript.result, AuditResult::Pending);
+
+    reveal_on(&env, &client, session_id, 1, first_tile(map_index, 1));
+    assert_eq!(client.get_transcript(&session_id, &1).result, AuditResult::Passed);
+
+    let result = client.try_reveal_round(&session_id, &1, &Vec::new(&env), &Vec::new(&env), &Bytes::new(&env));
+    assert_zk_hunt_error(&result, Error::AlreadyAudited);
+    let result = client.try_reveal_round(&session_id, &2, &Vec::new(&env), &Vec::new(&env), &Bytes::new(&env));
+    assert_zk_hunt_error(&result, Error::TranscriptNotFound);
+}
+
+#[test]
+fn test_staked_audit_timeout_forfeits_round() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_staked_audit(&env, &client, &hunter, &prey);
+    hide_whole_round(&env, &client, session_id);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::AuditPending);
+    assert_eq!((game.round, game.player1_score, game.player2_score), (1, 0, 1));
+    assert_zk_hunt_error(&client.try_claim_audit_timeout(&session_id), Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 721);
+    client.claim_audit_timeout(&session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.round, game.player1_score, game.player2_score), (2, 1, 0));
+    assert_eq!(client.get_transcript(&session_id, &1).result, AuditResult::Failed);
+}
+
+#[test]
+fn test_staked_audit_reveal_starts_next_round() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_staked_audit(&env, &client, &hunter, &prey);
+    let map_index = client.get_game(&session_id).map_index;
+    hide_whole_round(&env, &client, session_id);
+
+    // Revealing a plains tile is caught and forfeits the round
+    reveal_on(&env, &client, session_id, 1, first_tile(map_index, 0));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert_ne!(game.phase, GamePhase::AuditPending);
+    assert_eq!((game.player1_score, game.player2_score), (1, 0));
+    assert_eq!(client.get_transcript(&session_id, &1).result, AuditResult::Failed);
+}
+
+#[test]
+fn test_audit_rejects_impossible_path() {
+    let (env, client, hunter, prey) = setup_test();
+
+    // Jumping three tiles between turns fails the audit
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, audit_config(false));
+    let (a, _, c) = audit_tiles(client.get_game(&session_id).map_index);
+    hide_whole_round(&env, &client, session_id);
+    let mut tiles = [a; 11];
+    tiles[5..].fill(c);
+    reveal_path(&env, &client, session_id, 1, &tiles);
+    assert_eq!(client.get_transcript(&session_id, &1).result, AuditResult::Failed);
+
+    // The first hidden step is checked against the hidden spawn
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, audit_config(false));
+    let (a, _, c) = audit_tiles(client.get_game(&session_id).map_index);
+    hide_whole_round(&env, &client, session_id);
+    let mut tiles = [a; 11];
+    tiles[0] = c;
+    reveal_path(&env, &client, session_id, 1, &tiles);
+    assert_eq!(client.get_transcript(&session_id, &1).result, AuditResult::Failed);
+
+    // Two tiles is only legal on a dash turn
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, audit_config(false));
+    let (a, b, _) = audit_tiles(client.get_game(&session_id).map_index);
+    prey_jungle_step(&env, &client, session_id, 180);
+    let game = client.get_game(&session_id);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    let game = client.get_game(&session_id);
+    let next = BytesN::from_array(&env, &[181u8; 32]);
+    let proof = move_proof(&env, &game.prey_commitment, &next, game.map_index, game.burned_tiles);
+    client.prey_dash_jungle(&session_id, &next, &proof);
+    let game = client.get_game(&session_id);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    hide_whole_round(&env, &client, session_id);
+    assert_eq!(client.get_transcript(&session_id, &1).dash_turns, Vec::from_array(&env, [2u32]));
+
+    let mut tiles = [b; 11];
+    tiles[..2].fill(a);
+    reveal_path(&env, &client, session_id, 1, &tiles);
+    assert_eq!(client.get_transcript(&session_id, &1).result, AuditResult::Passed);
+}
+
+#[test]
+fn test_audit_path_starts_at_last_public_tile() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, audit_config(false));
+
+    // The prey shows itself on turn 1, then hides again
+    let game = client.get_game(&session_id);
+    let map = &MAPS[game.map_index as usize];
+    let plains = (0..64u32)
+        .find(|&p| {
+            map[p as usize] == 0
+                && tile_distance(p, game.hunter_y * 8 + game.hunter_x) > 1
+                && (0..64u32).any(|j| map[j as usize] == 1 && tile_distance(p, j) == 1)
+        })
+        .unwrap();
+    client.prey_exit_jungle(&session_id, &(plains % 8), &(plains / 8));
+    let game = client.get_game(&session_id);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    let game = client.get_game(&session_id);
+    let hidden = BytesN::from_array(&env, &[185u8; 32]);
+    let proof = move_proof(&env, &game.prey_commitment, &hidden, game.map_index, game.burned_tiles);
+    client.prey_enter_jungle(&session_id, &hidden, &proof);
+    let game = client.get_game(&session_id);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    hide_whole_round(&env, &client, session_id);
+
+    // Hidden steps must join the public tile, even if the jungle steps chain up
+    let transcript = client.get_transcript(&session_id, &1);
+    assert_eq!(transcript.trail.get(0).unwrap().turn, 1);
+    let far = (0..64u32)
+        .find(|&j| map[j as usize] == 1 && tile_distance(plains, j) >= 2)
+        .unwrap();
+    let count = transcript.commitments.len() as usize;
+    reveal_path(&env, &client, session_id, 1, &std::vec![far; count]);
+    assert_eq!(client.get_transcript(&session_id, &1).result, AuditResult::Failed);
+}
+
+#[test]
+fn test_audit_config_validation() {
+    let (_env, client, hunter, _prey) = setup_test();
+    for team_mode in [TeamMode::TwoVsOne, TeamMode::TwoVsTwo] {
+        let config = GameConfig {
+            team_mode,
+            ..audit_config(false)
+        };
+        let result = client.try_create_game_with_config(&hunter, &config, &None);
+        assert_zk_hunt_error(&result, Error::InvalidConfig);
+    }
+}
+
+// ============================================================================
+// EMP Freeze Tests
+// ============================================================================
+
+fn emp_config(emp_duration: u32, emp_range: u32) -> GameConfig {
+    GameConfig {
+        emp_duration,
+        emp_range,
+        ..Default::default()
+    }
+}
+
+/// Hunter steps to an adjacent plains tile away from the prey.
+fn hunter_step(client: &ZkHuntContractClient, session_id: u32) {
+    let game = client.get_game(&session_id);
+    let (hx, hy) = find_adjacent_plains_avoiding(
+        game.map_index, game.hunter_x, game.hunter_y, game.prey_x, game.prey_y,
+    );
+    client.hunter_move(&session_id, &hx, &hy);
+}
+
+#[test]
+fn test_frozen_turn_skipped_when_hunter_turn_ends() {
+    let (_env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join(&client, &hunter, &prey);
+
+    client.hunter_emp(&session_id);
+    hunter_step(&client, session_id);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::HunterTurn);
+    assert_eq!(game.turn_number, 2);
+    assert!(!game.prey_is_frozen);
+    assert_zk_hunt_error(&client.try_prey_pass_frozen(&session_id), Error::WrongPhase);
+}
+
+#[test]
+fn test_emp_duration_freezes_several_turns() {
+    let (_env, client, hunter, prey) = setup_test();
+    let session_id = client.create_game_with_config(&hunter, &emp_config(2, 0), &None);
+    client.join_game(&session_id, &prey);
+
+    client.hunter_emp(&session_id);
+    assert_eq!(client.get_game(&session_id).prey_frozen_turns, 2);
+
+    hunter_step(&client, session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.phase, game.turn_number), (GamePhase::HunterTurn, 2));
+    assert!(game.prey_is_frozen);
+
+    hunter_step(&client, session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.phase, game.turn_number), (GamePhase::HunterTurn, 3));
+    assert!(!game.prey_is_frozen);
+
+    hunter_step(&client, session_id);
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::PreyTurn);
+}
+
+#[test]
+fn test_emp_freeze_stays_with_frozen_team_member() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = client.create_game_with_config(
+        &hunter,
+        &GameConfig {
+            emp_duration: 2,
+            ..team_config(TeamMode::TwoVsTwo)
+        },
+        &None,
+    );
+    client.join_game(&session_id, &prey);
+    let prey2 = Address::generate(&env);
+    client.join_team(&session_id, &Address::generate(&env), &1);
+    client.join_team(&session_id, &prey2, &2);
+
+    // The frozen prey sits out one turn, then its partner plays unhindered
+    client.hunter_emp(&session_id);
+    hunter_step(&client, session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.phase, game.turn_number), (GamePhase::HunterTurn, 2));
+    assert_eq!(game.prey, prey2);
+    assert!(!game.prey_is_frozen);
+    let benched = game.teams.bench.iter().find(|member| member.player == prey).unwrap();
+    assert_eq!(benched.frozen_turns, 1);
+
+    assert!(play_one_turn(&client, session_id));
+
+    // Back in the active slot, the frozen prey owes its last turn
+    let game = client.get_game(&session_id);
+    assert_eq!(game.prey, prey);
+    assert!(game.prey_is_frozen);
+    hunter_step(&client, session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.phase, game.turn_number), (GamePhase::HunterTurn, 4));
+    assert_eq!(game.prey, prey2);
+}
+
+#[test]
+fn test_emp_range() {
+    let (_env, client, hunter, prey) = setup_test();
+
+    // Spawns are at least 3 tiles apart (Manhattan), so never within Chebyshev 1
+    let session_id = client.create_game_with_config(&hunter, &emp_config(0, 1), &None);
+    client.join_game(&session_id, &prey);
+    assert_zk_hunt_error(&client.try_hunter_emp(&session_id), Error::EmpOutOfRange);
+
+    let session_id = client.create_game_with_config(&hunter, &emp_config(0, 7), &None);
+    client.join_game(&session_id, &prey);
+    client.hunter_emp(&session_id);
+    assert!(client.get_game(&session_id).prey_is_frozen);
+}
+
+// ============================================================================
+// Session Key Tests
+// ============================================================================
+
+/// Address whose auth the last contract call required.
+fn last_signer(env: &Env) -> Address {
+    env.auths().first().expect("no auth recorded").0.clone()
+}
+
+/// Hunter steps to an adjacent plains tile, signed by `signer`.
+fn hunter_step_as(client: &ZkHuntContractClient, session_id: u32, signer: &Address) -> Result<(), Error> {
+    let game = client.get_game(&session_id);
+    let (hx, hy) = find_adjacent_plains_avoiding(
+        game.map_index, game.hunter_x, game.hunter_y, game.prey_x, game.prey_y,
+    );
+    match client.try_hunter_move_as(&session_id, signer, &hx, &hy) {
+        Ok(_) => Ok(()),
+        Err(Ok(error)) => Err(error),
+        Err(Err(error)) => panic!("unexpected invoke error: {:?}", error),
+    }
+}
+
+#[test]
+fn test_session_key_signs_game_actions() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join(&client, &hunter, &prey);
+    let session_key = Address::generate(&env);
+    client.register_session_key(&session_id, &hunter, &session_key, &200);
+    assert_eq!(client.get_session_key(&session_id, &hunter).unwrap().key, session_key);
+
+    hunter_step_as(&client, session_id, &session_key).unwrap();
+    assert_eq!(last_signer(&env), session_key);
+
+    // The prey has no key, so only its own wallet signs
+    let game = client.get_game(&session_id);
+    let (px, py) = find_adjacent_plains_avoiding(game.map_index, game.prey_x, game.prey_y, game.hunter_x, game.hunter_y);
+    let result = client.try_prey_move_public_as(&session_id, &session_key, &px, &py);
+    assert_zk_hunt_error(&result, Error::NotPlayer);
+    client.prey_move_public(&session_id, &px, &py);
+    assert_eq!(last_signer(&env), prey);
+
+    // The player's own wallet still signs while the key is active, through
+    // either entry point
+    hunter_step_as(&client, session_id, &hunter).unwrap();
+    assert_eq!(last_signer(&env), hunter);
+    let game = client.get_game(&session_id);
+    let (px, py) = find_adjacent_plains_avoiding(game.map_index, game.prey_x, game.prey_y, game.hunter_x, game.hunter_y);
+    client.prey_move_public(&session_id, &px, &py);
+    let game = client.get_game(&session_id);
+    let (hx, hy) = find_adjacent_plains_avoiding(game.map_index, game.hunter_x, game.hunter_y, game.prey_x, game.prey_y);
+    client.hunter_move(&session_id, &hx, &hy);
+    assert_eq!(last_signer(&env), hunter);
+    let game = client.get_game(&session_id);
+    let (px, py) = find_adjacent_plains_avoiding(game.map_index, game.prey_x, game.prey_y, game.hunter_x, game.hunter_y);
+    client.prey_move_public(&session_id, &px, &py);
+
+    // Once revoked, the key is refused
+    client.revoke_session_key(&session_id, &hunter);
+    assert_eq!(hunter_step_as(&client, session_id, &session_key), Err(Error::NotPlayer));
+}
+
+#[test]
+fn test_session_key_scoped_to_session_and_expiry() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join(&client, &hunter, &prey);
+    let other_session = create_and_join(&client, &hunter, &prey);
+    let session_key = Address::generate(&env);
+    client.register_session_key(&session_id, &hunter, &session_key, &150);
+
+    assert_eq!(hunter_step_as(&client, other_session, &session_key), Err(Error::NotPlayer));
+
+    env.ledger().with_mut(|ledger| ledger.sequence_number = 151);
+    assert_eq!(hunter_step_as(&client, session_id, &session_key), Err(Error::SessionKeyExpired));
+    hunter_step_as(&client, session_id, &hunter).unwrap();
+    assert_eq!(last_signer(&env), hunter);
+}
+
+#[test]
+fn test_session_key_registration_checks() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join(&client, &hunter, &prey);
+    let session_key = Address::generate(&env);
+
+    let result = client.try_register_session_key(&session_id, &Address::generate(&env), &session_key, &200);
+    assert_zk_hunt_error(&result, Error::NotPlayer);
+    let result = client.try_register_session_key(&session_id, &prey, &session_key, &100);
+    assert_zk_hunt_error(&result, Error::SessionKeyExpired);
+    let result = client.try_register_session_key(&999, &prey, &session_key, &200);
+    assert_zk_hunt_error(&result, Error::GameNotFound);
+}
+
+// ============================================================================
+// Channel Tests
+// ============================================================================
+
+fn channel_signer(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+/// Open a channel for both players, returns their signing keys.
+fn open_channel(env: &Env, client: &ZkHuntContractClient, session_id: u32) -> (SigningKey, SigningKey) {
+    let game = client.get_game(&session_id);
+    let (key1, key2) = (channel_signer(1), channel_signer(2));
+    client.open_channel(&session_id, &game.player1, &BytesN::from_array(env, &key1.verifying_key().to_bytes()));
+    client.open_channel(&session_id, &game.player2, &BytesN::from_array(env, &key2.verifying_key().to_bytes()));
+    (key1, key2)
+}
+
+fn sign_update(env: &Env, key: &SigningKey, update: &ChannelUpdate) -> BytesN<64> {
+    let message: std::vec::Vec<u8> = update.clone().to_xdr(env).iter().collect();
+    BytesN::from_array(env, &key.sign(&message).to_bytes())
+}
+
+#[test]
+fn test_channel_settle_match() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join(&client, &hunter, &prey);
+    let (key1, key2) = open_channel(&env, &client, session_id);
+
+    let mut game = client.get_game(&session_id);
+    game.player2_score = 2;
+    game.phase = GamePhase::Ended;
+    let update = ChannelUpdate { contract: client.address.clone(), session_id, seq: 7, game };
+
+    // Both signatures are required
+    let forged = sign_update(&env, &channel_signer(3), &update);
+    assert!(client.try_settle_match(&session_id, &update, &sign_update(&env, &key1, &update), &forged).is_err());
+
+    client.settle_match(&session_id, &update, &sign_update(&env, &key1, &update), &sign_update(&env, &key2, &update));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::Ended);
+    assert_eq!(game.winner, Some(prey));
+    assert_eq!(client.get_channel(&session_id).unwrap().seq, 7);
+}
+
+#[test]
+fn test_channel_update_bound_to_contract_and_match() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join(&client, &hunter, &prey);
+    let (key1, key2) = open_channel(&env, &client, session_id);
+    let post = |update: &ChannelUpdate| {
+        client.try_dispute(&session_id, &hunter, update, &sign_update(&env, &key1, update), &sign_update(&env, &key2, update))
+    };
+    let update = |seq: u32, game: &Game| ChannelUpdate {
+        contract: client.address.clone(),
+        session_id,
+        seq,
+        game: game.clone(),
+    };
+
+    let mut game = client.get_game(&session_id);
+    game.phase = GamePhase::PreyTurn;
+
+    // Signed for another deployment
+    let foreign = ChannelUpdate { contract: Address::generate(&env), ..update(1, &game) };
+    assert!(post(&foreign).is_err());
+
+    // Changed match settings
+    let mut changed = game.clone();
+    changed.config.hidden_spawn = true;
+    assert_zk_hunt_error(&post(&update(1, &changed)), Error::ChannelStateMismatch);
+    let mut changed = game.clone();
+    changed.total_rounds = 1;
+    assert_zk_hunt_error(&post(&update(1, &changed)), Error::ChannelStateMismatch);
+
+    // A later state cannot take back a point already posted
+    game.player2_score = 1;
+    post(&update(1, &game)).unwrap().unwrap();
+    game.player2_score = 0;
+    assert_zk_hunt_error(&post(&update(2, &game)), Error::ChannelStateMismatch);
+    assert_eq!(client.get_game(&session_id).player2_score, 1);
+}
+
+#[test]
+fn test_channel_dispute_timeout() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join(&client, &hunter, &prey);
+    let (key1, key2) = open_channel(&env, &client, session_id);
+
+    // The Hunter moved off-chain and the Prey went silent
+    let mut game = client.get_game(&session_id);
+    game.phase = GamePhase::PreyTurn;
+    let update = ChannelUpdate { contract: client.address.clone(), session_id, seq: 3, game };
+    let (sig1, sig2) = (sign_update(&env, &key1, &update), sign_update(&env, &key2, &update));
+
+    assert_zk_hunt_error(&client.try_dispute(&session_id, &prey, &update, &sig1, &sig2), Error::NotYourTurn);
+    client.dispute(&session_id, &hunter, &update, &sig1, &sig2);
+    assert_zk_hunt_error(&client.try_dispute(&session_id, &hunter, &update, &sig1, &sig2), Error::StaleUpdate);
+    assert_zk_hunt_error(&client.try_finalize_dispute(&session_id), Error::DeadlineNotReached);
+
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 121);
+    assert_eq!(client.finalize_dispute(&session_id), hunter);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.phase, game.winner), (GamePhase::Ended, Some(hunter)));
+}
+
+#[test]
+fn test_channel_dispute_answered_on_chain() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = create_and_join(&client, &hunter, &prey);
+    assert_zk_hunt_error(&client.try_finalize_dispute(&session_id), Error::ChannelNotOpen);
+    let (key1, key2) = open_channel(&env, &client, session_id);
+    assert_zk_hunt_error(
+        &client.try_open_channel(&session_id, &hunter, &BytesN::from_array(&env, &[9u8; 32])),
+        Error::ChannelAlreadyOpen,
+    );
+    assert_zk_hunt_error(&client.try_finalize_dispute(&session_id), Error::NoDispute);
+
+    let mut game = client.get_game(&session_id);
+    game.phase = GamePhase::PreyTurn;
+    let update = ChannelUpdate { contract: client.address.clone(), session_id, seq: 1, game: game.clone() };
+    client.dispute(&session_id, &hunter, &update, &sign_update(&env, &key1, &update), &sign_update(&env, &key2, &update));
+
+    // The Prey answers by playing its turn on-chain
+    let (px, py) = find_adjacent_plains_avoiding(game.map_index, game.prey_x, game.prey_y, game.hunter_x, game.hunter_y);
+    client.prey_move_public(&session_id, &px, &py);
+    assert_eq!(client.get_channel(&session_id).unwrap().seq, 2);
+
+    // A state signed before the on-chain move cannot roll it back
+    let stale = ChannelUpdate { seq: 2, ..update };
+    let result = client.try_dispute(&session_id, &hunter, &stale, &sign_update(&env, &key1, &stale), &sign_update(&env, &key2, &stale));
+    assert_zk_hunt_error(&result, Error::StaleUpdate);
+
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 121);
+    assert_zk_hunt_error(&client.try_finalize_dispute(&session_id), Error::DisputeAnswered);
+    assert_ne!(client.get_game(&session_id).phase, GamePhase::Ended);
+}
+
+// ============================================================================
+// Optimistic Move Tests
+// ============================================================================
+
+/// Verifier that rejects every proof, for challenges that should succeed.
+#[contract]
+pub struct RejectingVerifier;
+
+#[contractimpl]
+impl RejectingVerifier {
+    pub fn verify_proof(_env: Env, _vk_json: Bytes, _proof_blob: Bytes) -> BytesN<32> {
+        panic!("invalid proof")
+    }
+}
+
+fn optimistic_config() -> GameConfig {
+    GameConfig {
+        optimistic: true,
+        ..Default::default()
+    }
+}
+
+/// Submit a `jungle_move` proof blob for every unproven step of the pending batch.
+fn submit_batch(env: &Env, client: &ZkHuntContractClient, session_id: u32) {
+    let batch = client.get_move_batch(&session_id).unwrap();
+    for (i, step) in batch.steps.iter().enumerate() {
+        if batch.proven_steps & (1 << i) != 0 {
+            continue;
+        }
+        let proof = move_proof(env, &step.old_commitment, &step.new_commitment, batch.map_index, step.burned_tiles);
+        client.submit_step_proof(&session_id, &(i as u32), &proof);
+    }
+}
+
+#[test]
+fn test_optimistic_moves_proven_at_round_end() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, optimistic_config());
+
+    // No proof is needed during the round
+    let new_commitment = BytesN::from_array(&env, &[60u8; 32]);
+    client.prey_move_jungle(&session_id, &new_commitment, &Bytes::new(&env));
+    assert_eq!(client.get_game(&session_id).pending_steps.len(), 1);
+    let game = client.get_game(&session_id);
+    client.hunter_move(&session_id, &game.hunter_x, &game.hunter_y);
+    hide_whole_round(&env, &client, session_id);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::MovesPending);
+    assert!(game.pending_steps.is_empty());
+    assert_eq!(client.get_move_batch(&session_id).unwrap().steps.len(), 10);
+    assert_zk_hunt_error(&client.try_challenge_step(&session_id, &0), Error::ProofsNotSubmitted);
+    assert_zk_hunt_error(&client.try_settle_moves(&session_id), Error::DeadlineNotReached);
+
+    // Proofs arrive one step at a time, in any order; the window opens with the last
+    let batch = client.get_move_batch(&session_id).unwrap();
+    let step = batch.steps.get(9).unwrap();
+    let proof = move_proof(&env, &step.old_commitment, &step.new_commitment, batch.map_index, step.burned_tiles);
+    client.submit_step_proof(&session_id, &9, &proof);
+    assert_eq!(client.get_move_batch(&session_id).unwrap().proven_steps, 1 << 9);
+    assert_zk_hunt_error(&client.try_challenge_step(&session_id, &9), Error::ProofsNotSubmitted);
+
+    submit_batch(&env, &client, session_id);
+    let proof = dummy_proof(&env);
+    assert_zk_hunt_error(&client.try_submit_step_proof(&session_id, &0, &proof), Error::ProofsAlreadySubmitted);
+    assert_zk_hunt_error(&client.try_submit_step_proof(&session_id, &10, &proof), Error::StepNotFound);
+
+    // A valid step survives the challenge and the round stands
+    assert!(!client.challenge_step(&session_id, &3));
+    assert_zk_hunt_error(&client.try_challenge_step(&session_id, &10), Error::StepNotFound);
+    client.settle_moves(&session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.round, game.player1_score, game.player2_score), (2, 0, 1));
+    assert!(client.get_move_batch(&session_id).is_none());
+}
+
+#[test]
+fn test_optimistic_challenge_awards_round() {
+    let (env, client, hunter, prey) = setup_test();
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, optimistic_config());
+    hide_whole_round(&env, &client, session_id);
+    submit_batch(&env, &client, session_id);
+
+    env.register_at(&Address::from_str(&env, ULTRAHONK_CONTRACT_ADDRESS), RejectingVerifier, ());
+    assert!(client.challenge_step(&session_id, &0));
+    let game = client.get_game(&session_id);
+    assert_eq!((game.round, game.player1_score, game.player2_score), (2, 1, 0));
+    assert_ne!(game.phase, GamePhase::MovesPending);
+}
+
+#[test]
+fn test_optimistic_config_validation() {
+    let (_env, client, hunter, _prey) = setup_test();
+    for team_mode in [TeamMode::TwoVsOne, TeamMode::TwoVsTwo] {
+        let config = GameConfig {
+            team_mode,
+            ..optimistic_config()
+        };
+        let result = client.try_create_game_with_config(&hunter, &config, &None);
+        assert_zk_hunt_error(&result, Error::InvalidConfig);
+    }
+}
+
+#[test]
+fn test_optimistic_deadlines() {
+    let (env, client, hunter, prey) = setup_test();
+
+    // Missing proofs forfeit the round once the deadline passes
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, optimistic_config());
+    hide_whole_round(&env, &client, session_id);
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 721);
+    client.settle_moves(&session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.round, game.player1_score, game.player2_score), (2, 1, 0));
+
+    // Challenges close with the window
+    let session_id = start_hidden_prey_turn(&env, &client, &hunter, &prey, optimistic_config());
+    hide_whole_round(&env, &client, session_id);
+    submit_batch(&env, &client, session_id);
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 121);
+    assert_zk_hunt_error(&client.try_challenge_step(&session_id, &0), Error::ChallengeWindowClosed);
+    client.settle_moves(&session_id);
+    assert_eq!(client.get_game(&session_id).player2_score, 1);
+}
+
+// ============================================================================
+// Game Hub Notification Tests
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum HubError {
+    Rejected = 1,
+}
+
+/// V2 Game Hub that records the calls it accepts. It traps on every call
+/// while `down` and returns a contract error while `rejecting`.
+#[contract]
+pub struct FlakyHub;
+
+#[contractimpl]
+impl FlakyHub {
+    pub fn set_down(env: Env, down: bool) {
+        env.storage().instance().set(&symbol_short!("down"), &down);
+    }
+
+    pub fn set_rejecting(env: Env, rejecting: bool) {
+        env.storage().instance().set(&symbol_short!("reject"), &rejecting);
+    }
+
+    pub fn calls(env: Env) -> Vec<Symbol> {
+        env.storage().instance().get(&symbol_short!("calls")).unwrap_or(Vec::new(&env))
+    }
+
+    pub fn start_game(
+        env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) -> Result<(), HubError> {
+        Self::record(&env, symbol_short!("start"))
+    }
+
+    pub fn start_team_game(
+        env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _team1: Vec<Address>,
+        _team2: Vec<Address>,
+    ) -> Result<(), HubError> {
+        Self::record(&env, symbol_short!("teams"))
+    }
+
+    pub fn end_game(env: Env, _game_id: Address, _session_id: u32, _player1_won: bool) -> Result<(), HubError> {
+        Self::record(&env, symbol_short!("end"))
+    }
+
+    fn record(env: &Env, call: Symbol) -> Result<(), HubError> {
+        if env.storage().instance().get(&symbol_short!("down")).unwrap_or(false) {
+            panic!("hub down");
+        }
+        if env.storage().instance().get(&symbol_short!("reject")).unwrap_or(false) {
+            return Err(HubError::Rejected);
+        }
+        let mut calls = Self::calls(env.clone());
+        calls.push_back(call);
+        env.storage().instance().set(&symbol_short!("calls"), &calls);
+        Ok(())
+    }
+}
+
+fn setup_flaky_hub(env: &Env, client: &ZkHuntContractClient) -> FlakyHubClient<'static> {
+    let hub = FlakyHubClient::new(env, &env.register(FlakyHub, ()));
+    client.set_game_hub_v2(&hub.address);
+    hub
+}
+
+/// Game Hub with the standard interface, which has no `start_team_game` and
+/// no `game_id` in `end_game`.
+#[contract]
+pub struct StandardHub;
+
+#[contractimpl]
+impl StandardHub {
+    pub fn calls(env: Env) -> Vec<(Symbol, u32)> {
+        env.storage().instance().get(&symbol_short!("calls")).unwrap_or(Vec::new(&env))
+    }
+
+    pub fn start_game(
+        env: Env,
+        _game_id: Address,
+        session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        Self::record(&env, symbol_short!("start"), session_id);
+    }
+
+    pub fn end_game(env: Env, session_id: u32, _player1_won: bool) {
+        Self::record(&env, symbol_short!("end"), session_id);
+    }
+
+    fn record(env: &Env, call: Symbol, session_id: u32) {
+        let mut calls = Self::calls(env.clone());
+        calls.push_back((call, session_id));
+        env.storage().instance().set(&symbol_short!("calls"), &calls);
+    }
+}
+
+#[test]
+fn test_standard_hub_interface() {
+    let (env, client, hunter, prey) = setup_test();
+    let hub = StandardHubClient::new(&env, &env.register(StandardHub, ()));
+    client.set_game_hub(&hub.address);
+
+    let session_id = create_and_join(&client, &hunter, &prey);
+    let (key1, key2) = open_channel(&env, &client, session_id);
+    let mut game = client.get_game(&session_id);
+    game.phase = GamePhase::Ended;
+    let update = ChannelUpdate { contract: client.address.clone(), session_id, seq: 1, game };
+    client.settle_match(&session_id, &update, &sign_update(&env, &key1, &update), &sign_update(&env, &key2, &update));
+
+    // Team games are reported through `start_game` too
+    let team_session = client.create_game_with_config(&hunter, &team_config(TeamMode::TwoVsOne), &None);
+    client.join_game(&team_session, &prey);
+    client.join_team(&team_session, &Address::generate(&env), &1);
+
+    assert_eq!(
+        hub.calls(),
+        Vec::from_array(
+            &env,
+            [
+                (symbol_short!("start"), session_id),
+                (symbol_short!("end"), session_id),
+                (symbol_short!("start"), team_session),
+            ]
+        )
+    );
+    assert!(client.get_pending_hub_notifications(&session_id).is_empty());
+    assert!(client.get_pending_hub_notifications(&team_session).is_empty());
+}
+
+#[test]
+fn test_v2_hub_gets_team_rosters() {
+    let (env, client, hunter, prey) = setup_test();
+    let hub = setup_flaky_hub(&env, &client);
+    let session_id = client.create_game_with_config(&hunter, &team_config(TeamMode::TwoVsOne), &None);
+    client.join_game(&session_id, &prey);
+    client.join_team(&session_id, &Address::generate(&env), &1);
+    assert_eq!(hub.calls(), Vec::from_array(&env, [symbol_short!("teams")]));
+}
+
+#[test]
+fn test_hub_notified_directly_when_up() {
+    let (env, client, hunter, prey) = setup_test();
+    let hub = setup_flaky_hub(&env, &client);
+    let session_id = create_and_join(&client, &hunter, &prey);
+    assert_eq!(hub.calls(), Vec::from_array(&env, [symbol_short!("start")]));
+    assert!(client.get_pending_hub_notifications(&session_id).is_empty());
+}
+
+#[test]
+fn test_hub_failure_queues_notification() {
+    let (env, client, hunter, prey) = setup_test();
+    let hub = setup_flaky_hub(&env, &client);
+    hub.set_down(&true);
+
+    // The join goes through even though the hub rejects it
+    let session_id = create_and_join(&client, &hunter, &prey);
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::HunterTurn);
+    assert_eq!(
+        client.get_pending_hub_notifications(&session_id),
+        Vec::from_array(&env, [HubNotification::Start(session_id, hunter.clone(), prey.clone())])
+    );
+    assert_eq!(client.retry_hub_notifications(&session_id), 0);
+
+    hub.set_down(&false);
+    assert_eq!(client.retry_hub_notifications(&session_id), 1);
+    assert!(client.get_pending_hub_notifications(&session_id).is_empty());
+    assert_eq!(hub.calls().len(), 1);
+}
+
+#[test]
+fn test_hub_notifications_stay_in_order() {
+    let (env, client, hunter, prey) = setup_test();
+    let hub = setup_flaky_hub(&env, &client);
+    hub.set_down(&true);
+    let session_id = create_and_join(&client, &hunter, &prey);
+    hub.set_down(&false);
+
+    // The end waits behind the undelivered start
+    let (key1, key2) = open_channel(&env, &client, session_id);
+    let mut game = client.get_game(&session_id);
+    game.phase = GamePhase::Ended;
+    let update = ChannelUpdate { contract: client.address.clone(), session_id, seq: 1, game };
+    client.settle_match(&session_id, &update, &sign_update(&env, &key1, &update), &sign_update(&env, &key2, &update));
+    assert_eq!(client.get_pending_hub_notifications(&session_id).len(), 2);
+    assert!(hub.calls().is_empty());
+
+    // Other sessions do not wait behind this one
+    create_and_join(&client, &hunter, &prey);
+    assert_eq!(hub.calls(), Vec::from_array(&env, [symbol_short!("start")]));
+
+    assert_eq!(client.retry_hub_notifications(&session_id), 2);
+    assert_eq!(
+        hub.calls(),
+        Vec::from_array(&env, [symbol_short!("start"), symbol_short!("start"), symbol_short!("end")])
+    );
+}
+
+#[test]
+fn test_hub_rejection_dropped() {
+    let (env, client, hunter, prey) = setup_test();
+    let hub = setup_flaky_hub(&env, &client);
+
+    // A contract error is final, so nothing is queued
+    hub.set_rejecting(&true);
+    let session_id = create_and_join(&client, &hunter, &prey);
+    assert!(client.get_pending_hub_notifications(&session_id).is_empty());
+
+    // Queued while the hub was down, dropped once it answers with an error
+    hub.set_down(&true);
+    let session_id = create_and_join(&client, &hunter, &prey);
+    assert_eq!(client.get_pending_hub_notifications(&session_id).len(), 1);
+    hub.set_down(&false);
+    assert_eq!(client.retry_hub_notifications(&session_id), 0);
+    assert!(client.get_pending_hub_notifications(&session_id).is_empty());
+    assert!(hub.calls().is_empty());
+}
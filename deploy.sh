@@ -24,7 +24,13 @@ ENV_FILE="$PROJECT_DIR/.env"
 RPC_URL="${RPC_URL:-http://localhost:8000/rpc}"
 NETWORK_PASSPHRASE="${NETWORK_PASSPHRASE:-Standalone Network ; February 2017}"
 SOURCE="${SOURCE:-default}"
+# An external GAME_HUB_ADDRESS must have the standard hub interface:
+#   start_game(game_id, session_id, player1, player2, player1_points, player2_points)
+#   end_game(session_id, player1_won)
+# Set GAME_HUB_INTERFACE=v2 for a contracts/game-hub deployment instead, whose
+# end_game also takes game_id and which has start_team_game.
 GAME_HUB_ADDRESS="${GAME_HUB_ADDRESS:-}"
+GAME_HUB_INTERFACE="${GAME_HUB_INTERFACE:-v1}"
 MOCK_GAME_HUB_ID=""
 
 ULTRAHONK_WASM="$PROJECT_DIR/contracts/zk-hunt/ultrahonk_soroban_contract.wasm"
@@ -120,7 +126,7 @@ compile_circuits() {
     fi
 
     # Circuits that need compilation + VK generation (used for on-chain verification)
-    local proof_circuits=("jungle_move" "search_response")
+    local proof_circuits=("jungle_move" "search_response" "hidden_spawn" "jungle_dash" "sonar_response" "flare_response" "decoy_discard" "zone_check" "extraction" "item_claim" "hunter_adjacency" "scent_reveal" "round_audit" "jungle_enter" "jungle_exit")
 
     # Circuits that only need compilation (executed client-side, no proof)
     local exec_circuits=("commitment")
@@ -277,6 +283,28 @@ set_vks() {
         --move_vk "$move_vk_hex" \
         --search_vk "$search_vk_hex"
 
+    # Additional circuits, each in its own VK slot ("<circuit dir>:<Circuit variant>")
+    local extra_vks=("hidden_spawn:Spawn" "jungle_dash:Dash" "sonar_response:Sonar" "flare_response:Flare" "decoy_discard:Decoy" "zone_check:Zone" "extraction:Extraction" "item_claim:Item" "hunter_adjacency:Adjacency" "scent_reveal:Scent" "round_audit:Audit" "jungle_enter:Enter" "jungle_exit:Exit")
+    for entry in "${extra_vks[@]}"; do
+        local circuit="${entry%%:*}"
+        local variant="${entry##*:}"
+        local vk_file="$PUBLIC_CIRCUITS_DIR/${circuit}_vk.json"
+        if [ ! -f "$vk_file" ]; then
+            warn "VK for $circuit not found, skipping set_vk $variant"
+            continue
+        fi
+
+        stellar contract invoke \
+            --id "$ZK_HUNT_ID" \
+            --source "$SOURCE" \
+            --rpc-url "$RPC_URL" \
+            --network-passphrase "$NETWORK_PASSPHRASE" \
+            --config-dir "$CONFIG_DIR" \
+            -- set_vk \
+            --circuit "$variant" \
+            --vk "$(xxd -p "$vk_file" | tr -d '\n')"
+    done
+
     info "Verification keys set"
 }
 
@@ -291,7 +319,18 @@ set_game_hub() {
         return 0
     fi
 
-    info "Setting Game Hub address to $hub_addr..."
+    # The mock has the standard (v1) interface
+    local setter="set_game_hub"
+    case "$GAME_HUB_INTERFACE" in
+        v1) ;;
+        v2)
+            [ -n "$GAME_HUB_ADDRESS" ] || error "GAME_HUB_INTERFACE=v2 needs GAME_HUB_ADDRESS"
+            setter="set_game_hub_v2"
+            ;;
+        *) error "GAME_HUB_INTERFACE must be v1 or v2, got $GAME_HUB_INTERFACE" ;;
+    esac
+
+    info "Setting Game Hub address to $hub_addr ($GAME_HUB_INTERFACE interface)..."
 
     stellar contract invoke \
         --id "$ZK_HUNT_ID" \
@@ -299,7 +338,7 @@ set_game_hub() {
         --rpc-url "$RPC_URL" \
         --network-passphrase "$NETWORK_PASSPHRASE" \
         --config-dir "$CONFIG_DIR" \
-        -- set_game_hub \
+        -- "$setter" \
         --game_hub "$hub_addr"
 
     info "Game Hub address set"
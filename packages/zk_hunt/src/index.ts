@@ -53,7 +53,53 @@ export const Errors = {
   18: {message:"EmpTargetHidden"},
   19: {message:"EmpOutOfRange"},
   20: {message:"NoDashes"},
-  21: {message:"PreyFrozen"}
+  21: {message:"PreyFrozen"},
+  22: {message:"SeedRequired"},
+  23: {message:"SeedMismatch"},
+  24: {message:"SeedAlreadyRevealed"},
+  25: {message:"SeedNotRevealed"},
+  26: {message:"DeadlineNotReached"},
+  27: {message:"NotYourTurn"},
+  28: {message:"MapNotInPool"},
+  29: {message:"RuleDisabled"},
+  30: {message:"NotEnoughEnergy"},
+  31: {message:"NoSonar"},
+  32: {message:"InvalidRadius"},
+  33: {message:"NoFlare"},
+  34: {message:"InvalidMask"},
+  35: {message:"NoTraps"},
+  36: {message:"TrapNotFound"},
+  37: {message:"TrapMismatch"},
+  38: {message:"TooManyDecoys"},
+  39: {message:"DecoyNotFound"},
+  40: {message:"NoBurns"},
+  41: {message:"ZoneProofRequired"},
+  42: {message:"InvalidConfig"},
+  43: {message:"NotItemTile"},
+  44: {message:"TeamFull"},
+  45: {message:"AlreadySeated"},
+  46: {message:"HunterHidden"},
+  47: {message:"HunterNotHidden"},
+  48: {message:"ScentRevealRequired"},
+  49: {message:"NoScentDue"},
+  50: {message:"TranscriptNotFound"},
+  51: {message:"AlreadyAudited"},
+  52: {message:"SessionKeyExpired"},
+  53: {message:"ChannelNotOpen"},
+  54: {message:"ChannelAlreadyOpen"},
+  55: {message:"StaleUpdate"},
+  56: {message:"NoDispute"},
+  57: {message:"DisputeAnswered"},
+  58: {message:"ProofsAlreadySubmitted"},
+  59: {message:"ProofsNotSubmitted"},
+  60: {message:"StepNotFound"},
+  61: {message:"ChallengeWindowClosed"},
+  62: {message:"TrapProofRequired"},
+  63: {message:"NoTrapCheck"},
+  64: {message:"ItemsNotCommitted"},
+  65: {message:"ItemsAlreadyCommitted"},
+  66: {message:"ChannelStateMismatch"},
+  67: {message:"ItemAlreadyClaimed"}
 }
 
 export enum GamePhase {
@@ -62,15 +108,499 @@ export enum GamePhase {
   PreyTurn = 2,
   SearchPending = 3,
   Ended = 4,
+  SeedReveal = 5,
+  MapDraft = 6,
+  PreySpawn = 7,
+  SonarPending = 8,
+  FlarePending = 9,
+  BurnPending = 10,
+  /**
+   * Staked audited match: the prey must reveal the finished round's transcript.
+   */
+  AuditPending = 11,
+  /**
+   * Optimistic round: waiting for the prey's move proofs, then the hunter's
+   * challenge window.
+   */
+  MovesPending = 12,
+  /**
+   * The prey survived the turn limit with traps still hidden: the hunter
+   * may reveal them before the round is scored.
+   */
+  TrapReveal = 13,
+}
+
+
+/**
+ * Optional rules picked by the creator via `create_game_with_config`.
+ * `create_game` uses the default (all rules off).
+ */
+export interface GameConfig {
+  /**
+   * Keep the hidden prey's per-turn commitments and archive them when the
+   * round ends, for `reveal_round`. In commit-reveal matches the reveal is
+   * required before the next round.
+   */
+  audit: boolean;
+  /**
+   * Map and spawns come from a commit-reveal seed both players contribute to.
+   */
+  commit_reveal: boolean;
+  /**
+   * The Hunter may move and then search from the new tile in one turn
+   * (`hunter_move_and_search`).
+   */
+  compound_turn: boolean;
+  /**
+   * Prey turns an EMP freezes (1 while 0).
+   */
+  emp_duration: u32;
+  /**
+   * Chebyshev range of the EMP from the Hunter (global while 0).
+   */
+  emp_range: u32;
+  /**
+   * Per-role energy pool replacing the fixed ability counters; on when `energy.max > 0`.
+   */
+  energy: EnergyConfig;
+  /**
+   * The Hunter may hide in jungle too (`hunter_enter_jungle`), searching
+   * with adjacency proofs while hidden (`hunter_search_hidden`).
+   */
+  hidden_hunter: boolean;
+  /**
+   * Each round opens with the Prey committing to a hidden jungle spawn
+   * (`prey_spawn_hidden`) instead of a public plains spawn.
+   */
+  hidden_spawn: boolean;
+  /**
+   * Item tiles (jungle) placed at random each round; off while 0.
+   */
+  item_count: u32;
+  /**
+   * Items the prey must collect to win the round.
+   */
+  items_required: u32;
+  /**
+   * Players alternately ban maps from a drawn pool; the survivors are
+   * played in order, one per round.
+   */
+  map_draft: boolean;
+  /**
+   * How the prey wins a round.
+   */
+  objective: Objective;
+  /**
+   * Hidden prey moves (`prey_move_jungle`, `prey_dash_jungle`) post only the
+   * new commitment. Their proofs come step by step at round end
+   * (`submit_step_proof`) and the hunter can challenge any step.
+   */
+  optimistic: boolean;
+  /**
+   * The hidden Prey must reveal the tile it held this many moves ago
+   * before acting (`prey_reveal_scent`). Off while 0.
+   */
+  scent_delay: u32;
+  /**
+   * Team sizes; partners seat themselves with `join_team`.
+   */
+  team_mode: TeamMode;
+  /**
+   * From this turn on, one more outer ring of the board becomes hazardous
+   * each turn (down to the central 2x2). Off while 0.
+   */
+  zone_start_turn: u32;
+}
+
+/**
+ * Round objective for the prey.
+ */
+export enum Objective {
+  /**
+   * Survive `MAX_TURNS` turns.
+   */
+  Survive = 0,
+  /**
+   * Reach an extraction tile; surviving to the turn limit is a draw.
+   */
+  EscapeOrDraw = 1,
+  /**
+   * Reach an extraction tile; surviving to the turn limit is a hunter win.
+   */
+  EscapeOrLose = 2,
+}
+
+
+/**
+ * Team sizes for a match: player 1's team vs player 2's team.
+ */
+export enum TeamMode {
+  Solo = 0,
+  /**
+   * Player 1 and a partner against player 2.
+   */
+  TwoVsOne = 1,
+  TwoVsTwo = 2,
+}
+
+
+/**
+ * Energy economy: each role starts a round with `max` energy, regains
+ * `regen` per turn (capped at `max`), and pays the listed cost per action.
+ */
+export interface EnergyConfig {
+  burn_cost: u32;
+  dash_cost: u32;
+  decoy_cost: u32;
+  emp_cost: u32;
+  flare_cost: u32;
+  hide_cost: u32;
+  max: u32;
+  move_cost: u32;
+  power_search_cost: u32;
+  regen: u32;
+  search_cost: u32;
+  sonar_cost: u32;
+  trap_cost: u32;
+}
+
+/**
+ * Proof statements beyond the original move/search pair, each verified
+ * against its own VK slot (`DataKey::Vk`).
+ */
+export enum Circuit {
+  /**
+   * `hidden_spawn`: commitment is a jungle tile far enough from the hunter
+   * and the extraction tiles.
+   */
+  Spawn = 0,
+  /**
+   * `jungle_dash`: hidden move of up to 2 tiles through jungle.
+   */
+  Dash = 1,
+  /**
+   * `sonar_response`: commitment is inside/outside a radius around the hunter.
+   */
+  Sonar = 2,
+  /**
+   * `flare_response`: commitment is not on any tile of a 64-bit mask.
+   */
+  Flare = 3,
+  /**
+   * `decoy_discard`: a decoy sits on one of the searched tiles.
+   */
+  Decoy = 4,
+  /**
+   * `zone_check`: commitment lies inside the safe zone.
+   */
+  Zone = 5,
+  /**
+   * `extraction`: commitment is on one of the map's extraction tiles.
+   */
+  Extraction = 6,
+  /**
+   * `item_claim`: commitment is at one of the prey's committed item tiles.
+   */
+  Item = 7,
+  /**
+   * `hunter_adjacency`: searched tiles are around the hidden hunter's commitment.
+   */
+  Adjacency = 8,
+  /**
+   * `scent_reveal`: an old commitment opens to a tile a few moves from the current one.
+   */
+  Scent = 9,
+  /**
+   * `round_audit`: revealed positions and nonces open a round's commitments.
+   */
+  Audit = 10,
+  /**
+   * `jungle_enter`: a step from a public tile onto adjacent jungle.
+   */
+  Enter = 11,
+  /**
+   * `jungle_exit`: a public exit tile is within one step of a commitment.
+   */
+  Exit = 12,
+}
+
+
+/**
+ * A hunter trap, hidden as `sha256(x || y || salt)` (x, y as big-endian u32)
+ * until the hunter reveals it.
+ */
+export interface Trap {
+  commitment: Buffer;
+  placed_turn: u32;
+  revealed: boolean;
+}
+
+/**
+ * A ghost commitment registered by the hidden prey. Decoys use a tagged hash
+ * (`Poseidon2(x, y, nonce, 1)`), so they can never equal a position commitment.
+ */
+export interface Decoy {
+  commitment: Buffer;
+  expires_turn: u32;
+}
+
+/**
+ * A team member waiting for their turn. Team members alternate turns: at the
+ * end of every turn the benched member swaps into the active hunter / prey slot.
+ * Hunter abilities only reach the active prey: a benched prey hidden in jungle
+ * cannot be searched, flared, pinged or burned until it rotates back in, while
+ * a visible benched prey is still caught by a hunter stepping onto it.
+ */
+export interface BenchMember {
+  commitment: Buffer;
+  /**
+   * Frozen turns a benched prey still owes; they resume when it returns.
+   */
+  frozen_turns: u32;
+  is_hidden: boolean;
+  is_hunter: boolean;
+  player: string;
+  x: u32;
+  y: u32;
+}
+
+/**
+ * Rosters of a team game. The primary `Game` slots hold the active members;
+ * abilities and their pools are shared by the whole team.
+ */
+export interface Teams {
+  bench: Array<BenchMember>;
+  /**
+   * Partners of player 1 (not including player 1).
+   */
+  team1: Array<string>;
+  /**
+   * Partners of player 2 (not including player 2).
+   */
+  team2: Array<string>;
+}
+
+/**
+ * A tile the prey ended a turn on: seen while visible, or revealed later by scent.
+ */
+export interface TrailStep {
+  turn: u32;
+  x: u32;
+  y: u32;
+}
+
+/**
+ * The hidden prey's commitment at the end of a turn.
+ */
+export interface CommitmentRecord {
+  commitment: Buffer;
+  turn: u32;
+}
+
+/**
+ * Outcome of a round transcript audit.
+ */
+export enum AuditResult {
+  Pending = 0,
+  Passed = 1,
+  /**
+   * Revealed a non-jungle tile, or missed the deadline in a staked match.
+   */
+  Failed = 2,
+}
+
+
+/**
+ * The hidden prey's commitments of a finished round, stored per round
+ * under `DataKey::Transcript`.
+ */
+export interface RoundTranscript {
+  commitments: Array<CommitmentRecord>;
+  /**
+   * Turns the prey dashed on (a revealed step may cover 2 tiles).
+   */
+  dash_turns: Array<u32>;
+  /**
+   * Reveal deadline when the next round waits for it (staked matches).
+   */
+  deadline: u32;
+  hunter: string;
+  map_index: u32;
+  prey: string;
+  result: AuditResult;
+  /**
+   * Who scored the round; None for a draw.
+   */
+  round_winner: Option<string>;
+  /**
+   * The prey's public positions this round, which the revealed path must join.
+   */
+  trail: Array<TrailStep>;
+}
+
+/**
+ * A hidden prey move posted without a proof (`optimistic` rule).
+ */
+export interface MoveStep {
+  /**
+   * Burned tiles when the move was made, a public input of its proof.
+   */
+  burned_tiles: u64;
+  /**
+   * Proven by `jungle_dash` rather than `jungle_move`.
+   */
+  dash: boolean;
+  new_commitment: Buffer;
+  old_commitment: Buffer;
+}
+
+/**
+ * A finished optimistic round waiting on its move proofs and challenge window.
+ */
+export interface MoveBatch {
+  /**
+   * Proof submission deadline, then the end of the challenge window.
+   */
+  deadline: u32;
+  hunter: string;
+  map_index: u32;
+  prey: string;
+  /**
+   * Bit per step whose proof has been submitted (`DataKey::StepProof`).
+   */
+  proven_steps: u32;
+  round: u32;
+  round_winner: Option<string>;
+  steps: Array<MoveStep>;
+}
+
+/**
+ * A key a player registered to sign its game actions in one session, so the
+ * main wallet does not have to sign every move.
+ */
+export interface SessionKey {
+  /**
+   * Last ledger the key is accepted on.
+   */
+  expires_ledger: u32;
+  key: string;
+}
+
+/**
+ * A game state both players sign off-chain in channel mode. The signed
+ * message is the XDR encoding of the whole update.
+ */
+export interface ChannelUpdate {
+  /**
+   * Contract the update is for, so signatures cannot be replayed on
+   * another deployment.
+   */
+  contract: string;
+  game: Game;
+  /**
+   * Increases with every exchanged state; only newer states are accepted on-chain.
+   */
+  seq: u32;
+  session_id: u32;
+}
+
+/**
+ * Channel-mode bookkeeping for a session: the players' ed25519 keys and the
+ * latest state posted on-chain.
+ */
+export interface Channel {
+  /**
+   * Player who posted the pending dispute; the opponent has to answer.
+   */
+  challenger: Option<string>;
+  dispute_deadline: u32;
+  disputed_phase: GamePhase;
+  /**
+   * Round, turn and phase of the disputed state; any move changes them.
+   */
+  disputed_round: u32;
+  disputed_turn: u32;
+  player1_key: Option<Buffer>;
+  player2_key: Option<Buffer>;
+  /**
+   * Sequence number of the latest state on-chain: the last posted update,
+   * plus one for every on-chain game action since.
+   */
+  seq: u32;
 }
 
+/**
+ * Seed commitments for a commit-reveal game.
+ * Commitments are `sha256(seed)`; the match seed is `sha256(player1_seed || player2_seed)`.
+ */
+export interface SeedState {
+  match_seed: Option<Buffer>;
+  player1_hash: Buffer;
+  player1_seed: Option<Buffer>;
+  player2_hash: Buffer;
+  player2_seed: Option<Buffer>;
+  reveal_deadline: u32;
+}
 
 export interface Game {
+  /**
+   * Jungle tiles burned to plains this round (bit `y * 8 + x`).
+   */
+  burned_tiles: u64;
+  /**
+   * Every commitment the hidden prey ended a turn on this round, after its
+   * hidden spawn as turn 0 (`audit` rule).
+   */
+  commitment_history: Array<CommitmentRecord>;
+  config: GameConfig;
+  /**
+   * Turns the hidden prey dashed on this round (`audit` rule).
+   */
+  dash_turns: Array<u32>;
+  /**
+   * Active decoys (at most `MAX_DECOYS`), dropped once `turn_number` reaches `expires_turn`.
+   */
+  decoys: Array<Decoy>;
+  draft_deadline: u32;
+  draft_pool: Array<u32>;
   emp_uses_remaining: u32;
+  /**
+   * Tiles lit by the pending flare (bit `y * 8 + x`).
+   */
+  flare_mask: u64;
+  flare_uses_remaining: u32;
   hunter: string;
+  hunter_commitment: Buffer;
+  hunter_energy: u32;
+  /**
+   * While hidden, `hunter_x`/`hunter_y` are the Hunter's last known position.
+   */
+  hunter_is_hidden: boolean;
   hunter_x: u32;
   hunter_y: u32;
+  /**
+   * Candidate item tiles this round (bit `y * 8 + x`); `item_count` of them
+   * hold items, chosen secretly by the prey (`prey_commit_items`).
+   */
+  item_candidates: u64;
+  /**
+   * Nullifiers of the items claimed this round.
+   */
+  item_nullifiers: Array<Buffer>;
+  items_collected: u32;
+  /**
+   * Prey's commitment to which candidates hold items, once made this round.
+   */
+  items_commitment: Option<Buffer>;
+  /**
+   * Answer to the last sonar ping this round: inside the radius or not.
+   */
+  last_sonar_inside: Option<boolean>;
   map_index: u32;
+  /**
+   * Hidden prey moves not proven yet this round (`optimistic` rule).
+   */
+  pending_steps: Array<MoveStep>;
   phase: GamePhase;
   player1: string;
   player1_score: u32;
@@ -80,19 +610,73 @@ export interface Game {
   prey: string;
   prey_commitment: Buffer;
   prey_dash_remaining: u32;
+  prey_energy: u32;
+  /**
+   * Prey turns still to be skipped while frozen.
+   */
+  prey_frozen_turns: u32;
   prey_is_frozen: boolean;
   prey_is_hidden: boolean;
+  /**
+   * Public positions of the prey this round (a public spawn as turn 0),
+   * checked when a trap is revealed or a transcript is audited.
+   */
+  prey_trail: Array<TrailStep>;
   prey_x: u32;
   prey_y: u32;
+  /**
+   * Ledger by which the prey must answer a pending flare, sonar ping or burn.
+   */
+  response_deadline: u32;
   round: u32;
+  /**
+   * Commitments of the hidden prey whose scent is not revealed yet, oldest first.
+   */
+  scent_history: Array<CommitmentRecord>;
+  /**
+   * Hidden positions revealed by scent this round.
+   */
+  scent_trail: Array<TrailStep>;
   searched_tiles_x: Array<u32>;
   searched_tiles_y: Array<u32>;
+  /**
+   * Radius of the pending (or last answered) sonar ping.
+   */
+  sonar_radius: u32;
+  sonar_uses_remaining: u32;
+  teams: Teams;
   total_rounds: u32;
+  /**
+   * Revealed trap tiles the hidden prey has yet to prove it is not on (bit `y * 8 + x`).
+   */
+  trap_check_mask: u64;
+  /**
+   * Traps placed this round (at most `MAX_TRAPS`).
+   */
+  traps: Array<Trap>;
   turn_number: u32;
   winner: Option<string>;
+  /**
+   * Commitment the prey proved inside the zone on `zone_proven_turn`.
+   */
+  zone_commitment: Buffer;
+  /**
+   * Last turn the hidden prey proved it is inside the safe zone.
+   */
+  zone_proven_turn: u32;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "Admin", values: void} | {tag: "MoveVk", values: void} | {tag: "SearchVk", values: void} | {tag: "NextSessionId", values: void} | {tag: "GameHubAddress", values: void};
+export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "Admin", values: void} | {tag: "MoveVk", values: void} | {tag: "SearchVk", values: void} | {tag: "NextSessionId", values: void} | {tag: "GameHubAddress", values: void} | {tag: "GameHubInterface", values: void} | {tag: "Seeds", values: readonly [u32]} | {tag: "Vk", values: readonly [Circuit]} | {tag: "Transcript", values: readonly [u32, u32]} | {tag: "SessionKey", values: readonly [u32, string]} | {tag: "Channel", values: readonly [u32]} | {tag: "MoveBatch", values: readonly [u32]} | {tag: "StepProof", values: readonly [u32, u32]} | {tag: "PendingHub", values: readonly [u32]};
+
+/**
+ * Interface of the configured Game Hub.
+ */
+export type HubInterface = {tag: "V1", values: void} | {tag: "V2", values: void};
+
+/**
+ * A Game Hub call waiting to be retried.
+ */
+export type HubNotification = {tag: "Start", values: readonly [u32, string, string]} | {tag: "StartTeams", values: readonly [u32, Array<string>, Array<string>]} | {tag: "End", values: readonly [u32, boolean]};
 
 export interface Client {
   /**
@@ -101,67 +685,513 @@ export interface Client {
    */
   set_vks: ({move_vk, search_vk}: {move_vk: Buffer, search_vk: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a set_vk transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the verification key of an additional circuit (called post-deploy by admin).
+   */
+  set_vk: ({circuit, vk}: {circuit: Circuit, vk: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
   /**
    * Construct and simulate a set_game_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Set the Game Hub contract address (called post-deploy by admin).
+   * The hub must have the standard interface (`HubInterface::V1`).
    * If not set, GameHub notifications are silently skipped (local dev).
    */
   set_game_hub: ({game_hub}: {game_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a set_game_hub_v2 transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a Game Hub with the `contracts/game-hub` interface
+   * (`HubInterface::V2`), called post-deploy by admin.
+   */
+  set_game_hub_v2: ({game_hub}: {game_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
   /**
    * Construct and simulate a create_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Create a new game. Caller becomes the Hunter.
    */
   create_game: ({hunter}: {hunter: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
+  /**
+   * Construct and simulate a create_game_with_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create a new game with optional rules. Caller becomes the Hunter.
+   * 
+   * With `config.commit_reveal`, `seed_hash` must be `sha256(seed)` for a
+   * secret 32-byte seed the hunter reveals later via `reveal_seed`.
+   */
+  create_game_with_config: ({hunter, config, seed_hash}: {hunter: string, config: GameConfig, seed_hash: Option<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
   /**
    * Construct and simulate a join_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Prey joins an existing game.
    */
   join_game: ({session_id, prey}: {session_id: u32, prey: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a join_game_with_seed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Prey joins a commit-reveal game, committing `sha256(seed)`.
+   */
+  join_game_with_seed: ({session_id, prey, seed_hash}: {session_id: u32, prey: string, seed_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a join_team transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * A team partner takes a seat before the match starts: team 1 plays with
+   * player 1, team 2 with player 2. The match starts once every seat is filled.
+   */
+  join_team: ({session_id, player, team}: {session_id: u32, player: string, team: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_seed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal a committed seed. Once both seeds are in, the match seed picks
+   * the map and spawns and the first round starts.
+   */
+  reveal_seed: ({session_id, player, seed}: {session_id: u32, player: string, seed: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_reveal_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claim the match when the opponent failed to reveal their seed in time.
+   * Only a player who revealed can claim; they win the match outright.
+   * If neither player revealed, either one can call it to cancel the match,
+   * which ends as a 0-0 draw.
+   */
+  claim_reveal_timeout: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a ban_map transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Ban a map from the draft pool. Players alternate, player2 first.
+   * Once only `total_rounds` maps remain, they are played in order.
+   */
+  ban_map: ({session_id, player, map_index}: {session_id: u32, player: string, map_index: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a force_draft_ban transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Ban a random map for a player who let their draft deadline pass.
+   * Callable by anyone so a stalling player cannot hold the match hostage.
+   */
+  force_draft_ban: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a hunter_move transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Hunter moves to an adjacent tile (public movement).
    */
   hunter_move: ({session_id, x, y}: {session_id: u32, x: u32, y: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a hunter_move_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_move` signed by `signer`: the player or their session key.
+   */
+  hunter_move_as: ({session_id, signer, x, y}: {session_id: u32, signer: string, x: u32, y: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a hunter_search transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Hunter searches one adjacent jungle tile for the Prey.
    */
   hunter_search: ({session_id, x, y}: {session_id: u32, x: u32, y: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a hunter_search_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_search` signed by `signer`: the player or their session key.
+   */
+  hunter_search_as: ({session_id, signer, x, y}: {session_id: u32, signer: string, x: u32, y: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_move_and_search transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hunter moves, then searches one jungle tile adjacent to the new position,
+   * as a single turn (requires the `compound_turn` rule).
+   */
+  hunter_move_and_search: ({session_id, x, y, sx, sy}: {session_id: u32, x: u32, y: u32, sx: u32, sy: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_move_and_search_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_move_and_search` signed by `signer`: the player or their session key.
+   */
+  hunter_move_and_search_as: ({session_id, signer, x, y, sx, sy}: {session_id: u32, signer: string, x: u32, y: u32, sx: u32, sy: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a hunter_power_search transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Hunter uses power search to search ALL adjacent jungle tiles (limited uses).
    */
   hunter_power_search: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a hunter_power_search_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_power_search` signed by `signer`: the player or their session key.
+   */
+  hunter_power_search_as: ({session_id, signer}: {session_id: u32, signer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_enter_jungle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hunter enters jungle from a visible position (becomes hidden), with a
+   * `jungle_enter` proof that the new commitment is a jungle tile next to
+   * the hunter's public position. Requires the `hidden_hunter` rule.
+   * 
+   * Proof blob layout (after 4-byte num_fields header):
+   * bytes 4..36:    x (u8 in last byte)
+   * bytes 36..68:   y (u8 in last byte)
+   * bytes 68..100:  new_commitment
+   * bytes 100..132: map_id (u8 in last byte)
+   * bytes 132..164: burned tiles (u64 in last 8 bytes)
+   */
+  hunter_enter_jungle: ({session_id, new_commitment, proof}: {session_id: u32, new_commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_enter_jungle_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_enter_jungle` signed by `signer`: the player or their session key.
+   */
+  hunter_enter_jungle_as: ({session_id, signer, new_commitment, proof}: {session_id: u32, signer: string, new_commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_move_jungle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hidden Hunter moves within jungle (hidden to hidden), proven by `jungle_move`.
+   */
+  hunter_move_jungle: ({session_id, new_commitment, proof}: {session_id: u32, new_commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_move_jungle_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_move_jungle` signed by `signer`: the player or their session key.
+   */
+  hunter_move_jungle_as: ({session_id, signer, new_commitment, proof}: {session_id: u32, signer: string, new_commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_exit_jungle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hidden Hunter exits jungle onto plains (reveals position, becomes visible),
+   * with a `jungle_exit` proof that (x, y) is within one step of its commitment.
+   * 
+   * Proof blob layout (after 4-byte num_fields header):
+   * bytes 4..36:   commitment
+   * bytes 36..68:  x (u8 in last byte)
+   * bytes 68..100: y (u8 in last byte)
+   */
+  hunter_exit_jungle: ({session_id, x, y, proof}: {session_id: u32, x: u32, y: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_exit_jungle_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_exit_jungle` signed by `signer`: the player or their session key.
+   */
+  hunter_exit_jungle_as: ({session_id, signer, x, y, proof}: {session_id: u32, signer: string, x: u32, y: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_search_hidden transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hidden Hunter searches up to 9 jungle tiles, proving with a
+   * `hunter_adjacency` proof that they surround its committed position.
+   * One tile costs a search, more cost a power search.
+   * 
+   * Proof blob layout matches `respond_search`, with the hunter's commitment.
+   */
+  hunter_search_hidden: ({session_id, tiles_x, tiles_y, proof}: {session_id: u32, tiles_x: Array<u32>, tiles_y: Array<u32>, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_search_hidden_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_search_hidden` signed by `signer`: the player or their session key.
+   */
+  hunter_search_hidden_as: ({session_id, signer, tiles_x, tiles_y, proof}: {session_id: u32, signer: string, tiles_x: Array<u32>, tiles_y: Array<u32>, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_sonar transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hunter pings a Manhattan radius around their position; the Prey must
+   * answer with a proof of whether they are inside it (limited uses).
+   */
+  hunter_sonar: ({session_id, radius}: {session_id: u32, radius: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_sonar_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_sonar` signed by `signer`: the player or their session key.
+   */
+  hunter_sonar_as: ({session_id, signer, radius}: {session_id: u32, signer: string, radius: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_flare transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hunter fires a flare over an arbitrary set of tiles given as a bitmask
+   * (bit `y * 8 + x`, at most `MAX_FLARE_TILES` tiles, limited uses).
+   */
+  hunter_flare: ({session_id, mask}: {session_id: u32, mask: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_flare_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_flare` signed by `signer`: the player or their session key.
+   */
+  hunter_flare_as: ({session_id, signer, mask}: {session_id: u32, signer: string, mask: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_place_trap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hunter hides a trap as `sha256(x || y || salt)` (up to `MAX_TRAPS` per round).
+   * Does not use the hunter's turn.
+   */
+  hunter_place_trap: ({session_id, commitment}: {session_id: u32, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_place_trap_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_place_trap` signed by `signer`: the player or their session key.
+   */
+  hunter_place_trap_as: ({session_id, signer, commitment}: {session_id: u32, signer: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_reveal_trap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hunter reveals a trap. If the prey's public trail crossed the trap tile
+   * since it was placed, the hunter wins the round. Returns whether it sprang.
+   * A hidden prey must then prove it is not on the tile (`prey_prove_traps`)
+   * before acting again. Traps can also be revealed once the prey has
+   * survived the turn limit (`TrapReveal`).
+   */
+  hunter_reveal_trap: ({session_id, index, x, y, salt}: {session_id: u32, index: u32, x: u32, y: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a hunter_reveal_trap_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_reveal_trap` signed by `signer`: the player or their session key.
+   */
+  hunter_reveal_trap_as: ({session_id, signer, index, x, y, salt}: {session_id: u32, signer: string, index: u32, x: u32, y: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a prey_prove_traps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hidden prey proves with a `flare_response` proof that it is on none of
+   * the revealed trap tiles in `trap_check_mask`. Required before acting
+   * after a trap is revealed; does not use the turn. An empty proof
+   * concedes the round (caught in the trap).
+   * 
+   * Same proof blob layout as `respond_flare`, with the trap check mask.
+   */
+  prey_prove_traps: ({session_id, proof}: {session_id: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_prove_traps_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_prove_traps` signed by `signer`: the player or their session key.
+   */
+  prey_prove_traps_as: ({session_id, signer, proof}: {session_id: u32, signer: string, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a close_trap_reveal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Score a round waiting in `TrapReveal`. The hunter can do it at any time
+   * to skip revealing its remaining traps; after the deadline anyone can.
+   * If the prey left a trap check unanswered past the deadline, the round
+   * goes to the hunter.
+   */
+  close_trap_reveal: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a close_trap_reveal_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `close_trap_reveal` signed by `signer`: the player or their session key.
+   */
+  close_trap_reveal_as: ({session_id, signer}: {session_id: u32, signer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_place_decoy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hidden prey registers a decoy commitment that lasts `DECOY_LIFETIME_TURNS`
+   * turns (up to `MAX_DECOYS` active). Does not use the prey's turn.
+   */
+  prey_place_decoy: ({session_id, commitment}: {session_id: u32, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_place_decoy_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_place_decoy` signed by `signer`: the player or their session key.
+   */
+  prey_place_decoy_as: ({session_id, signer, commitment}: {session_id: u32, signer: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hunter burns an adjacent jungle tile to plains for the rest of the round.
+   * If the prey is hidden it must prove it is not on the tile (`respond_burn`).
+   */
+  hunter_burn: ({session_id, x, y}: {session_id: u32, x: u32, y: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a hunter_burn_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_burn` signed by `signer`: the player or their session key.
+   */
+  hunter_burn_as: ({session_id, signer, x, y}: {session_id: u32, signer: string, x: u32, y: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a respond_burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hidden prey answers a burn with a `search_response` proof that it is not
+   * on the burned tile. An empty proof reveals the prey on that tile.
+   * 
+   * Same proof blob layout as `respond_search`.
+   */
+  respond_burn: ({session_id, proof}: {session_id: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a respond_burn_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `respond_burn` signed by `signer`: the player or their session key.
+   */
+  respond_burn_as: ({session_id, signer, proof}: {session_id: u32, signer: string, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_prove_zone transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Prey proves the commitment it is about to move to (`commitment`) is
+   * inside this turn's safe zone. Once the zone starts shrinking, every
+   * hidden move (and entering jungle) must end on a commitment proven this
+   * turn, so the prey never ends a turn hidden outside the zone. An empty
+   * proof concedes the round.
+   * 
+   * Proof blob layout (after 4-byte num_fields header):
+   * bytes 4..36:  commitment
+   * bytes 36..68: inset (u8 in last byte)
+   */
+  prey_prove_zone: ({session_id, commitment, proof}: {session_id: u32, commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_prove_zone_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_prove_zone` signed by `signer`: the player or their session key.
+   */
+  prey_prove_zone_as: ({session_id, signer, commitment, proof}: {session_id: u32, signer: string, commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_reveal_scent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hidden prey reveals the tile it held `scent_delay` moves ago, with a
+   * `scent_reveal` proof chaining that commitment to its current one.
+   * Required before acting once a scent is due; does not use the turn.
+   * 
+   * Proof blob layout (after 4-byte num_fields header):
+   * bytes 4..36:    old_commitment
+   * bytes 36..68:   current_commitment
+   * bytes 68..100:  x (u8 in last byte)
+   * bytes 100..132: y (u8 in last byte)
+   * bytes 132..164: steps (u8 in last byte)
+   */
+  prey_reveal_scent: ({session_id, x, y, proof}: {session_id: u32, x: u32, y: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_reveal_scent_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_reveal_scent` signed by `signer`: the player or their session key.
+   */
+  prey_reveal_scent_as: ({session_id, signer, x, y, proof}: {session_id: u32, signer: string, x: u32, y: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_extract_hidden transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hidden prey proves it is on one of the map's extraction tiles and wins
+   * the round (escape mode). Visible prey extract by ending a turn on one.
+   * 
+   * Proof blob layout (after 4-byte num_fields header):
+   * bytes 4..36:  commitment
+   * bytes 36..68: extraction mask (u64 in last 8 bytes)
+   */
+  prey_extract_hidden: ({session_id, proof}: {session_id: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_extract_hidden_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_extract_hidden` signed by `signer`: the player or their session key.
+   */
+  prey_extract_hidden_as: ({session_id, signer, proof}: {session_id: u32, signer: string, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_commit_items transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Prey commits to which `item_count` of the round's candidate tiles hold
+   * items: `commitment = hash(mask, salt)` for a secret tile mask and salt.
+   * Required before the prey's first action of the round; does not use
+   * its turn.
+   */
+  prey_commit_items: ({session_id, commitment}: {session_id: u32, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_commit_items_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_commit_items` signed by `signer`: the player or their session key.
+   */
+  prey_commit_items_as: ({session_id, signer, commitment}: {session_id: u32, signer: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_claim_item transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hidden prey claims an item by proving it stands on one of its
+   * committed item tiles, without revealing which. `nullifier` is unique
+   * per item tile, so each item is claimed once (`ItemAlreadyClaimed`).
+   * Publishes `ItemCollected` with the nullifier of the item taken;
+   * collecting `items_required` wins the round. Does not use the prey's turn.
+   * 
+   * Proof blob layout (after 4-byte num_fields header):
+   * bytes 4..36:    commitment
+   * bytes 36..68:   candidates (u64 in last 8 bytes)
+   * bytes 68..100:  item_count (u8 in last byte)
+   * bytes 100..132: items_commitment
+   * bytes 132..164: nullifier
+   */
+  prey_claim_item: ({session_id, nullifier, proof}: {session_id: u32, nullifier: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_claim_item_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_claim_item` signed by `signer`: the player or their session key.
+   */
+  prey_claim_item_as: ({session_id, signer, nullifier, proof}: {session_id: u32, signer: string, nullifier: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a prey_move_public transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Prey moves publicly on plains (visible to visible).
    */
   prey_move_public: ({session_id, x, y}: {session_id: u32, x: u32, y: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a prey_move_public_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_move_public` signed by `signer`: the player or their session key.
+   */
+  prey_move_public_as: ({session_id, signer, x, y}: {session_id: u32, signer: string, x: u32, y: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a prey_enter_jungle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Prey enters jungle from a visible position (becomes hidden).
    */
   prey_enter_jungle: ({session_id, new_commitment, proof}: {session_id: u32, new_commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a prey_enter_jungle_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_enter_jungle` signed by `signer`: the player or their session key.
+   */
+  prey_enter_jungle_as: ({session_id, signer, new_commitment, proof}: {session_id: u32, signer: string, new_commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_spawn_hidden transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Prey commits to a hidden spawn at the start of a hidden-spawn round.
+   * 
+   * Proof blob layout (after 4-byte num_fields header):
+   * bytes 4..36:    commitment (32 bytes, Field)
+   * bytes 36..68:   map_id (u8 in last byte)
+   * bytes 68..100:  hunter_x (u8 in last byte)
+   * bytes 100..132: hunter_y (u8 in last byte)
+   * bytes 132..164: extraction (u64 in last 8 bytes)
+   */
+  prey_spawn_hidden: ({session_id, commitment, proof}: {session_id: u32, commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_spawn_hidden_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_spawn_hidden` signed by `signer`: the player or their session key.
+   */
+  prey_spawn_hidden_as: ({session_id, signer, commitment, proof}: {session_id: u32, signer: string, commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a prey_move_jungle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Prey moves within jungle (hidden to hidden).
+   * In an `optimistic` match `proof` is not checked (pass it empty); the
+   * move is proven with the round's batch instead.
    */
   prey_move_jungle: ({session_id, new_commitment, proof}: {session_id: u32, new_commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a prey_move_jungle_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_move_jungle` signed by `signer`: the player or their session key.
+   */
+  prey_move_jungle_as: ({session_id, signer, new_commitment, proof}: {session_id: u32, signer: string, new_commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_dash_jungle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Prey dashes up to 2 tiles through jungle while hidden (consumes a dash).
+   * Same public inputs as `prey_move_jungle`, proven by the `jungle_dash` circuit.
+   */
+  prey_dash_jungle: ({session_id, new_commitment, proof}: {session_id: u32, new_commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a prey_dash_jungle_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_dash_jungle` signed by `signer`: the player or their session key.
+   */
+  prey_dash_jungle_as: ({session_id, signer, new_commitment, proof}: {session_id: u32, signer: string, new_commitment: Buffer, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a prey_exit_jungle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Prey exits jungle (reveals position, becomes visible).
    */
   prey_exit_jungle: ({session_id, x, y}: {session_id: u32, x: u32, y: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a prey_exit_jungle_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_exit_jungle` signed by `signer`: the player or their session key.
+   */
+  prey_exit_jungle_as: ({session_id, signer, x, y}: {session_id: u32, signer: string, x: u32, y: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a respond_search transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Prey responds to a search with a single batched ZK proof of non-presence.
@@ -177,30 +1207,286 @@ export interface Client {
    */
   respond_search: ({session_id, proof}: {session_id: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a respond_search_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `respond_search` signed by `signer`: the player or their session key.
+   */
+  respond_search_as: ({session_id, signer, proof}: {session_id: u32, signer: string, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a respond_sonar transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Prey answers a sonar ping with a ZK proof that their committed position
+   * is (or is not) within `sonar_radius` of the hunter.
+   * 
+   * Proof blob layout (after 4-byte num_fields header):
+   * bytes 4..36:    commitment
+   * bytes 36..68:   hunter_x (u8 in last byte)
+   * bytes 68..100:  hunter_y (u8 in last byte)
+   * bytes 100..132: radius (u8 in last byte)
+   * bytes 132..164: inside (bool in last byte)
+   */
+  respond_sonar: ({session_id, inside, proof}: {session_id: u32, inside: boolean, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a respond_sonar_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `respond_sonar` signed by `signer`: the player or their session key.
+   */
+  respond_sonar_as: ({session_id, signer, inside, proof}: {session_id: u32, signer: string, inside: boolean, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a respond_flare transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Prey responds to a flare with a ZK proof that they are on none of the lit tiles.
+   * An empty proof concedes the round.
+   * 
+   * Proof blob layout (after 4-byte num_fields header):
+   * bytes 4..36:  commitment
+   * bytes 36..68: mask (u64 in last 8 bytes)
+   */
+  respond_flare: ({session_id, proof}: {session_id: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a respond_flare_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `respond_flare` signed by `signer`: the player or their session key.
+   */
+  respond_flare_as: ({session_id, signer, proof}: {session_id: u32, signer: string, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a respond_search_with_decoy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Prey answers a search with a decoy instead: a `decoy_discard` proof that
+   * the decoy at `index` sits on one of the searched tiles. The decoy absorbs
+   * the search, whether or not the prey was hit too: it is discarded and the
+   * search resolved as a miss.
+   * 
+   * Same proof blob layout as `respond_search`, with the decoy's commitment.
+   */
+  respond_search_with_decoy: ({session_id, index, proof}: {session_id: u32, index: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a respond_search_with_decoy_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `respond_search_with_decoy` signed by `signer`: the player or their session key.
+   */
+  respond_search_with_decoy_as: ({session_id, signer, index, proof}: {session_id: u32, signer: string, index: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a hunter_emp transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Hunter uses EMP to freeze visible prey for 1 turn (global range, 1 use per round).
+   * Hunter uses EMP to freeze visible prey for `emp_duration` turns (1 use per round).
+   * With an `emp_range` the prey must be within that Chebyshev distance.
    */
   hunter_emp: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a hunter_emp_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `hunter_emp` signed by `signer`: the player or their session key.
+   */
+  hunter_emp_as: ({session_id, signer}: {session_id: u32, signer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a prey_pass_frozen transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Frozen prey skips their turn (called automatically by frontend).
+   * Skip a frozen prey's turn. Frozen turns are normally skipped as the
+   * hunter's turn ends; this settles one left pending, and anyone may call it.
    */
   prey_pass_frozen: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a prey_dash_public transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Prey dashes up to 2 tiles on plains in a single move (2 uses per turn).
+   * Prey dashes up to 2 tiles on plains in a single move (2 uses per round).
    */
   prey_dash_public: ({session_id, x, y}: {session_id: u32, x: u32, y: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a prey_dash_public_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `prey_dash_public` signed by `signer`: the player or their session key.
+   */
+  prey_dash_public_as: ({session_id, signer, x, y}: {session_id: u32, signer: string, x: u32, y: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a claim_catch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Hunter claims catch (prey failed to respond to search).
+   * Hunter claims catch (prey failed to respond to search). A flare, sonar
+   * ping or burn left unanswered past its deadline is claimed the same way.
    */
   claim_catch: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
+  /**
+   * Construct and simulate a claim_catch_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `claim_catch` signed by `signer`: the player or their session key.
+   */
+  claim_catch_as: ({session_id, signer}: {session_id: u32, signer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a reveal_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Prey reveals where it was hidden during a finished round (`audit` rule).
+   * Each revealed tile (`y * 8 + x`) and nonce must open the commitment
+   * stored for that turn, checked by a `round_audit` proof. A tile that is
+   * not jungle on the round's map fails the audit; in a staked match that
+   * forfeits the round and the next round starts either way.
+   * 
+   * Proof blob layout (after 4-byte num_fields header):
+   * bytes 4..356:     commitments[0..11] (unused slots 0)
+   * bytes 356..708:   positions[0..11] (u8 in last byte)
+   * bytes 708..1060:  nonces[0..11]
+   * bytes 1060..1092: count (u8 in last byte)
+   */
+  reveal_round: ({session_id, round, positions, nonces, proof}: {session_id: u32, round: u32, positions: Array<u32>, nonces: Array<Buffer>, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_round_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `reveal_round` signed by `signer`: the player or their session key.
+   */
+  reveal_round_as: ({session_id, signer, round, positions, nonces, proof}: {session_id: u32, signer: string, round: u32, positions: Array<u32>, nonces: Array<Buffer>, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_audit_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The hunter of a staked audited round claims it after the prey missed
+   * the transcript reveal deadline. The round is forfeited to the hunter.
+   */
+  claim_audit_timeout: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_audit_timeout_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `claim_audit_timeout` signed by `signer`: the player or their session key.
+   */
+  claim_audit_timeout_as: ({session_id, signer}: {session_id: u32, signer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submit_step_proof transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit the proof of one hidden move of a finished optimistic round.
+   * Each proof is stored on its own, as a whole round of them would not
+   * fit in one entry or transaction. Only its public inputs are checked
+   * here; the proof itself is verified when the hunter challenges the
+   * step. The last proof opens the challenge window.
+   */
+  submit_step_proof: ({session_id, step, proof}: {session_id: u32, step: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a submit_step_proof_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `submit_step_proof` signed by `signer`: the player or their session key.
+   */
+  submit_step_proof_as: ({session_id, signer, step, proof}: {session_id: u32, signer: string, step: u32, proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a challenge_step transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The hunter challenges one step of the submitted batch. Its proof is
+   * verified now; if it fails, the round is forfeited to the hunter and the
+   * next round starts. Returns whether the challenge succeeded.
+   */
+  challenge_step: ({session_id, step}: {session_id: u32, step: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a challenge_step_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `challenge_step` signed by `signer`: the player or their session key.
+   */
+  challenge_step_as: ({session_id, signer, step}: {session_id: u32, signer: string, step: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a settle_moves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Close an optimistic round and start the next one. Anyone can call it
+   * after the deadline: if the prey never submitted its proofs, the round
+   * is forfeited to the hunter. Before the deadline, the hunter can call it
+   * to waive the rest of the challenge window.
+   */
+  settle_moves: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a settle_moves_as transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `settle_moves` signed by `signer`: the player or their session key.
+   */
+  settle_moves_as: ({session_id, signer}: {session_id: u32, signer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a retry_hub_notifications transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resend a session's queued Game Hub notifications in order, stopping
+   * at the first that fails again. Ones the hub rejects with a contract
+   * error are dropped. Anyone can call it. Returns how many were delivered.
+   */
+  retry_hub_notifications: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_pending_hub_notifications transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_pending_hub_notifications: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<HubNotification>>>
+
+  /**
+   * Construct and simulate a get_move_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Read the optimistic round waiting to be settled, if any.
+   */
+  get_move_batch: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<MoveBatch>>>
+
+  /**
+   * Construct and simulate a get_transcript transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Read the transcript of a finished round (`audit` rule).
+   */
+  get_transcript: ({session_id, round}: {session_id: u32, round: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<RoundTranscript>>>
+
+  /**
+   * Construct and simulate a register_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register a session key that signs `player`'s game actions in this
+   * session until `expires_ledger`, through each action's `_as` entry point.
+   * The plain entry points keep taking the player's own signature. The key
+   * cannot act in any other session, nor join, reveal seeds or manage
+   * session keys.
+   */
+  register_session_key: ({session_id, player, session_key, expires_ledger}: {session_id: u32, player: string, session_key: string, expires_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a revoke_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Remove `player`'s session key; only the player can sign its game actions again.
+   */
+  revoke_session_key: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Read `player`'s session key for a session, if one is registered.
+   */
+  get_session_key: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SessionKey>>>
+
+  /**
+   * Construct and simulate a open_channel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register `player`'s ed25519 key for channel play. Once both players
+   * have one, they can exchange signed `ChannelUpdate`s off-chain and only
+   * come back on-chain to settle or dispute. Keys cannot be changed later.
+   */
+  open_channel: ({session_id, player, channel_key}: {session_id: u32, player: string, channel_key: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a settle_match transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle a match played off-chain: post the final state (`phase == Ended`)
+   * signed by both players. The winner follows from the signed scores and
+   * the Game Hub is notified as for an on-chain match.
+   */
+  settle_match: ({session_id, update, player1_sig, player2_sig}: {session_id: u32, update: ChannelUpdate, player1_sig: Buffer, player2_sig: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a dispute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Post the latest state signed by both players when the opponent stops
+   * answering off-chain. It replaces the on-chain game state wholesale
+   * (only the match config and rosters must match, and round and scores
+   * cannot go back). Its `seq` must be above the channel's, which every
+   * on-chain action advances, so it cannot roll back on-chain moves. The
+   * opponent (who must be the one to move) has until the deadline to
+   * answer with an on-chain move or a newer signed state handing the move
+   * back.
+   */
+  dispute: ({session_id, player, update, player1_sig, player2_sig}: {session_id: u32, player: string, update: ChannelUpdate, player1_sig: Buffer, player2_sig: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a finalize_dispute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * After the dispute deadline, end the match in the challenger's favour if
+   * the disputed state was never moved on.
+   */
+  finalize_dispute: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_channel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Read a session's channel state, if channel play was opened.
+   */
+  get_channel: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Channel>>>
+
+  /**
+   * Construct and simulate a get_seeds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Read the seed commitments of a commit-reveal game.
+   */
+  get_seeds: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<SeedState>>>
+
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Read game state (for frontend polling).
@@ -242,27 +1528,139 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAFQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAKV3JvbmdQaGFzZQAAAAAAAwAAAAAAAAAJTm90SHVudGVyAAAAAAAABAAAAAAAAAAHTm90UHJleQAAAAAFAAAAAAAAAAtPdXRPZkJvdW5kcwAAAAAGAAAAAAAAAAtJbnZhbGlkTW92ZQAAAAAHAAAAAAAAAAlOb3RKdW5nbGUAAAAAAAAIAAAAAAAAAAtQcm9vZkZhaWxlZAAAAAAJAAAAAAAAABBHYW1lQWxyZWFkeUVuZGVkAAAACgAAAAAAAAARTm90QWRqYWNlbnRKdW5nbGUAAAAAAAALAAAAAAAAAA1TZWFyY2hQZW5kaW5nAAAAAAAADAAAAAAAAAAPTm9Qb3dlclNlYXJjaGVzAAAAAA0AAAAAAAAADVByZXlOb3RIaWRkZW4AAAAAAAAOAAAAAAAAABFQcmV5QWxyZWFkeUhpZGRlbgAAAAAAAA8AAAAAAAAACElzSnVuZ2xlAAAAEAAAAAAAAAAFTm9FTVAAAAAAAAARAAAAAAAAAA9FbXBUYXJnZXRIaWRkZW4AAAAAEgAAAAAAAAANRW1wT3V0T2ZSYW5nZQAAAAAAABMAAAAAAAAACE5vRGFzaGVzAAAAFAAAAAAAAAAKUHJleUZyb3plbgAAAAAAFQ==",
-        "AAAAAwAAAAAAAAAAAAAACUdhbWVQaGFzZQAAAAAAAAUAAAAAAAAAEVdhaXRpbmdGb3JQbGF5ZXIyAAAAAAAAAAAAAAAAAAAKSHVudGVyVHVybgAAAAAAAQAAAAAAAAAIUHJleVR1cm4AAAACAAAAAAAAAA1TZWFyY2hQZW5kaW5nAAAAAAAAAwAAAAAAAAAFRW5kZWQAAAAAAAAE",
-        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAYAAAAAAAAABJlbXBfdXNlc19yZW1haW5pbmcAAAAAAAQAAAAAAAAABmh1bnRlcgAAAAAAEwAAAAAAAAAIaHVudGVyX3gAAAAEAAAAAAAAAAhodW50ZXJfeQAAAAQAAAAAAAAACW1hcF9pbmRleAAAAAAAAAQAAAAAAAAABXBoYXNlAAAAAAAH0AAAAAlHYW1lUGhhc2UAAAAAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAANcGxheWVyMV9zY29yZQAAAAAAAAQAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAANcGxheWVyMl9zY29yZQAAAAAAAAQAAAAAAAAAGHBvd2VyX3NlYXJjaGVzX3JlbWFpbmluZwAAAAQAAAAAAAAABHByZXkAAAATAAAAAAAAAA9wcmV5X2NvbW1pdG1lbnQAAAAD7gAAACAAAAAAAAAAE3ByZXlfZGFzaF9yZW1haW5pbmcAAAAABAAAAAAAAAAOcHJleV9pc19mcm96ZW4AAAAAAAEAAAAAAAAADnByZXlfaXNfaGlkZGVuAAAAAAABAAAAAAAAAAZwcmV5X3gAAAAAAAQAAAAAAAAABnByZXlfeQAAAAAABAAAAAAAAAAFcm91bmQAAAAAAAAEAAAAAAAAABBzZWFyY2hlZF90aWxlc194AAAD6gAAAAQAAAAAAAAAEHNlYXJjaGVkX3RpbGVzX3kAAAPqAAAABAAAAAAAAAAMdG90YWxfcm91bmRzAAAABAAAAAAAAAALdHVybl9udW1iZXIAAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABgAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAABUFkbWluAAAAAAAAAAAAAAAAAAAGTW92ZVZrAAAAAAAAAAAAAAAAAAhTZWFyY2hWawAAAAAAAAAAAAAADU5leHRTZXNzaW9uSWQAAAAAAAAAAAAAAAAAAA5HYW1lSHViQWRkcmVzcwAA",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAQwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAKV3JvbmdQaGFzZQAAAAAAAwAAAAAAAAAJTm90SHVudGVyAAAAAAAABAAAAAAAAAAHTm90UHJleQAAAAAFAAAAAAAAAAtPdXRPZkJvdW5kcwAAAAAGAAAAAAAAAAtJbnZhbGlkTW92ZQAAAAAHAAAAAAAAAAlOb3RKdW5nbGUAAAAAAAAIAAAAAAAAAAtQcm9vZkZhaWxlZAAAAAAJAAAAAAAAABBHYW1lQWxyZWFkeUVuZGVkAAAACgAAAAAAAAARTm90QWRqYWNlbnRKdW5nbGUAAAAAAAALAAAAAAAAAA1TZWFyY2hQZW5kaW5nAAAAAAAADAAAAAAAAAAPTm9Qb3dlclNlYXJjaGVzAAAAAA0AAAAAAAAADVByZXlOb3RIaWRkZW4AAAAAAAAOAAAAAAAAABFQcmV5QWxyZWFkeUhpZGRlbgAAAAAAAA8AAAAAAAAACElzSnVuZ2xlAAAAEAAAAAAAAAAFTm9FTVAAAAAAAAARAAAAAAAAAA9FbXBUYXJnZXRIaWRkZW4AAAAAEgAAAAAAAAANRW1wT3V0T2ZSYW5nZQAAAAAAABMAAAAAAAAACE5vRGFzaGVzAAAAFAAAAAAAAAAKUHJleUZyb3plbgAAAAAAFQAAAAAAAAAMU2VlZFJlcXVpcmVkAAAAFgAAAAAAAAAMU2VlZE1pc21hdGNoAAAAFwAAAAAAAAATU2VlZEFscmVhZHlSZXZlYWxlZAAAAAAYAAAAAAAAAA9TZWVkTm90UmV2ZWFsZWQAAAAAGQAAAAAAAAASRGVhZGxpbmVOb3RSZWFjaGVkAAAAAAAaAAAAAAAAAAtOb3RZb3VyVHVybgAAAAAbAAAAAAAAAAxNYXBOb3RJblBvb2wAAAAcAAAAAAAAAAxSdWxlRGlzYWJsZWQAAAAdAAAAAAAAAA9Ob3RFbm91Z2hFbmVyZ3kAAAAAHgAAAAAAAAAHTm9Tb25hcgAAAAAfAAAAAAAAAA1JbnZhbGlkUmFkaXVzAAAAAAAAIAAAAAAAAAAHTm9GbGFyZQAAAAAhAAAAAAAAAAtJbnZhbGlkTWFzawAAAAAiAAAAAAAAAAdOb1RyYXBzAAAAACMAAAAAAAAADFRyYXBOb3RGb3VuZAAAACQAAAAAAAAADFRyYXBNaXNtYXRjaAAAACUAAAAAAAAADVRvb01hbnlEZWNveXMAAAAAAAAmAAAAAAAAAA1EZWNveU5vdEZvdW5kAAAAAAAAJwAAAAAAAAAHTm9CdXJucwAAAAAoAAAAAAAAABFab25lUHJvb2ZSZXF1aXJlZAAAAAAAACkAAAAAAAAADUludmFsaWRDb25maWcAAAAAAAAqAAAAAAAAAAtOb3RJdGVtVGlsZQAAAAArAAAAAAAAAAhUZWFtRnVsbAAAACwAAAAAAAAADUFscmVhZHlTZWF0ZWQAAAAAAAAtAAAAAAAAAAxIdW50ZXJIaWRkZW4AAAAuAAAAAAAAAA9IdW50ZXJOb3RIaWRkZW4AAAAALwAAAAAAAAATU2NlbnRSZXZlYWxSZXF1aXJlZAAAAAAwAAAAAAAAAApOb1NjZW50RHVlAAAAAAAxAAAAAAAAABJUcmFuc2NyaXB0Tm90Rm91bmQAAAAAADIAAAAAAAAADkFscmVhZHlBdWRpdGVkAAAAAAAzAAAAAAAAABFTZXNzaW9uS2V5RXhwaXJlZAAAAAAAADQAAAAAAAAADkNoYW5uZWxOb3RPcGVuAAAAAAA1AAAAAAAAABJDaGFubmVsQWxyZWFkeU9wZW4AAAAAADYAAAAAAAAAC1N0YWxlVXBkYXRlAAAAADcAAAAAAAAACU5vRGlzcHV0ZQAAAAAAADgAAAAAAAAAD0Rpc3B1dGVBbnN3ZXJlZAAAAAA5AAAAAAAAABZQcm9vZnNBbHJlYWR5U3VibWl0dGVkAAAAAAA6AAAAAAAAABJQcm9vZnNOb3RTdWJtaXR0ZWQAAAAAADsAAAAAAAAADFN0ZXBOb3RGb3VuZAAAADwAAAAAAAAAFUNoYWxsZW5nZVdpbmRvd0Nsb3NlZAAAAAAAAD0AAAAAAAAAEVRyYXBQcm9vZlJlcXVpcmVkAAAAAAAAPgAAAAAAAAALTm9UcmFwQ2hlY2sAAAAAPwAAAAAAAAARSXRlbXNOb3RDb21taXR0ZWQAAAAAAABAAAAAAAAAABVJdGVtc0FscmVhZHlDb21taXR0ZWQAAAAAAABBAAAAAAAAABRDaGFubmVsU3RhdGVNaXNtYXRjaAAAAEIAAAAAAAAAEkl0ZW1BbHJlYWR5Q2xhaW1lZAAAAAAAQw==",
+        "AAAAAwAAAAAAAAAAAAAACUdhbWVQaGFzZQAAAAAAAA4AAAAAAAAAEVdhaXRpbmdGb3JQbGF5ZXIyAAAAAAAAAAAAAAAAAAAKSHVudGVyVHVybgAAAAAAAQAAAAAAAAAIUHJleVR1cm4AAAACAAAAAAAAAA1TZWFyY2hQZW5kaW5nAAAAAAAAAwAAAAAAAAAFRW5kZWQAAAAAAAAEAAAAAAAAAApTZWVkUmV2ZWFsAAAAAAAFAAAAAAAAAAhNYXBEcmFmdAAAAAYAAAAAAAAACVByZXlTcGF3bgAAAAAAAAcAAAAAAAAADFNvbmFyUGVuZGluZwAAAAgAAAAAAAAADEZsYXJlUGVuZGluZwAAAAkAAAAAAAAAC0J1cm5QZW5kaW5nAAAAAAoAAABLU3Rha2VkIGF1ZGl0ZWQgbWF0Y2g6IHRoZSBwcmV5IG11c3QgcmV2ZWFsIHRoZSBmaW5pc2hlZCByb3VuZCdzIHRyYW5zY3JpcHQuAAAAAAxBdWRpdFBlbmRpbmcAAAALAAAAWU9wdGltaXN0aWMgcm91bmQ6IHdhaXRpbmcgZm9yIHRoZSBwcmV5J3MgbW92ZSBwcm9vZnMsIHRoZW4gdGhlIGh1bnRlcidzCmNoYWxsZW5nZSB3aW5kb3cuAAAAAAAADE1vdmVzUGVuZGluZwAAAAwAAABwVGhlIHByZXkgc3Vydml2ZWQgdGhlIHR1cm4gbGltaXQgd2l0aCB0cmFwcyBzdGlsbCBoaWRkZW46IHRoZSBodW50ZXIKbWF5IHJldmVhbCB0aGVtIGJlZm9yZSB0aGUgcm91bmQgaXMgc2NvcmVkLgAAAApUcmFwUmV2ZWFsAAAAAAAN",
+        "AAAAAQAAAHNPcHRpb25hbCBydWxlcyBwaWNrZWQgYnkgdGhlIGNyZWF0b3IgdmlhIGBjcmVhdGVfZ2FtZV93aXRoX2NvbmZpZ2AuCmBjcmVhdGVfZ2FtZWAgdXNlcyB0aGUgZGVmYXVsdCAoYWxsIHJ1bGVzIG9mZikuAAAAAAAAAAAKR2FtZUNvbmZpZwAAAAAAEAAAAKxLZWVwIHRoZSBoaWRkZW4gcHJleSdzIHBlci10dXJuIGNvbW1pdG1lbnRzIGFuZCBhcmNoaXZlIHRoZW0gd2hlbiB0aGUKcm91bmQgZW5kcywgZm9yIGByZXZlYWxfcm91bmRgLiBJbiBjb21taXQtcmV2ZWFsIG1hdGNoZXMgdGhlIHJldmVhbCBpcwpyZXF1aXJlZCBiZWZvcmUgdGhlIG5leHQgcm91bmQuAAAABWF1ZGl0AAAAAAAAAQAAAElNYXAgYW5kIHNwYXducyBjb21lIGZyb20gYSBjb21taXQtcmV2ZWFsIHNlZWQgYm90aCBwbGF5ZXJzIGNvbnRyaWJ1dGUgdG8uAAAAAAAADWNvbW1pdF9yZXZlYWwAAAAAAAABAAAAXVRoZSBIdW50ZXIgbWF5IG1vdmUgYW5kIHRoZW4gc2VhcmNoIGZyb20gdGhlIG5ldyB0aWxlIGluIG9uZSB0dXJuCihgaHVudGVyX21vdmVfYW5kX3NlYXJjaGApLgAAAAAAAA1jb21wb3VuZF90dXJuAAAAAAAAAQAAACZQcmV5IHR1cm5zIGFuIEVNUCBmcmVlemVzICgxIHdoaWxlIDApLgAAAAAADGVtcF9kdXJhdGlvbgAAAAQAAAA8Q2hlYnlzaGV2IHJhbmdlIG9mIHRoZSBFTVAgZnJvbSB0aGUgSHVudGVyIChnbG9iYWwgd2hpbGUgMCkuAAAACWVtcF9yYW5nZQAAAAAAAAQAAABUUGVyLXJvbGUgZW5lcmd5IHBvb2wgcmVwbGFjaW5nIHRoZSBmaXhlZCBhYmlsaXR5IGNvdW50ZXJzOyBvbiB3aGVuIGBlbmVyZ3kubWF4ID4gMGAuAAAABmVuZXJneQAAAAAH0AAAAAxFbmVyZ3lDb25maWcAAACBVGhlIEh1bnRlciBtYXkgaGlkZSBpbiBqdW5nbGUgdG9vIChgaHVudGVyX2VudGVyX2p1bmdsZWApLCBzZWFyY2hpbmcKd2l0aCBhZGphY2VuY3kgcHJvb2ZzIHdoaWxlIGhpZGRlbiAoYGh1bnRlcl9zZWFyY2hfaGlkZGVuYCkuAAAAAAAADWhpZGRlbl9odW50ZXIAAAAAAAABAAAAekVhY2ggcm91bmQgb3BlbnMgd2l0aCB0aGUgUHJleSBjb21taXR0aW5nIHRvIGEgaGlkZGVuIGp1bmdsZSBzcGF3bgooYHByZXlfc3Bhd25faGlkZGVuYCkgaW5zdGVhZCBvZiBhIHB1YmxpYyBwbGFpbnMgc3Bhd24uAAAAAAAMaGlkZGVuX3NwYXduAAAAAQAAAD1JdGVtIHRpbGVzIChqdW5nbGUpIHBsYWNlZCBhdCByYW5kb20gZWFjaCByb3VuZDsgb2ZmIHdoaWxlIDAuAAAAAAAACml0ZW1fY291bnQAAAAAAAQAAAAtSXRlbXMgdGhlIHByZXkgbXVzdCBjb2xsZWN0IHRvIHdpbiB0aGUgcm91bmQuAAAAAAAADml0ZW1zX3JlcXVpcmVkAAAAAAAEAAAAYVBsYXllcnMgYWx0ZXJuYXRlbHkgYmFuIG1hcHMgZnJvbSBhIGRyYXduIHBvb2w7IHRoZSBzdXJ2aXZvcnMgYXJlCnBsYXllZCBpbiBvcmRlciwgb25lIHBlciByb3VuZC4AAAAAAAAJbWFwX2RyYWZ0AAAAAAAAAQAAABpIb3cgdGhlIHByZXkgd2lucyBhIHJvdW5kLgAAAAAACW9iamVjdGl2ZQAAAAAAB9AAAAAJT2JqZWN0aXZlAAAAAAAAwUhpZGRlbiBwcmV5IG1vdmVzIChgcHJleV9tb3ZlX2p1bmdsZWAsIGBwcmV5X2Rhc2hfanVuZ2xlYCkgcG9zdCBvbmx5IHRoZQpuZXcgY29tbWl0bWVudC4gVGhlaXIgcHJvb2ZzIGNvbWUgc3RlcCBieSBzdGVwIGF0IHJvdW5kIGVuZAooYHN1Ym1pdF9zdGVwX3Byb29mYCkgYW5kIHRoZSBodW50ZXIgY2FuIGNoYWxsZW5nZSBhbnkgc3RlcC4AAAAAAAAKb3B0aW1pc3RpYwAAAAAAAQAAAHJUaGUgaGlkZGVuIFByZXkgbXVzdCByZXZlYWwgdGhlIHRpbGUgaXQgaGVsZCB0aGlzIG1hbnkgbW92ZXMgYWdvCmJlZm9yZSBhY3RpbmcgKGBwcmV5X3JldmVhbF9zY2VudGApLiBPZmYgd2hpbGUgMC4AAAAAAAtzY2VudF9kZWxheQAAAAAEAAAANlRlYW0gc2l6ZXM7IHBhcnRuZXJzIHNlYXQgdGhlbXNlbHZlcyB3aXRoIGBqb2luX3RlYW1gLgAAAAAACXRlYW1fbW9kZQAAAAAAB9AAAAAIVGVhbU1vZGUAAAB3RnJvbSB0aGlzIHR1cm4gb24sIG9uZSBtb3JlIG91dGVyIHJpbmcgb2YgdGhlIGJvYXJkIGJlY29tZXMgaGF6YXJkb3VzCmVhY2ggdHVybiAoZG93biB0byB0aGUgY2VudHJhbCAyeDIpLiBPZmYgd2hpbGUgMC4AAAAAD3pvbmVfc3RhcnRfdHVybgAAAAAE",
+        "AAAAAwAAAB1Sb3VuZCBvYmplY3RpdmUgZm9yIHRoZSBwcmV5LgAAAAAAAAAAAAAJT2JqZWN0aXZlAAAAAAAAAwAAABpTdXJ2aXZlIGBNQVhfVFVSTlNgIHR1cm5zLgAAAAAAB1N1cnZpdmUAAAAAAAAAAEBSZWFjaCBhbiBleHRyYWN0aW9uIHRpbGU7IHN1cnZpdmluZyB0byB0aGUgdHVybiBsaW1pdCBpcyBhIGRyYXcuAAAADEVzY2FwZU9yRHJhdwAAAAEAAABGUmVhY2ggYW4gZXh0cmFjdGlvbiB0aWxlOyBzdXJ2aXZpbmcgdG8gdGhlIHR1cm4gbGltaXQgaXMgYSBodW50ZXIgd2luLgAAAAAADEVzY2FwZU9yTG9zZQAAAAI=",
+        "AAAAAwAAADtUZWFtIHNpemVzIGZvciBhIG1hdGNoOiBwbGF5ZXIgMSdzIHRlYW0gdnMgcGxheWVyIDIncyB0ZWFtLgAAAAAAAAAACFRlYW1Nb2RlAAAAAwAAAAAAAAAEU29sbwAAAAAAAAAoUGxheWVyIDEgYW5kIGEgcGFydG5lciBhZ2FpbnN0IHBsYXllciAyLgAAAAhUd29Wc09uZQAAAAEAAAAAAAAACFR3b1ZzVHdvAAAAAg==",
+        "AAAAAQAAAIxFbmVyZ3kgZWNvbm9teTogZWFjaCByb2xlIHN0YXJ0cyBhIHJvdW5kIHdpdGggYG1heGAgZW5lcmd5LCByZWdhaW5zCmByZWdlbmAgcGVyIHR1cm4gKGNhcHBlZCBhdCBgbWF4YCksIGFuZCBwYXlzIHRoZSBsaXN0ZWQgY29zdCBwZXIgYWN0aW9uLgAAAAAAAAAMRW5lcmd5Q29uZmlnAAAADQAAAAAAAAAJYnVybl9jb3N0AAAAAAAABAAAAAAAAAAJZGFzaF9jb3N0AAAAAAAABAAAAAAAAAAKZGVjb3lfY29zdAAAAAAABAAAAAAAAAAIZW1wX2Nvc3QAAAAEAAAAAAAAAApmbGFyZV9jb3N0AAAAAAAEAAAAAAAAAAloaWRlX2Nvc3QAAAAAAAAEAAAAAAAAAANtYXgAAAAABAAAAAAAAAAJbW92ZV9jb3N0AAAAAAAABAAAAAAAAAARcG93ZXJfc2VhcmNoX2Nvc3QAAAAAAAAEAAAAAAAAAAVyZWdlbgAAAAAAAAQAAAAAAAAAC3NlYXJjaF9jb3N0AAAAAAQAAAAAAAAACnNvbmFyX2Nvc3QAAAAAAAQAAAAAAAAACXRyYXBfY29zdAAAAAAAAAQ=",
+        "AAAAAwAAAG1Qcm9vZiBzdGF0ZW1lbnRzIGJleW9uZCB0aGUgb3JpZ2luYWwgbW92ZS9zZWFyY2ggcGFpciwgZWFjaCB2ZXJpZmllZAphZ2FpbnN0IGl0cyBvd24gVksgc2xvdCAoYERhdGFLZXk6OlZrYCkuAAAAAAAAAAAAAAdDaXJjdWl0AAAAAA0AAABgYGhpZGRlbl9zcGF3bmA6IGNvbW1pdG1lbnQgaXMgYSBqdW5nbGUgdGlsZSBmYXIgZW5vdWdoIGZyb20gdGhlIGh1bnRlcgphbmQgdGhlIGV4dHJhY3Rpb24gdGlsZXMuAAAABVNwYXduAAAAAAAAAAAAADtganVuZ2xlX2Rhc2hgOiBoaWRkZW4gbW92ZSBvZiB1cCB0byAyIHRpbGVzIHRocm91Z2gganVuZ2xlLgAAAAAERGFzaAAAAAEAAABKYHNvbmFyX3Jlc3BvbnNlYDogY29tbWl0bWVudCBpcyBpbnNpZGUvb3V0c2lkZSBhIHJhZGl1cyBhcm91bmQgdGhlIGh1bnRlci4AAAAAAAVTb25hcgAAAAAAAAIAAABBYGZsYXJlX3Jlc3BvbnNlYDogY29tbWl0bWVudCBpcyBub3Qgb24gYW55IHRpbGUgb2YgYSA2NC1iaXQgbWFzay4AAAAAAAAFRmxhcmUAAAAAAAADAAAAO2BkZWNveV9kaXNjYXJkYDogYSBkZWNveSBzaXRzIG9uIG9uZSBvZiB0aGUgc2VhcmNoZWQgdGlsZXMuAAAAAAVEZWNveQAAAAAAAAQAAAAzYHpvbmVfY2hlY2tgOiBjb21taXRtZW50IGxpZXMgaW5zaWRlIHRoZSBzYWZlIHpvbmUuAAAAAARab25lAAAABQAAAEFgZXh0cmFjdGlvbmA6IGNvbW1pdG1lbnQgaXMgb24gb25lIG9mIHRoZSBtYXAncyBleHRyYWN0aW9uIHRpbGVzLgAAAAAAAApFeHRyYWN0aW9uAAAAAAAGAAAARmBpdGVtX2NsYWltYDogY29tbWl0bWVudCBpcyBhdCBvbmUgb2YgdGhlIHByZXkncyBjb21taXR0ZWQgaXRlbSB0aWxlcy4AAAAAAARJdGVtAAAABwAAAE1gaHVudGVyX2FkamFjZW5jeWA6IHNlYXJjaGVkIHRpbGVzIGFyZSBhcm91bmQgdGhlIGhpZGRlbiBodW50ZXIncyBjb21taXRtZW50LgAAAAAAAAlBZGphY2VuY3kAAAAAAAAIAAAAU2BzY2VudF9yZXZlYWxgOiBhbiBvbGQgY29tbWl0bWVudCBvcGVucyB0byBhIHRpbGUgYSBmZXcgbW92ZXMgZnJvbSB0aGUgY3VycmVudCBvbmUuAAAAAAVTY2VudAAAAAAAAAkAAABIYHJvdW5kX2F1ZGl0YDogcmV2ZWFsZWQgcG9zaXRpb25zIGFuZCBub25jZXMgb3BlbiBhIHJvdW5kJ3MgY29tbWl0bWVudHMuAAAABUF1ZGl0AAAAAAAACgAAAD9ganVuZ2xlX2VudGVyYDogYSBzdGVwIGZyb20gYSBwdWJsaWMgdGlsZSBvbnRvIGFkamFjZW50IGp1bmdsZS4AAAAABUVudGVyAAAAAAAACwAAAEVganVuZ2xlX2V4aXRgOiBhIHB1YmxpYyBleGl0IHRpbGUgaXMgd2l0aGluIG9uZSBzdGVwIG9mIGEgY29tbWl0bWVudC4AAAAAAAAERXhpdAAAAAw=",
+        "AAAAAQAAAGdBIGh1bnRlciB0cmFwLCBoaWRkZW4gYXMgYHNoYTI1Nih4IHx8IHkgfHwgc2FsdClgICh4LCB5IGFzIGJpZy1lbmRpYW4gdTMyKQp1bnRpbCB0aGUgaHVudGVyIHJldmVhbHMgaXQuAAAAAAAAAAAEVHJhcAAAAAMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAAAAAAtwbGFjZWRfdHVybgAAAAAEAAAAAAAAAAhyZXZlYWxlZAAAAAE=",
+        "AAAAAQAAAJhBIGdob3N0IGNvbW1pdG1lbnQgcmVnaXN0ZXJlZCBieSB0aGUgaGlkZGVuIHByZXkuIERlY295cyB1c2UgYSB0YWdnZWQgaGFzaAooYFBvc2VpZG9uMih4LCB5LCBub25jZSwgMSlgKSwgc28gdGhleSBjYW4gbmV2ZXIgZXF1YWwgYSBwb3NpdGlvbiBjb21taXRtZW50LgAAAAAAAAAFRGVjb3kAAAAAAAACAAAAAAAAAApjb21taXRtZW50AAAAAAPuAAAAIAAAAAAAAAAMZXhwaXJlc190dXJuAAAABA==",
+        "AAAAAQAAAXhBIHRlYW0gbWVtYmVyIHdhaXRpbmcgZm9yIHRoZWlyIHR1cm4uIFRlYW0gbWVtYmVycyBhbHRlcm5hdGUgdHVybnM6IGF0IHRoZQplbmQgb2YgZXZlcnkgdHVybiB0aGUgYmVuY2hlZCBtZW1iZXIgc3dhcHMgaW50byB0aGUgYWN0aXZlIGh1bnRlciAvIHByZXkgc2xvdC4KSHVudGVyIGFiaWxpdGllcyBvbmx5IHJlYWNoIHRoZSBhY3RpdmUgcHJleTogYSBiZW5jaGVkIHByZXkgaGlkZGVuIGluIGp1bmdsZQpjYW5ub3QgYmUgc2VhcmNoZWQsIGZsYXJlZCwgcGluZ2VkIG9yIGJ1cm5lZCB1bnRpbCBpdCByb3RhdGVzIGJhY2sgaW4sIHdoaWxlCmEgdmlzaWJsZSBiZW5jaGVkIHByZXkgaXMgc3RpbGwgY2F1Z2h0IGJ5IGEgaHVudGVyIHN0ZXBwaW5nIG9udG8gaXQuAAAAAAAAAAtCZW5jaE1lbWJlcgAAAAAHAAAAAAAAAApjb21taXRtZW50AAAAAAPuAAAAIAAAAERGcm96ZW4gdHVybnMgYSBiZW5jaGVkIHByZXkgc3RpbGwgb3dlczsgdGhleSByZXN1bWUgd2hlbiBpdCByZXR1cm5zLgAAAAxmcm96ZW5fdHVybnMAAAAEAAAAAAAAAAlpc19oaWRkZW4AAAAAAAABAAAAAAAAAAlpc19odW50ZXIAAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABA==",
+        "AAAAAQAAAIFSb3N0ZXJzIG9mIGEgdGVhbSBnYW1lLiBUaGUgcHJpbWFyeSBgR2FtZWAgc2xvdHMgaG9sZCB0aGUgYWN0aXZlIG1lbWJlcnM7CmFiaWxpdGllcyBhbmQgdGhlaXIgcG9vbHMgYXJlIHNoYXJlZCBieSB0aGUgd2hvbGUgdGVhbS4AAAAAAAAAAAAABVRlYW1zAAAAAAAAAwAAAAAAAAAFYmVuY2gAAAAAAAPqAAAH0AAAAAtCZW5jaE1lbWJlcgAAAAAuUGFydG5lcnMgb2YgcGxheWVyIDEgKG5vdCBpbmNsdWRpbmcgcGxheWVyIDEpLgAAAAAABXRlYW0xAAAAAAAD6gAAABMAAAAuUGFydG5lcnMgb2YgcGxheWVyIDIgKG5vdCBpbmNsdWRpbmcgcGxheWVyIDIpLgAAAAAABXRlYW0yAAAAAAAD6gAAABM=",
+        "AAAAAQAAAFBBIHRpbGUgdGhlIHByZXkgZW5kZWQgYSB0dXJuIG9uOiBzZWVuIHdoaWxlIHZpc2libGUsIG9yIHJldmVhbGVkIGxhdGVyIGJ5IHNjZW50LgAAAAAAAAAJVHJhaWxTdGVwAAAAAAAAAwAAAAAAAAAEdHVybgAAAAQAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABA==",
+        "AAAAAQAAADJUaGUgaGlkZGVuIHByZXkncyBjb21taXRtZW50IGF0IHRoZSBlbmQgb2YgYSB0dXJuLgAAAAAAAAAAABBDb21taXRtZW50UmVjb3JkAAAAAgAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAABHR1cm4AAAAE",
+        "AAAAAwAAACRPdXRjb21lIG9mIGEgcm91bmQgdHJhbnNjcmlwdCBhdWRpdC4AAAAAAAAAC0F1ZGl0UmVzdWx0AAAAAAMAAAAAAAAAB1BlbmRpbmcAAAAAAAAAAAAAAAAGUGFzc2VkAAAAAAABAAAARVJldmVhbGVkIGEgbm9uLWp1bmdsZSB0aWxlLCBvciBtaXNzZWQgdGhlIGRlYWRsaW5lIGluIGEgc3Rha2VkIG1hdGNoLgAAAAAAAAZGYWlsZWQAAAAAAAI=",
+        "AAAAAQAAAGBUaGUgaGlkZGVuIHByZXkncyBjb21taXRtZW50cyBvZiBhIGZpbmlzaGVkIHJvdW5kLCBzdG9yZWQgcGVyIHJvdW5kCnVuZGVyIGBEYXRhS2V5OjpUcmFuc2NyaXB0YC4AAAAAAAAAD1JvdW5kVHJhbnNjcmlwdAAAAAAJAAAAAAAAAAtjb21taXRtZW50cwAAAAPqAAAH0AAAABBDb21taXRtZW50UmVjb3JkAAAAPVR1cm5zIHRoZSBwcmV5IGRhc2hlZCBvbiAoYSByZXZlYWxlZCBzdGVwIG1heSBjb3ZlciAyIHRpbGVzKS4AAAAAAAAKZGFzaF90dXJucwAAAAAD6gAAAAQAAABCUmV2ZWFsIGRlYWRsaW5lIHdoZW4gdGhlIG5leHQgcm91bmQgd2FpdHMgZm9yIGl0IChzdGFrZWQgbWF0Y2hlcykuAAAAAAAIZGVhZGxpbmUAAAAEAAAAAAAAAAZodW50ZXIAAAAAABMAAAAAAAAACW1hcF9pbmRleAAAAAAAAAQAAAAAAAAABHByZXkAAAATAAAAAAAAAAZyZXN1bHQAAAAAB9AAAAALQXVkaXRSZXN1bHQAAAAAJldobyBzY29yZWQgdGhlIHJvdW5kOyBOb25lIGZvciBhIGRyYXcuAAAAAAAMcm91bmRfd2lubmVyAAAD6AAAABMAAABKVGhlIHByZXkncyBwdWJsaWMgcG9zaXRpb25zIHRoaXMgcm91bmQsIHdoaWNoIHRoZSByZXZlYWxlZCBwYXRoIG11c3Qgam9pbi4AAAAAAAV0cmFpbAAAAAAAA+oAAAfQAAAACVRyYWlsU3RlcAAAAA==",
+        "AAAAAQAAAD5BIGhpZGRlbiBwcmV5IG1vdmUgcG9zdGVkIHdpdGhvdXQgYSBwcm9vZiAoYG9wdGltaXN0aWNgIHJ1bGUpLgAAAAAAAAAAAAhNb3ZlU3RlcAAAAAQAAABBQnVybmVkIHRpbGVzIHdoZW4gdGhlIG1vdmUgd2FzIG1hZGUsIGEgcHVibGljIGlucHV0IG9mIGl0cyBwcm9vZi4AAAAAAAAMYnVybmVkX3RpbGVzAAAABgAAADJQcm92ZW4gYnkgYGp1bmdsZV9kYXNoYCByYXRoZXIgdGhhbiBganVuZ2xlX21vdmVgLgAAAAAABGRhc2gAAAABAAAAAAAAAA5uZXdfY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAADm9sZF9jb21taXRtZW50AAAAAAPuAAAAIA==",
+        "AAAAAQAAAExBIGZpbmlzaGVkIG9wdGltaXN0aWMgcm91bmQgd2FpdGluZyBvbiBpdHMgbW92ZSBwcm9vZnMgYW5kIGNoYWxsZW5nZSB3aW5kb3cuAAAAAAAAAAlNb3ZlQmF0Y2gAAAAAAAAIAAAAQFByb29mIHN1Ym1pc3Npb24gZGVhZGxpbmUsIHRoZW4gdGhlIGVuZCBvZiB0aGUgY2hhbGxlbmdlIHdpbmRvdy4AAAAIZGVhZGxpbmUAAAAEAAAAAAAAAAZodW50ZXIAAAAAABMAAAAAAAAACW1hcF9pbmRleAAAAAAAAAQAAAAAAAAABHByZXkAAAATAAAAQ0JpdCBwZXIgc3RlcCB3aG9zZSBwcm9vZiBoYXMgYmVlbiBzdWJtaXR0ZWQgKGBEYXRhS2V5OjpTdGVwUHJvb2ZgKS4AAAAADHByb3Zlbl9zdGVwcwAAAAQAAAAAAAAABXJvdW5kAAAAAAAABAAAAAAAAAAMcm91bmRfd2lubmVyAAAD6AAAABMAAAAAAAAABXN0ZXBzAAAAAAAD6gAAB9AAAAAITW92ZVN0ZXA=",
+        "AAAAAQAAAHdBIGtleSBhIHBsYXllciByZWdpc3RlcmVkIHRvIHNpZ24gaXRzIGdhbWUgYWN0aW9ucyBpbiBvbmUgc2Vzc2lvbiwgc28gdGhlCm1haW4gd2FsbGV0IGRvZXMgbm90IGhhdmUgdG8gc2lnbiBldmVyeSBtb3ZlLgAAAAAAAAAAClNlc3Npb25LZXkAAAAAAAIAAAAjTGFzdCBsZWRnZXIgdGhlIGtleSBpcyBhY2NlcHRlZCBvbi4AAAAADmV4cGlyZXNfbGVkZ2VyAAAAAAAEAAAAAAAAAANrZXkAAAAAEw==",
+        "AAAAAQAAAHVBIGdhbWUgc3RhdGUgYm90aCBwbGF5ZXJzIHNpZ24gb2ZmLWNoYWluIGluIGNoYW5uZWwgbW9kZS4gVGhlIHNpZ25lZAptZXNzYWdlIGlzIHRoZSBYRFIgZW5jb2Rpbmcgb2YgdGhlIHdob2xlIHVwZGF0ZS4AAAAAAAAAAAAADUNoYW5uZWxVcGRhdGUAAAAAAAAEAAAAU0NvbnRyYWN0IHRoZSB1cGRhdGUgaXMgZm9yLCBzbyBzaWduYXR1cmVzIGNhbm5vdCBiZSByZXBsYXllZCBvbgphbm90aGVyIGRlcGxveW1lbnQuAAAAAAhjb250cmFjdAAAABMAAAAAAAAABGdhbWUAAAfQAAAABEdhbWUAAABOSW5jcmVhc2VzIHdpdGggZXZlcnkgZXhjaGFuZ2VkIHN0YXRlOyBvbmx5IG5ld2VyIHN0YXRlcyBhcmUgYWNjZXB0ZWQgb24tY2hhaW4uAAAAAAADc2VxAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQ=",
+        "AAAAAQAAAGdDaGFubmVsLW1vZGUgYm9va2tlZXBpbmcgZm9yIGEgc2Vzc2lvbjogdGhlIHBsYXllcnMnIGVkMjU1MTkga2V5cyBhbmQgdGhlCmxhdGVzdCBzdGF0ZSBwb3N0ZWQgb24tY2hhaW4uAAAAAAAAAAAHQ2hhbm5lbAAAAAAIAAAAQlBsYXllciB3aG8gcG9zdGVkIHRoZSBwZW5kaW5nIGRpc3B1dGU7IHRoZSBvcHBvbmVudCBoYXMgdG8gYW5zd2VyLgAAAAAACmNoYWxsZW5nZXIAAAAAA+gAAAATAAAAAAAAABBkaXNwdXRlX2RlYWRsaW5lAAAABAAAAAAAAAAOZGlzcHV0ZWRfcGhhc2UAAAAAB9AAAAAJR2FtZVBoYXNlAAAAAAAAQ1JvdW5kLCB0dXJuIGFuZCBwaGFzZSBvZiB0aGUgZGlzcHV0ZWQgc3RhdGU7IGFueSBtb3ZlIGNoYW5nZXMgdGhlbS4AAAAADmRpc3B1dGVkX3JvdW5kAAAAAAAEAAAAAAAAAA1kaXNwdXRlZF90dXJuAAAAAAAABAAAAAAAAAALcGxheWVyMV9rZXkAAAAD6AAAA+4AAAAgAAAAAAAAAAtwbGF5ZXIyX2tleQAAAAPoAAAD7gAAACAAAAB0U2VxdWVuY2UgbnVtYmVyIG9mIHRoZSBsYXRlc3Qgc3RhdGUgb24tY2hhaW46IHRoZSBsYXN0IHBvc3RlZCB1cGRhdGUsCnBsdXMgb25lIGZvciBldmVyeSBvbi1jaGFpbiBnYW1lIGFjdGlvbiBzaW5jZS4AAAADc2VxAAAAAAQ=",
+        "AAAAAQAAAIRTZWVkIGNvbW1pdG1lbnRzIGZvciBhIGNvbW1pdC1yZXZlYWwgZ2FtZS4KQ29tbWl0bWVudHMgYXJlIGBzaGEyNTYoc2VlZClgOyB0aGUgbWF0Y2ggc2VlZCBpcyBgc2hhMjU2KHBsYXllcjFfc2VlZCB8fCBwbGF5ZXIyX3NlZWQpYC4AAAAAAAAACVNlZWRTdGF0ZQAAAAAAAAYAAAAAAAAACm1hdGNoX3NlZWQAAAAAA+gAAAPuAAAAIAAAAAAAAAAMcGxheWVyMV9oYXNoAAAD7gAAACAAAAAAAAAADHBsYXllcjFfc2VlZAAAA+gAAAPuAAAAIAAAAAAAAAAMcGxheWVyMl9oYXNoAAAD7gAAACAAAAAAAAAADHBsYXllcjJfc2VlZAAAA+gAAAPuAAAAIAAAAAAAAAAPcmV2ZWFsX2RlYWRsaW5lAAAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAA3AAAAO0p1bmdsZSB0aWxlcyBidXJuZWQgdG8gcGxhaW5zIHRoaXMgcm91bmQgKGJpdCBgeSAqIDggKyB4YCkuAAAAAAxidXJuZWRfdGlsZXMAAAAGAAAAbUV2ZXJ5IGNvbW1pdG1lbnQgdGhlIGhpZGRlbiBwcmV5IGVuZGVkIGEgdHVybiBvbiB0aGlzIHJvdW5kLCBhZnRlciBpdHMKaGlkZGVuIHNwYXduIGFzIHR1cm4gMCAoYGF1ZGl0YCBydWxlKS4AAAAAAAASY29tbWl0bWVudF9oaXN0b3J5AAAAAAPqAAAH0AAAABBDb21taXRtZW50UmVjb3JkAAAAAAAAAAZjb25maWcAAAAAB9AAAAAKR2FtZUNvbmZpZwAAAAAAOlR1cm5zIHRoZSBoaWRkZW4gcHJleSBkYXNoZWQgb24gdGhpcyByb3VuZCAoYGF1ZGl0YCBydWxlKS4AAAAAAApkYXNoX3R1cm5zAAAAAAPqAAAABAAAAFhBY3RpdmUgZGVjb3lzIChhdCBtb3N0IGBNQVhfREVDT1lTYCksIGRyb3BwZWQgb25jZSBgdHVybl9udW1iZXJgIHJlYWNoZXMgYGV4cGlyZXNfdHVybmAuAAAABmRlY295cwAAAAAD6gAAB9AAAAAFRGVjb3kAAAAAAAAAAAAADmRyYWZ0X2RlYWRsaW5lAAAAAAAEAAAAAAAAAApkcmFmdF9wb29sAAAAAAPqAAAABAAAAAAAAAASZW1wX3VzZXNfcmVtYWluaW5nAAAAAAAEAAAAMVRpbGVzIGxpdCBieSB0aGUgcGVuZGluZyBmbGFyZSAoYml0IGB5ICogOCArIHhgKS4AAAAAAAAKZmxhcmVfbWFzawAAAAAABgAAAAAAAAAUZmxhcmVfdXNlc19yZW1haW5pbmcAAAAEAAAAAAAAAAZodW50ZXIAAAAAABMAAAAAAAAAEWh1bnRlcl9jb21taXRtZW50AAAAAAAD7gAAACAAAAAAAAAADWh1bnRlcl9lbmVyZ3kAAAAAAAAEAAAASVdoaWxlIGhpZGRlbiwgYGh1bnRlcl94YC9gaHVudGVyX3lgIGFyZSB0aGUgSHVudGVyJ3MgbGFzdCBrbm93biBwb3NpdGlvbi4AAAAAAAAQaHVudGVyX2lzX2hpZGRlbgAAAAEAAAAAAAAACGh1bnRlcl94AAAABAAAAAAAAAAIaHVudGVyX3kAAAAEAAAAhkNhbmRpZGF0ZSBpdGVtIHRpbGVzIHRoaXMgcm91bmQgKGJpdCBgeSAqIDggKyB4YCk7IGBpdGVtX2NvdW50YCBvZiB0aGVtCmhvbGQgaXRlbXMsIGNob3NlbiBzZWNyZXRseSBieSB0aGUgcHJleSAoYHByZXlfY29tbWl0X2l0ZW1zYCkuAAAAAAAPaXRlbV9jYW5kaWRhdGVzAAAAAAYAAAArTnVsbGlmaWVycyBvZiB0aGUgaXRlbXMgY2xhaW1lZCB0aGlzIHJvdW5kLgAAAAAPaXRlbV9udWxsaWZpZXJzAAAAA+oAAAPuAAAAIAAAAAAAAAAPaXRlbXNfY29sbGVjdGVkAAAAAAQAAABHUHJleSdzIGNvbW1pdG1lbnQgdG8gd2hpY2ggY2FuZGlkYXRlcyBob2xkIGl0ZW1zLCBvbmNlIG1hZGUgdGhpcyByb3VuZC4AAAAAEGl0ZW1zX2NvbW1pdG1lbnQAAAPoAAAD7gAAACAAAABDQW5zd2VyIHRvIHRoZSBsYXN0IHNvbmFyIHBpbmcgdGhpcyByb3VuZDogaW5zaWRlIHRoZSByYWRpdXMgb3Igbm90LgAAAAARbGFzdF9zb25hcl9pbnNpZGUAAAAAAAPoAAAAAQAAAAAAAAAJbWFwX2luZGV4AAAAAAAABAAAAEBIaWRkZW4gcHJleSBtb3ZlcyBub3QgcHJvdmVuIHlldCB0aGlzIHJvdW5kIChgb3B0aW1pc3RpY2AgcnVsZSkuAAAADXBlbmRpbmdfc3RlcHMAAAAAAAPqAAAH0AAAAAhNb3ZlU3RlcAAAAAAAAAAFcGhhc2UAAAAAAAfQAAAACUdhbWVQaGFzZQAAAAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAA1wbGF5ZXIxX3Njb3JlAAAAAAAABAAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA1wbGF5ZXIyX3Njb3JlAAAAAAAABAAAAAAAAAAYcG93ZXJfc2VhcmNoZXNfcmVtYWluaW5nAAAABAAAAAAAAAAEcHJleQAAABMAAAAAAAAAD3ByZXlfY29tbWl0bWVudAAAAAPuAAAAIAAAAAAAAAATcHJleV9kYXNoX3JlbWFpbmluZwAAAAAEAAAAAAAAAAtwcmV5X2VuZXJneQAAAAAEAAAALFByZXkgdHVybnMgc3RpbGwgdG8gYmUgc2tpcHBlZCB3aGlsZSBmcm96ZW4uAAAAEXByZXlfZnJvemVuX3R1cm5zAAAAAAAABAAAAAAAAAAOcHJleV9pc19mcm96ZW4AAAAAAAEAAAAAAAAADnByZXlfaXNfaGlkZGVuAAAAAAABAAAAf1B1YmxpYyBwb3NpdGlvbnMgb2YgdGhlIHByZXkgdGhpcyByb3VuZCAoYSBwdWJsaWMgc3Bhd24gYXMgdHVybiAwKSwKY2hlY2tlZCB3aGVuIGEgdHJhcCBpcyByZXZlYWxlZCBvciBhIHRyYW5zY3JpcHQgaXMgYXVkaXRlZC4AAAAACnByZXlfdHJhaWwAAAAAA+oAAAfQAAAACVRyYWlsU3RlcAAAAAAAAAAAAAAGcHJleV94AAAAAAAEAAAAAAAAAAZwcmV5X3kAAAAAAAQAAABJTGVkZ2VyIGJ5IHdoaWNoIHRoZSBwcmV5IG11c3QgYW5zd2VyIGEgcGVuZGluZyBmbGFyZSwgc29uYXIgcGluZyBvciBidXJuLgAAAAAAABFyZXNwb25zZV9kZWFkbGluZQAAAAAAAAQAAAAAAAAABXJvdW5kAAAAAAAABAAAAE1Db21taXRtZW50cyBvZiB0aGUgaGlkZGVuIHByZXkgd2hvc2Ugc2NlbnQgaXMgbm90IHJldmVhbGVkIHlldCwgb2xkZXN0IGZpcnN0LgAAAAAAAA1zY2VudF9oaXN0b3J5AAAAAAAD6gAAB9AAAAAQQ29tbWl0bWVudFJlY29yZAAAAC5IaWRkZW4gcG9zaXRpb25zIHJldmVhbGVkIGJ5IHNjZW50IHRoaXMgcm91bmQuAAAAAAALc2NlbnRfdHJhaWwAAAAD6gAAB9AAAAAJVHJhaWxTdGVwAAAAAAAAAAAAABBzZWFyY2hlZF90aWxlc194AAAD6gAAAAQAAAAAAAAAEHNlYXJjaGVkX3RpbGVzX3kAAAPqAAAABAAAADRSYWRpdXMgb2YgdGhlIHBlbmRpbmcgKG9yIGxhc3QgYW5zd2VyZWQpIHNvbmFyIHBpbmcuAAAADHNvbmFyX3JhZGl1cwAAAAQAAAAAAAAAFHNvbmFyX3VzZXNfcmVtYWluaW5nAAAABAAAAAAAAAAFdGVhbXMAAAAAAAfQAAAABVRlYW1zAAAAAAAAAAAAAAx0b3RhbF9yb3VuZHMAAAAEAAAAVFJldmVhbGVkIHRyYXAgdGlsZXMgdGhlIGhpZGRlbiBwcmV5IGhhcyB5ZXQgdG8gcHJvdmUgaXQgaXMgbm90IG9uIChiaXQgYHkgKiA4ICsgeGApLgAAAA90cmFwX2NoZWNrX21hc2sAAAAABgAAAC5UcmFwcyBwbGFjZWQgdGhpcyByb3VuZCAoYXQgbW9zdCBgTUFYX1RSQVBTYCkuAAAAAAAFdHJhcHMAAAAAAAPqAAAH0AAAAARUcmFwAAAAAAAAAAt0dXJuX251bWJlcgAAAAAEAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAATAAAAQUNvbW1pdG1lbnQgdGhlIHByZXkgcHJvdmVkIGluc2lkZSB0aGUgem9uZSBvbiBgem9uZV9wcm92ZW5fdHVybmAuAAAAAAAAD3pvbmVfY29tbWl0bWVudAAAAAPuAAAAIAAAADxMYXN0IHR1cm4gdGhlIGhpZGRlbiBwcmV5IHByb3ZlZCBpdCBpcyBpbnNpZGUgdGhlIHNhZmUgem9uZS4AAAAQem9uZV9wcm92ZW5fdHVybgAAAAQ=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAADwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAABUFkbWluAAAAAAAAAAAAAAAAAAAGTW92ZVZrAAAAAAAAAAAAAAAAAAhTZWFyY2hWawAAAAAAAAAAAAAADU5leHRTZXNzaW9uSWQAAAAAAAAAAAAAAAAAAA5HYW1lSHViQWRkcmVzcwAAAAAAAAAAAAAAAAAQR2FtZUh1YkludGVyZmFjZQAAAAEAAAAAAAAABVNlZWRzAAAAAAAAAQAAAAQAAAABAAAAAAAAAAJWawAAAAAAAQAAB9AAAAAHQ2lyY3VpdAAAAAABAAAANFRyYW5zY3JpcHQgb2YgYSBmaW5pc2hlZCByb3VuZDogKHNlc3Npb25faWQsIHJvdW5kKS4AAAAKVHJhbnNjcmlwdAAAAAAAAgAAAAQAAAAEAAAAAQAAADpBIHBsYXllcidzIHNlc3Npb24ga2V5IGZvciBvbmUgZ2FtZTogKHNlc3Npb25faWQsIHBsYXllcikuAAAAAAAKU2Vzc2lvbktleQAAAAAAAgAAAAQAAAATAAAAAQAAAAAAAAAHQ2hhbm5lbAAAAAABAAAABAAAAAEAAAAAAAAACU1vdmVCYXRjaAAAAAAAAAEAAAAEAAAAAQAAADtTdWJtaXR0ZWQgcHJvb2Ygb2Ygb25lIG9wdGltaXN0aWMgc3RlcDogKHNlc3Npb25faWQsIHN0ZXApLgAAAAAJU3RlcFByb29mAAAAAAAAAgAAAAQAAAAEAAAAAQAAAD1BIHNlc3Npb24ncyBHYW1lIEh1YiBub3RpZmljYXRpb25zIHRoYXQgZmFpbGVkLCBvbGRlc3QgZmlyc3QuAAAAAAAAClBlbmRpbmdIdWIAAAAAAAEAAAAE",
+        "AAAAAgAAACVJbnRlcmZhY2Ugb2YgdGhlIGNvbmZpZ3VyZWQgR2FtZSBIdWIuAAAAAAAAAAAAAAxIdWJJbnRlcmZhY2UAAAACAAAAAAAAAJxgc3RhcnRfZ2FtZWAgYW5kIGBlbmRfZ2FtZShzZXNzaW9uX2lkLCBwbGF5ZXIxX3dvbilgLCBhcyBkZXBsb3llZCBodWJzCmFuZCBgbW9jay1nYW1lLWh1YmAgaW1wbGVtZW50LiBUZWFtIGdhbWVzIGFyZSByZXBvcnRlZCB3aXRoIGVhY2gKdGVhbSdzIGZpcnN0IG1lbWJlci4AAAACVjEAAAAAAAAAAACAYGNvbnRyYWN0cy9nYW1lLWh1YmA6IHNlc3Npb25zIGFyZSBrZXllZCBieSBnYW1lLCBzbyBgZW5kX2dhbWVgIHRha2VzCmBnYW1lX2lkYCB0b28sIGFuZCBgc3RhcnRfdGVhbV9nYW1lYCByZXBvcnRzIGZ1bGwgcm9zdGVycy4AAAACVjIAAA==",
+        "AAAAAgAAACZBIEdhbWUgSHViIGNhbGwgd2FpdGluZyB0byBiZSByZXRyaWVkLgAAAAAAAAAAAA9IdWJOb3RpZmljYXRpb24AAAAAAwAAAAEAAAArYHN0YXJ0X2dhbWUoc2Vzc2lvbl9pZCwgcGxheWVyMSwgcGxheWVyMilgLgAAAAAFU3RhcnQAAAAAAAADAAAABAAAABMAAAATAAAAAQAAAGdgc3RhcnRfdGVhbV9nYW1lKHNlc3Npb25faWQsIHRlYW0xLCB0ZWFtMilgLCBvciBgc3RhcnRfZ2FtZWAgd2l0aCB0aGUKdGVhbXMnIGZpcnN0IG1lbWJlcnMgb24gYSBWMSBodWIuAAAAAApTdGFydFRlYW1zAAAAAAADAAAABAAAA+oAAAATAAAD6gAAABMAAAABAAAARmBlbmRfZ2FtZShzZXNzaW9uX2lkLCBwbGF5ZXIxX3dvbilgLCB3aXRoIGBnYW1lX2lkYCBmaXJzdCBvbiBhIFYyIGh1Yi4AAAAAAANFbmQAAAAAAgAAAAQAAAAB",
         "AAAAAAAAACZJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIGFuIGFkbWluLgAAAAAADV9fY29uc3RydWN0b3IAAAAAAAABAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAA",
         "AAAAAAAAADRTZXQgdmVyaWZpY2F0aW9uIGtleXMgKGNhbGxlZCBwb3N0LWRlcGxveSBieSBhZG1pbikuAAAAB3NldF92a3MAAAAAAgAAAAAAAAAHbW92ZV92awAAAAAOAAAAAAAAAAlzZWFyY2hfdmsAAAAAAAAOAAAAAA==",
-        "AAAAAAAAAIRTZXQgdGhlIEdhbWUgSHViIGNvbnRyYWN0IGFkZHJlc3MgKGNhbGxlZCBwb3N0LWRlcGxveSBieSBhZG1pbikuCklmIG5vdCBzZXQsIEdhbWVIdWIgbm90aWZpY2F0aW9ucyBhcmUgc2lsZW50bHkgc2tpcHBlZCAobG9jYWwgZGV2KS4AAAAMc2V0X2dhbWVfaHViAAAAAQAAAAAAAAAIZ2FtZV9odWIAAAATAAAAAA==",
+        "AAAAAAAAAFBTZXQgdGhlIHZlcmlmaWNhdGlvbiBrZXkgb2YgYW4gYWRkaXRpb25hbCBjaXJjdWl0IChjYWxsZWQgcG9zdC1kZXBsb3kgYnkgYWRtaW4pLgAAAAZzZXRfdmsAAAAAAAIAAAAAAAAAB2NpcmN1aXQAAAAH0AAAAAdDaXJjdWl0AAAAAAAAAAACdmsAAAAAAA4AAAAA",
+        "AAAAAAAAAMNTZXQgdGhlIEdhbWUgSHViIGNvbnRyYWN0IGFkZHJlc3MgKGNhbGxlZCBwb3N0LWRlcGxveSBieSBhZG1pbikuClRoZSBodWIgbXVzdCBoYXZlIHRoZSBzdGFuZGFyZCBpbnRlcmZhY2UgKGBIdWJJbnRlcmZhY2U6OlYxYCkuCklmIG5vdCBzZXQsIEdhbWVIdWIgbm90aWZpY2F0aW9ucyBhcmUgc2lsZW50bHkgc2tpcHBlZCAobG9jYWwgZGV2KS4AAAAADHNldF9nYW1lX2h1YgAAAAEAAAAAAAAACGdhbWVfaHViAAAAEwAAAAA=",
+        "AAAAAAAAAGlTZXQgYSBHYW1lIEh1YiB3aXRoIHRoZSBgY29udHJhY3RzL2dhbWUtaHViYCBpbnRlcmZhY2UKKGBIdWJJbnRlcmZhY2U6OlYyYCksIGNhbGxlZCBwb3N0LWRlcGxveSBieSBhZG1pbi4AAAAAAAAPc2V0X2dhbWVfaHViX3YyAAAAAAEAAAAAAAAACGdhbWVfaHViAAAAEwAAAAA=",
         "AAAAAAAAAC1DcmVhdGUgYSBuZXcgZ2FtZS4gQ2FsbGVyIGJlY29tZXMgdGhlIEh1bnRlci4AAAAAAAALY3JlYXRlX2dhbWUAAAAAAQAAAAAAAAAGaHVudGVyAAAAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAAMhDcmVhdGUgYSBuZXcgZ2FtZSB3aXRoIG9wdGlvbmFsIHJ1bGVzLiBDYWxsZXIgYmVjb21lcyB0aGUgSHVudGVyLgoKV2l0aCBgY29uZmlnLmNvbW1pdF9yZXZlYWxgLCBgc2VlZF9oYXNoYCBtdXN0IGJlIGBzaGEyNTYoc2VlZClgIGZvciBhCnNlY3JldCAzMi1ieXRlIHNlZWQgdGhlIGh1bnRlciByZXZlYWxzIGxhdGVyIHZpYSBgcmV2ZWFsX3NlZWRgLgAAABdjcmVhdGVfZ2FtZV93aXRoX2NvbmZpZwAAAAADAAAAAAAAAAZodW50ZXIAAAAAABMAAAAAAAAABmNvbmZpZwAAAAAH0AAAAApHYW1lQ29uZmlnAAAAAAAAAAAACXNlZWRfaGFzaAAAAAAAA+gAAAPuAAAAIAAAAAEAAAPpAAAABAAAAAM=",
         "AAAAAAAAABxQcmV5IGpvaW5zIGFuIGV4aXN0aW5nIGdhbWUuAAAACWpvaW5fZ2FtZQAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABHByZXkAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADtQcmV5IGpvaW5zIGEgY29tbWl0LXJldmVhbCBnYW1lLCBjb21taXR0aW5nIGBzaGEyNTYoc2VlZClgLgAAAAATam9pbl9nYW1lX3dpdGhfc2VlZAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAARwcmV5AAAAEwAAAAAAAAAJc2VlZF9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAJJBIHRlYW0gcGFydG5lciB0YWtlcyBhIHNlYXQgYmVmb3JlIHRoZSBtYXRjaCBzdGFydHM6IHRlYW0gMSBwbGF5cyB3aXRoCnBsYXllciAxLCB0ZWFtIDIgd2l0aCBwbGF5ZXIgMi4gVGhlIG1hdGNoIHN0YXJ0cyBvbmNlIGV2ZXJ5IHNlYXQgaXMgZmlsbGVkLgAAAAAACWpvaW5fdGVhbQAAAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAEdGVhbQAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAHRSZXZlYWwgYSBjb21taXR0ZWQgc2VlZC4gT25jZSBib3RoIHNlZWRzIGFyZSBpbiwgdGhlIG1hdGNoIHNlZWQgcGlja3MKdGhlIG1hcCBhbmQgc3Bhd25zIGFuZCB0aGUgZmlyc3Qgcm91bmQgc3RhcnRzLgAAAAtyZXZlYWxfc2VlZAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABHNlZWQAAAPuAAAAIAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAOtDbGFpbSB0aGUgbWF0Y2ggd2hlbiB0aGUgb3Bwb25lbnQgZmFpbGVkIHRvIHJldmVhbCB0aGVpciBzZWVkIGluIHRpbWUuCk9ubHkgYSBwbGF5ZXIgd2hvIHJldmVhbGVkIGNhbiBjbGFpbTsgdGhleSB3aW4gdGhlIG1hdGNoIG91dHJpZ2h0LgpJZiBuZWl0aGVyIHBsYXllciByZXZlYWxlZCwgZWl0aGVyIG9uZSBjYW4gY2FsbCBpdCB0byBjYW5jZWwgdGhlIG1hdGNoLAp3aGljaCBlbmRzIGFzIGEgMC0wIGRyYXcuAAAAABRjbGFpbV9yZXZlYWxfdGltZW91dAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAIBCYW4gYSBtYXAgZnJvbSB0aGUgZHJhZnQgcG9vbC4gUGxheWVycyBhbHRlcm5hdGUsIHBsYXllcjIgZmlyc3QuCk9uY2Ugb25seSBgdG90YWxfcm91bmRzYCBtYXBzIHJlbWFpbiwgdGhleSBhcmUgcGxheWVkIGluIG9yZGVyLgAAAAdiYW5fbWFwAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAJbWFwX2luZGV4AAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAIdCYW4gYSByYW5kb20gbWFwIGZvciBhIHBsYXllciB3aG8gbGV0IHRoZWlyIGRyYWZ0IGRlYWRsaW5lIHBhc3MuCkNhbGxhYmxlIGJ5IGFueW9uZSBzbyBhIHN0YWxsaW5nIHBsYXllciBjYW5ub3QgaG9sZCB0aGUgbWF0Y2ggaG9zdGFnZS4AAAAAD2ZvcmNlX2RyYWZ0X2JhbgAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
         "AAAAAAAAADNIdW50ZXIgbW92ZXMgdG8gYW4gYWRqYWNlbnQgdGlsZSAocHVibGljIG1vdmVtZW50KS4AAAAAC2h1bnRlcl9tb3ZlAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEJgaHVudGVyX21vdmVgIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAAAA5odW50ZXJfbW92ZV9hcwAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAAF4AAAAAAAABAAAAAAAAAABeQAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
         "AAAAAAAAADZIdW50ZXIgc2VhcmNoZXMgb25lIGFkamFjZW50IGp1bmdsZSB0aWxlIGZvciB0aGUgUHJleS4AAAAAAA1odW50ZXJfc2VhcmNoAAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAABeAAAAAAAAAQAAAAAAAAAAXkAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAERgaHVudGVyX3NlYXJjaGAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAABBodW50ZXJfc2VhcmNoX2FzAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAAF4AAAAAAAABAAAAAAAAAABeQAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAH9IdW50ZXIgbW92ZXMsIHRoZW4gc2VhcmNoZXMgb25lIGp1bmdsZSB0aWxlIGFkamFjZW50IHRvIHRoZSBuZXcgcG9zaXRpb24sCmFzIGEgc2luZ2xlIHR1cm4gKHJlcXVpcmVzIHRoZSBgY29tcG91bmRfdHVybmAgcnVsZSkuAAAAABZodW50ZXJfbW92ZV9hbmRfc2VhcmNoAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAF4AAAAAAAABAAAAAAAAAABeQAAAAAAAAQAAAAAAAAAAnN4AAAAAAAEAAAAAAAAAAJzeQAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAE1gaHVudGVyX21vdmVfYW5kX3NlYXJjaGAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAAAABlodW50ZXJfbW92ZV9hbmRfc2VhcmNoX2FzAAAAAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAAF4AAAAAAAABAAAAAAAAAABeQAAAAAAAAQAAAAAAAAAAnN4AAAAAAAEAAAAAAAAAAJzeQAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
         "AAAAAAAAAExIdW50ZXIgdXNlcyBwb3dlciBzZWFyY2ggdG8gc2VhcmNoIEFMTCBhZGphY2VudCBqdW5nbGUgdGlsZXMgKGxpbWl0ZWQgdXNlcykuAAAAE2h1bnRlcl9wb3dlcl9zZWFyY2gAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEpgaHVudGVyX3Bvd2VyX3NlYXJjaGAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAAAFmh1bnRlcl9wb3dlcl9zZWFyY2hfYXMAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAcRIdW50ZXIgZW50ZXJzIGp1bmdsZSBmcm9tIGEgdmlzaWJsZSBwb3NpdGlvbiAoYmVjb21lcyBoaWRkZW4pLCB3aXRoIGEKYGp1bmdsZV9lbnRlcmAgcHJvb2YgdGhhdCB0aGUgbmV3IGNvbW1pdG1lbnQgaXMgYSBqdW5nbGUgdGlsZSBuZXh0IHRvCnRoZSBodW50ZXIncyBwdWJsaWMgcG9zaXRpb24uIFJlcXVpcmVzIHRoZSBgaGlkZGVuX2h1bnRlcmAgcnVsZS4KClByb29mIGJsb2IgbGF5b3V0IChhZnRlciA0LWJ5dGUgbnVtX2ZpZWxkcyBoZWFkZXIpOgpieXRlcyA0Li4zNjogICAgeCAodTggaW4gbGFzdCBieXRlKQpieXRlcyAzNi4uNjg6ICAgeSAodTggaW4gbGFzdCBieXRlKQpieXRlcyA2OC4uMTAwOiAgbmV3X2NvbW1pdG1lbnQKYnl0ZXMgMTAwLi4xMzI6IG1hcF9pZCAodTggaW4gbGFzdCBieXRlKQpieXRlcyAxMzIuLjE2NDogYnVybmVkIHRpbGVzICh1NjQgaW4gbGFzdCA4IGJ5dGVzKQAAABNodW50ZXJfZW50ZXJfanVuZ2xlAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAADm5ld19jb21taXRtZW50AAAAAAPuAAAAIAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEpgaHVudGVyX2VudGVyX2p1bmdsZWAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAAAFmh1bnRlcl9lbnRlcl9qdW5nbGVfYXMAAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAAObmV3X2NvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAE5IaWRkZW4gSHVudGVyIG1vdmVzIHdpdGhpbiBqdW5nbGUgKGhpZGRlbiB0byBoaWRkZW4pLCBwcm92ZW4gYnkgYGp1bmdsZV9tb3ZlYC4AAAAAABJodW50ZXJfbW92ZV9qdW5nbGUAAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAADm5ld19jb21taXRtZW50AAAAAAPuAAAAIAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAElgaHVudGVyX21vdmVfanVuZ2xlYCBzaWduZWQgYnkgYHNpZ25lcmA6IHRoZSBwbGF5ZXIgb3IgdGhlaXIgc2Vzc2lvbiBrZXkuAAAAAAAAFWh1bnRlcl9tb3ZlX2p1bmdsZV9hcwAAAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAAObmV3X2NvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAS1IaWRkZW4gSHVudGVyIGV4aXRzIGp1bmdsZSBvbnRvIHBsYWlucyAocmV2ZWFscyBwb3NpdGlvbiwgYmVjb21lcyB2aXNpYmxlKSwKd2l0aCBhIGBqdW5nbGVfZXhpdGAgcHJvb2YgdGhhdCAoeCwgeSkgaXMgd2l0aGluIG9uZSBzdGVwIG9mIGl0cyBjb21taXRtZW50LgoKUHJvb2YgYmxvYiBsYXlvdXQgKGFmdGVyIDQtYnl0ZSBudW1fZmllbGRzIGhlYWRlcik6CmJ5dGVzIDQuLjM2OiAgIGNvbW1pdG1lbnQKYnl0ZXMgMzYuLjY4OiAgeCAodTggaW4gbGFzdCBieXRlKQpieXRlcyA2OC4uMTAwOiB5ICh1OCBpbiBsYXN0IGJ5dGUpAAAAAAAAEmh1bnRlcl9leGl0X2p1bmdsZQAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAABeAAAAAAAAAQAAAAAAAAAAXkAAAAAAAAEAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAElgaHVudGVyX2V4aXRfanVuZ2xlYCBzaWduZWQgYnkgYHNpZ25lcmA6IHRoZSBwbGF5ZXIgb3IgdGhlaXIgc2Vzc2lvbiBrZXkuAAAAAAAAFWh1bnRlcl9leGl0X2p1bmdsZV9hcwAAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAABeAAAAAAAAAQAAAAAAAAAAXkAAAAAAAAEAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAP1IaWRkZW4gSHVudGVyIHNlYXJjaGVzIHVwIHRvIDkganVuZ2xlIHRpbGVzLCBwcm92aW5nIHdpdGggYQpgaHVudGVyX2FkamFjZW5jeWAgcHJvb2YgdGhhdCB0aGV5IHN1cnJvdW5kIGl0cyBjb21taXR0ZWQgcG9zaXRpb24uCk9uZSB0aWxlIGNvc3RzIGEgc2VhcmNoLCBtb3JlIGNvc3QgYSBwb3dlciBzZWFyY2guCgpQcm9vZiBibG9iIGxheW91dCBtYXRjaGVzIGByZXNwb25kX3NlYXJjaGAsIHdpdGggdGhlIGh1bnRlcidzIGNvbW1pdG1lbnQuAAAAAAAAFGh1bnRlcl9zZWFyY2hfaGlkZGVuAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHdGlsZXNfeAAAAAPqAAAABAAAAAAAAAAHdGlsZXNfeQAAAAPqAAAABAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEtgaHVudGVyX3NlYXJjaF9oaWRkZW5gIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAAF2h1bnRlcl9zZWFyY2hfaGlkZGVuX2FzAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAAHdGlsZXNfeAAAAAPqAAAABAAAAAAAAAAHdGlsZXNfeQAAAAPqAAAABAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAIZIdW50ZXIgcGluZ3MgYSBNYW5oYXR0YW4gcmFkaXVzIGFyb3VuZCB0aGVpciBwb3NpdGlvbjsgdGhlIFByZXkgbXVzdAphbnN3ZXIgd2l0aCBhIHByb29mIG9mIHdoZXRoZXIgdGhleSBhcmUgaW5zaWRlIGl0IChsaW1pdGVkIHVzZXMpLgAAAAAADGh1bnRlcl9zb25hcgAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnJhZGl1cwAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAENgaHVudGVyX3NvbmFyYCBzaWduZWQgYnkgYHNpZ25lcmA6IHRoZSBwbGF5ZXIgb3IgdGhlaXIgc2Vzc2lvbiBrZXkuAAAAAA9odW50ZXJfc29uYXJfYXMAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAAZyYWRpdXMAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAIhIdW50ZXIgZmlyZXMgYSBmbGFyZSBvdmVyIGFuIGFyYml0cmFyeSBzZXQgb2YgdGlsZXMgZ2l2ZW4gYXMgYSBiaXRtYXNrCihiaXQgYHkgKiA4ICsgeGAsIGF0IG1vc3QgYE1BWF9GTEFSRV9USUxFU2AgdGlsZXMsIGxpbWl0ZWQgdXNlcykuAAAADGh1bnRlcl9mbGFyZQAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABG1hc2sAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAENgaHVudGVyX2ZsYXJlYCBzaWduZWQgYnkgYHNpZ25lcmA6IHRoZSBwbGF5ZXIgb3IgdGhlaXIgc2Vzc2lvbiBrZXkuAAAAAA9odW50ZXJfZmxhcmVfYXMAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAARtYXNrAAAABgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAG5IdW50ZXIgaGlkZXMgYSB0cmFwIGFzIGBzaGEyNTYoeCB8fCB5IHx8IHNhbHQpYCAodXAgdG8gYE1BWF9UUkFQU2AgcGVyIHJvdW5kKS4KRG9lcyBub3QgdXNlIHRoZSBodW50ZXIncyB0dXJuLgAAAAAAEWh1bnRlcl9wbGFjZV90cmFwAAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEhgaHVudGVyX3BsYWNlX3RyYXBgIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAUaHVudGVyX3BsYWNlX3RyYXBfYXMAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAUVIdW50ZXIgcmV2ZWFscyBhIHRyYXAuIElmIHRoZSBwcmV5J3MgcHVibGljIHRyYWlsIGNyb3NzZWQgdGhlIHRyYXAgdGlsZQpzaW5jZSBpdCB3YXMgcGxhY2VkLCB0aGUgaHVudGVyIHdpbnMgdGhlIHJvdW5kLiBSZXR1cm5zIHdoZXRoZXIgaXQgc3ByYW5nLgpBIGhpZGRlbiBwcmV5IG11c3QgdGhlbiBwcm92ZSBpdCBpcyBub3Qgb24gdGhlIHRpbGUgKGBwcmV5X3Byb3ZlX3RyYXBzYCkKYmVmb3JlIGFjdGluZyBhZ2Fpbi4gVHJhcHMgY2FuIGFsc28gYmUgcmV2ZWFsZWQgb25jZSB0aGUgcHJleSBoYXMKc3Vydml2ZWQgdGhlIHR1cm4gbGltaXQgKGBUcmFwUmV2ZWFsYCkuAAAAAAAAEmh1bnRlcl9yZXZlYWxfdHJhcAAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAFaW5kZXgAAAAAAAAEAAAAAAAAAAF4AAAAAAAABAAAAAAAAAABeQAAAAAAAAQAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAEAAAPpAAAAAQAAAAM=",
+        "AAAAAAAAAElgaHVudGVyX3JldmVhbF90cmFwYCBzaWduZWQgYnkgYHNpZ25lcmA6IHRoZSBwbGF5ZXIgb3IgdGhlaXIgc2Vzc2lvbiBrZXkuAAAAAAAAFWh1bnRlcl9yZXZlYWxfdHJhcF9hcwAAAAAAAAYAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAAFaW5kZXgAAAAAAAAEAAAAAAAAAAF4AAAAAAAABAAAAAAAAAABeQAAAAAAAAQAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAEAAAPpAAAAAQAAAAM=",
+        "AAAAAAAAATpIaWRkZW4gcHJleSBwcm92ZXMgd2l0aCBhIGBmbGFyZV9yZXNwb25zZWAgcHJvb2YgdGhhdCBpdCBpcyBvbiBub25lIG9mCnRoZSByZXZlYWxlZCB0cmFwIHRpbGVzIGluIGB0cmFwX2NoZWNrX21hc2tgLiBSZXF1aXJlZCBiZWZvcmUgYWN0aW5nCmFmdGVyIGEgdHJhcCBpcyByZXZlYWxlZDsgZG9lcyBub3QgdXNlIHRoZSB0dXJuLiBBbiBlbXB0eSBwcm9vZgpjb25jZWRlcyB0aGUgcm91bmQgKGNhdWdodCBpbiB0aGUgdHJhcCkuCgpTYW1lIHByb29mIGJsb2IgbGF5b3V0IGFzIGByZXNwb25kX2ZsYXJlYCwgd2l0aCB0aGUgdHJhcCBjaGVjayBtYXNrLgAAAAAAEHByZXlfcHJvdmVfdHJhcHMAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEdgcHJleV9wcm92ZV90cmFwc2Agc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAATcHJleV9wcm92ZV90cmFwc19hcwAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAOdTY29yZSBhIHJvdW5kIHdhaXRpbmcgaW4gYFRyYXBSZXZlYWxgLiBUaGUgaHVudGVyIGNhbiBkbyBpdCBhdCBhbnkgdGltZQp0byBza2lwIHJldmVhbGluZyBpdHMgcmVtYWluaW5nIHRyYXBzOyBhZnRlciB0aGUgZGVhZGxpbmUgYW55b25lIGNhbi4KSWYgdGhlIHByZXkgbGVmdCBhIHRyYXAgY2hlY2sgdW5hbnN3ZXJlZCBwYXN0IHRoZSBkZWFkbGluZSwgdGhlIHJvdW5kCmdvZXMgdG8gdGhlIGh1bnRlci4AAAAAEWNsb3NlX3RyYXBfcmV2ZWFsAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEhgY2xvc2VfdHJhcF9yZXZlYWxgIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAUY2xvc2VfdHJhcF9yZXZlYWxfYXMAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAItIaWRkZW4gcHJleSByZWdpc3RlcnMgYSBkZWNveSBjb21taXRtZW50IHRoYXQgbGFzdHMgYERFQ09ZX0xJRkVUSU1FX1RVUk5TYAp0dXJucyAodXAgdG8gYE1BWF9ERUNPWVNgIGFjdGl2ZSkuIERvZXMgbm90IHVzZSB0aGUgcHJleSdzIHR1cm4uAAAAABBwcmV5X3BsYWNlX2RlY295AAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEdgcHJleV9wbGFjZV9kZWNveWAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAATcHJleV9wbGFjZV9kZWNveV9hcwAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAJVIdW50ZXIgYnVybnMgYW4gYWRqYWNlbnQganVuZ2xlIHRpbGUgdG8gcGxhaW5zIGZvciB0aGUgcmVzdCBvZiB0aGUgcm91bmQuCklmIHRoZSBwcmV5IGlzIGhpZGRlbiBpdCBtdXN0IHByb3ZlIGl0IGlzIG5vdCBvbiB0aGUgdGlsZSAoYHJlc3BvbmRfYnVybmApLgAAAAAAAAtodW50ZXJfYnVybgAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAF4AAAAAAAABAAAAAAAAAABeQAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEJgaHVudGVyX2J1cm5gIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAAAA5odW50ZXJfYnVybl9hcwAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAAF4AAAAAAAABAAAAAAAAAABeQAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAALdIaWRkZW4gcHJleSBhbnN3ZXJzIGEgYnVybiB3aXRoIGEgYHNlYXJjaF9yZXNwb25zZWAgcHJvb2YgdGhhdCBpdCBpcyBub3QKb24gdGhlIGJ1cm5lZCB0aWxlLiBBbiBlbXB0eSBwcm9vZiByZXZlYWxzIHRoZSBwcmV5IG9uIHRoYXQgdGlsZS4KClNhbWUgcHJvb2YgYmxvYiBsYXlvdXQgYXMgYHJlc3BvbmRfc2VhcmNoYC4AAAAADHJlc3BvbmRfYnVybgAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAENgcmVzcG9uZF9idXJuYCBzaWduZWQgYnkgYHNpZ25lcmA6IHRoZSBwbGF5ZXIgb3IgdGhlaXIgc2Vzc2lvbiBrZXkuAAAAAA9yZXNwb25kX2J1cm5fYXMAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAaJQcmV5IHByb3ZlcyB0aGUgY29tbWl0bWVudCBpdCBpcyBhYm91dCB0byBtb3ZlIHRvIChgY29tbWl0bWVudGApIGlzCmluc2lkZSB0aGlzIHR1cm4ncyBzYWZlIHpvbmUuIE9uY2UgdGhlIHpvbmUgc3RhcnRzIHNocmlua2luZywgZXZlcnkKaGlkZGVuIG1vdmUgKGFuZCBlbnRlcmluZyBqdW5nbGUpIG11c3QgZW5kIG9uIGEgY29tbWl0bWVudCBwcm92ZW4gdGhpcwp0dXJuLCBzbyB0aGUgcHJleSBuZXZlciBlbmRzIGEgdHVybiBoaWRkZW4gb3V0c2lkZSB0aGUgem9uZS4gQW4gZW1wdHkKcHJvb2YgY29uY2VkZXMgdGhlIHJvdW5kLgoKUHJvb2YgYmxvYiBsYXlvdXQgKGFmdGVyIDQtYnl0ZSBudW1fZmllbGRzIGhlYWRlcik6CmJ5dGVzIDQuLjM2OiAgY29tbWl0bWVudApieXRlcyAzNi4uNjg6IGluc2V0ICh1OCBpbiBsYXN0IGJ5dGUpAAAAAAAPcHJleV9wcm92ZV96b25lAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEZgcHJleV9wcm92ZV96b25lYCBzaWduZWQgYnkgYHNpZ25lcmA6IHRoZSBwbGF5ZXIgb3IgdGhlaXIgc2Vzc2lvbiBrZXkuAAAAAAAScHJleV9wcm92ZV96b25lX2FzAAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAbBIaWRkZW4gcHJleSByZXZlYWxzIHRoZSB0aWxlIGl0IGhlbGQgYHNjZW50X2RlbGF5YCBtb3ZlcyBhZ28sIHdpdGggYQpgc2NlbnRfcmV2ZWFsYCBwcm9vZiBjaGFpbmluZyB0aGF0IGNvbW1pdG1lbnQgdG8gaXRzIGN1cnJlbnQgb25lLgpSZXF1aXJlZCBiZWZvcmUgYWN0aW5nIG9uY2UgYSBzY2VudCBpcyBkdWU7IGRvZXMgbm90IHVzZSB0aGUgdHVybi4KClByb29mIGJsb2IgbGF5b3V0IChhZnRlciA0LWJ5dGUgbnVtX2ZpZWxkcyBoZWFkZXIpOgpieXRlcyA0Li4zNjogICAgb2xkX2NvbW1pdG1lbnQKYnl0ZXMgMzYuLjY4OiAgIGN1cnJlbnRfY29tbWl0bWVudApieXRlcyA2OC4uMTAwOiAgeCAodTggaW4gbGFzdCBieXRlKQpieXRlcyAxMDAuLjEzMjogeSAodTggaW4gbGFzdCBieXRlKQpieXRlcyAxMzIuLjE2NDogc3RlcHMgKHU4IGluIGxhc3QgYnl0ZSkAAAARcHJleV9yZXZlYWxfc2NlbnQAAAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAF4AAAAAAAABAAAAAAAAAABeQAAAAAAAAQAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEhgcHJleV9yZXZlYWxfc2NlbnRgIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAUcHJleV9yZXZlYWxfc2NlbnRfYXMAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAQ9IaWRkZW4gcHJleSBwcm92ZXMgaXQgaXMgb24gb25lIG9mIHRoZSBtYXAncyBleHRyYWN0aW9uIHRpbGVzIGFuZCB3aW5zCnRoZSByb3VuZCAoZXNjYXBlIG1vZGUpLiBWaXNpYmxlIHByZXkgZXh0cmFjdCBieSBlbmRpbmcgYSB0dXJuIG9uIG9uZS4KClByb29mIGJsb2IgbGF5b3V0IChhZnRlciA0LWJ5dGUgbnVtX2ZpZWxkcyBoZWFkZXIpOgpieXRlcyA0Li4zNjogIGNvbW1pdG1lbnQKYnl0ZXMgMzYuLjY4OiBleHRyYWN0aW9uIG1hc2sgKHU2NCBpbiBsYXN0IDggYnl0ZXMpAAAAABNwcmV5X2V4dHJhY3RfaGlkZGVuAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEpgcHJleV9leHRyYWN0X2hpZGRlbmAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAAAFnByZXlfZXh0cmFjdF9oaWRkZW5fYXMAAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAANtQcmV5IGNvbW1pdHMgdG8gd2hpY2ggYGl0ZW1fY291bnRgIG9mIHRoZSByb3VuZCdzIGNhbmRpZGF0ZSB0aWxlcyBob2xkCml0ZW1zOiBgY29tbWl0bWVudCA9IGhhc2gobWFzaywgc2FsdClgIGZvciBhIHNlY3JldCB0aWxlIG1hc2sgYW5kIHNhbHQuClJlcXVpcmVkIGJlZm9yZSB0aGUgcHJleSdzIGZpcnN0IGFjdGlvbiBvZiB0aGUgcm91bmQ7IGRvZXMgbm90IHVzZQppdHMgdHVybi4AAAAAEXByZXlfY29tbWl0X2l0ZW1zAAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEhgcHJleV9jb21taXRfaXRlbXNgIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAUcHJleV9jb21taXRfaXRlbXNfYXMAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAjlIaWRkZW4gcHJleSBjbGFpbXMgYW4gaXRlbSBieSBwcm92aW5nIGl0IHN0YW5kcyBvbiBvbmUgb2YgaXRzCmNvbW1pdHRlZCBpdGVtIHRpbGVzLCB3aXRob3V0IHJldmVhbGluZyB3aGljaC4gYG51bGxpZmllcmAgaXMgdW5pcXVlCnBlciBpdGVtIHRpbGUsIHNvIGVhY2ggaXRlbSBpcyBjbGFpbWVkIG9uY2UgKGBJdGVtQWxyZWFkeUNsYWltZWRgKS4KUHVibGlzaGVzIGBJdGVtQ29sbGVjdGVkYCB3aXRoIHRoZSBudWxsaWZpZXIgb2YgdGhlIGl0ZW0gdGFrZW47CmNvbGxlY3RpbmcgYGl0ZW1zX3JlcXVpcmVkYCB3aW5zIHRoZSByb3VuZC4gRG9lcyBub3QgdXNlIHRoZSBwcmV5J3MgdHVybi4KClByb29mIGJsb2IgbGF5b3V0IChhZnRlciA0LWJ5dGUgbnVtX2ZpZWxkcyBoZWFkZXIpOgpieXRlcyA0Li4zNjogICAgY29tbWl0bWVudApieXRlcyAzNi4uNjg6ICAgY2FuZGlkYXRlcyAodTY0IGluIGxhc3QgOCBieXRlcykKYnl0ZXMgNjguLjEwMDogIGl0ZW1fY291bnQgKHU4IGluIGxhc3QgYnl0ZSkKYnl0ZXMgMTAwLi4xMzI6IGl0ZW1zX2NvbW1pdG1lbnQKYnl0ZXMgMTMyLi4xNjQ6IG51bGxpZmllcgAAAAAAAA9wcmV5X2NsYWltX2l0ZW0AAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAJbnVsbGlmaWVyAAAAAAAD7gAAACAAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEZgcHJleV9jbGFpbV9pdGVtYCBzaWduZWQgYnkgYHNpZ25lcmA6IHRoZSBwbGF5ZXIgb3IgdGhlaXIgc2Vzc2lvbiBrZXkuAAAAAAAScHJleV9jbGFpbV9pdGVtX2FzAAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAACW51bGxpZmllcgAAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
         "AAAAAAAAADNQcmV5IG1vdmVzIHB1YmxpY2x5IG9uIHBsYWlucyAodmlzaWJsZSB0byB2aXNpYmxlKS4AAAAAEHByZXlfbW92ZV9wdWJsaWMAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAF4AAAAAAAABAAAAAAAAAABeQAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEdgcHJleV9tb3ZlX3B1YmxpY2Agc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAATcHJleV9tb3ZlX3B1YmxpY19hcwAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
         "AAAAAAAAADxQcmV5IGVudGVycyBqdW5nbGUgZnJvbSBhIHZpc2libGUgcG9zaXRpb24gKGJlY29tZXMgaGlkZGVuKS4AAAARcHJleV9lbnRlcl9qdW5nbGUAAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAA5uZXdfY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAACxQcmV5IG1vdmVzIHdpdGhpbiBqdW5nbGUgKGhpZGRlbiB0byBoaWRkZW4pLgAAABBwcmV5X21vdmVfanVuZ2xlAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAObmV3X2NvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEhgcHJleV9lbnRlcl9qdW5nbGVgIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAUcHJleV9lbnRlcl9qdW5nbGVfYXMAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAADm5ld19jb21taXRtZW50AAAAAAPuAAAAIAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAVZQcmV5IGNvbW1pdHMgdG8gYSBoaWRkZW4gc3Bhd24gYXQgdGhlIHN0YXJ0IG9mIGEgaGlkZGVuLXNwYXduIHJvdW5kLgoKUHJvb2YgYmxvYiBsYXlvdXQgKGFmdGVyIDQtYnl0ZSBudW1fZmllbGRzIGhlYWRlcik6CmJ5dGVzIDQuLjM2OiAgICBjb21taXRtZW50ICgzMiBieXRlcywgRmllbGQpCmJ5dGVzIDM2Li42ODogICBtYXBfaWQgKHU4IGluIGxhc3QgYnl0ZSkKYnl0ZXMgNjguLjEwMDogIGh1bnRlcl94ICh1OCBpbiBsYXN0IGJ5dGUpCmJ5dGVzIDEwMC4uMTMyOiBodW50ZXJfeSAodTggaW4gbGFzdCBieXRlKQpieXRlcyAxMzIuLjE2NDogZXh0cmFjdGlvbiAodTY0IGluIGxhc3QgOCBieXRlcykAAAAAABFwcmV5X3NwYXduX2hpZGRlbgAAAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEhgcHJleV9zcGF3bl9oaWRkZW5gIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAUcHJleV9zcGF3bl9oaWRkZW5fYXMAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAKBQcmV5IG1vdmVzIHdpdGhpbiBqdW5nbGUgKGhpZGRlbiB0byBoaWRkZW4pLgpJbiBhbiBgb3B0aW1pc3RpY2AgbWF0Y2ggYHByb29mYCBpcyBub3QgY2hlY2tlZCAocGFzcyBpdCBlbXB0eSk7IHRoZQptb3ZlIGlzIHByb3ZlbiB3aXRoIHRoZSByb3VuZCdzIGJhdGNoIGluc3RlYWQuAAAAEHByZXlfbW92ZV9qdW5nbGUAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAA5uZXdfY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEdgcHJleV9tb3ZlX2p1bmdsZWAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAATcHJleV9tb3ZlX2p1bmdsZV9hcwAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAADm5ld19jb21taXRtZW50AAAAAAPuAAAAIAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAJdQcmV5IGRhc2hlcyB1cCB0byAyIHRpbGVzIHRocm91Z2gganVuZ2xlIHdoaWxlIGhpZGRlbiAoY29uc3VtZXMgYSBkYXNoKS4KU2FtZSBwdWJsaWMgaW5wdXRzIGFzIGBwcmV5X21vdmVfanVuZ2xlYCwgcHJvdmVuIGJ5IHRoZSBganVuZ2xlX2Rhc2hgIGNpcmN1aXQuAAAAABBwcmV5X2Rhc2hfanVuZ2xlAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAObmV3X2NvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEdgcHJleV9kYXNoX2p1bmdsZWAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAATcHJleV9kYXNoX2p1bmdsZV9hcwAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAADm5ld19jb21taXRtZW50AAAAAAPuAAAAIAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
         "AAAAAAAAADZQcmV5IGV4aXRzIGp1bmdsZSAocmV2ZWFscyBwb3NpdGlvbiwgYmVjb21lcyB2aXNpYmxlKS4AAAAAABBwcmV5X2V4aXRfanVuZ2xlAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAABeAAAAAAAAAQAAAAAAAAAAXkAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEdgcHJleV9leGl0X2p1bmdsZWAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAATcHJleV9leGl0X2p1bmdsZV9hcwAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
         "AAAAAAAAAfBQcmV5IHJlc3BvbmRzIHRvIGEgc2VhcmNoIHdpdGggYSBzaW5nbGUgYmF0Y2hlZCBaSyBwcm9vZiBvZiBub24tcHJlc2VuY2UuCgpUaGUgcHJvb2YncyBwdWJsaWMgaW5wdXRzIG11c3QgbWF0Y2ggdGhlIG9uLWNoYWluIGdhbWUgc3RhdGU6Ci0gY29tbWl0bWVudCBtdXN0IGVxdWFsIGdhbWUucHJleV9jb21taXRtZW50Ci0gc2VhcmNoZWRfeC95IGFycmF5cyBtdXN0IG1hdGNoIGdhbWUuc2VhcmNoZWRfdGlsZXNfeC95IChwYWRkZWQgd2l0aCAyNTUgdG8gbGVuZ3RoIDkpCgpQcm9vZiBibG9iIGxheW91dCAoYWZ0ZXIgNC1ieXRlIG51bV9maWVsZHMgaGVhZGVyKToKYnl0ZXMgNC4uMzY6ICAgIGNvbW1pdG1lbnQgKDMyIGJ5dGVzLCBGaWVsZCkKYnl0ZXMgMzYuLjMyNDogIHNlYXJjaGVkX3hbMC4uOV0gKDkgKiAzMiBieXRlcywgdTggaW4gbGFzdCBieXRlKQpieXRlcyAzMjQuLjYxMjogc2VhcmNoZWRfeVswLi45XSAoOSAqIDMyIGJ5dGVzLCB1OCBpbiBsYXN0IGJ5dGUpAAAADnJlc3BvbmRfc2VhcmNoAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAFJIdW50ZXIgdXNlcyBFTVAgdG8gZnJlZXplIHZpc2libGUgcHJleSBmb3IgMSB0dXJuIChnbG9iYWwgcmFuZ2UsIDEgdXNlIHBlciByb3VuZCkuAAAAAAAKaHVudGVyX2VtcAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAAEBGcm96ZW4gcHJleSBza2lwcyB0aGVpciB0dXJuIChjYWxsZWQgYXV0b21hdGljYWxseSBieSBmcm9udGVuZCkuAAAAEHByZXlfcGFzc19mcm96ZW4AAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAAEdQcmV5IGRhc2hlcyB1cCB0byAyIHRpbGVzIG9uIHBsYWlucyBpbiBhIHNpbmdsZSBtb3ZlICgyIHVzZXMgcGVyIHR1cm4pLgAAAAAQcHJleV9kYXNoX3B1YmxpYwAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAADdIdW50ZXIgY2xhaW1zIGNhdGNoIChwcmV5IGZhaWxlZCB0byByZXNwb25kIHRvIHNlYXJjaCkuAAAAAAtjbGFpbV9jYXRjaAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAEVgcmVzcG9uZF9zZWFyY2hgIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAAAAARcmVzcG9uZF9zZWFyY2hfYXMAAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAXVQcmV5IGFuc3dlcnMgYSBzb25hciBwaW5nIHdpdGggYSBaSyBwcm9vZiB0aGF0IHRoZWlyIGNvbW1pdHRlZCBwb3NpdGlvbgppcyAob3IgaXMgbm90KSB3aXRoaW4gYHNvbmFyX3JhZGl1c2Agb2YgdGhlIGh1bnRlci4KClByb29mIGJsb2IgbGF5b3V0IChhZnRlciA0LWJ5dGUgbnVtX2ZpZWxkcyBoZWFkZXIpOgpieXRlcyA0Li4zNjogICAgY29tbWl0bWVudApieXRlcyAzNi4uNjg6ICAgaHVudGVyX3ggKHU4IGluIGxhc3QgYnl0ZSkKYnl0ZXMgNjguLjEwMDogIGh1bnRlcl95ICh1OCBpbiBsYXN0IGJ5dGUpCmJ5dGVzIDEwMC4uMTMyOiByYWRpdXMgKHU4IGluIGxhc3QgYnl0ZSkKYnl0ZXMgMTMyLi4xNjQ6IGluc2lkZSAoYm9vbCBpbiBsYXN0IGJ5dGUpAAAAAAAADXJlc3BvbmRfc29uYXIAAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZpbnNpZGUAAAAAAAEAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAERgcmVzcG9uZF9zb25hcmAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAABByZXNwb25kX3NvbmFyX2FzAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAAZpbnNpZGUAAAAAAAEAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAOpQcmV5IHJlc3BvbmRzIHRvIGEgZmxhcmUgd2l0aCBhIFpLIHByb29mIHRoYXQgdGhleSBhcmUgb24gbm9uZSBvZiB0aGUgbGl0IHRpbGVzLgpBbiBlbXB0eSBwcm9vZiBjb25jZWRlcyB0aGUgcm91bmQuCgpQcm9vZiBibG9iIGxheW91dCAoYWZ0ZXIgNC1ieXRlIG51bV9maWVsZHMgaGVhZGVyKToKYnl0ZXMgNC4uMzY6ICBjb21taXRtZW50CmJ5dGVzIDM2Li42ODogbWFzayAodTY0IGluIGxhc3QgOCBieXRlcykAAAAAAA1yZXNwb25kX2ZsYXJlAAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAERgcmVzcG9uZF9mbGFyZWAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAABByZXNwb25kX2ZsYXJlX2FzAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAUBQcmV5IGFuc3dlcnMgYSBzZWFyY2ggd2l0aCBhIGRlY295IGluc3RlYWQ6IGEgYGRlY295X2Rpc2NhcmRgIHByb29mIHRoYXQKdGhlIGRlY295IGF0IGBpbmRleGAgc2l0cyBvbiBvbmUgb2YgdGhlIHNlYXJjaGVkIHRpbGVzLiBUaGUgZGVjb3kgYWJzb3Jicwp0aGUgc2VhcmNoLCB3aGV0aGVyIG9yIG5vdCB0aGUgcHJleSB3YXMgaGl0IHRvbzogaXQgaXMgZGlzY2FyZGVkIGFuZCB0aGUKc2VhcmNoIHJlc29sdmVkIGFzIGEgbWlzcy4KClNhbWUgcHJvb2YgYmxvYiBsYXlvdXQgYXMgYHJlc3BvbmRfc2VhcmNoYCwgd2l0aCB0aGUgZGVjb3kncyBjb21taXRtZW50LgAAABlyZXNwb25kX3NlYXJjaF93aXRoX2RlY295AAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAFaW5kZXgAAAAAAAAEAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAFBgcmVzcG9uZF9zZWFyY2hfd2l0aF9kZWNveWAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAABxyZXNwb25kX3NlYXJjaF93aXRoX2RlY295X2FzAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAAVpbmRleAAAAAAAAAQAAAAAAAAABXByb29mAAAAAAAADgAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAJdIdW50ZXIgdXNlcyBFTVAgdG8gZnJlZXplIHZpc2libGUgcHJleSBmb3IgYGVtcF9kdXJhdGlvbmAgdHVybnMgKDEgdXNlIHBlciByb3VuZCkuCldpdGggYW4gYGVtcF9yYW5nZWAgdGhlIHByZXkgbXVzdCBiZSB3aXRoaW4gdGhhdCBDaGVieXNoZXYgZGlzdGFuY2UuAAAAAApodW50ZXJfZW1wAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAEFgaHVudGVyX2VtcGAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAAAAA1odW50ZXJfZW1wX2FzAAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAI5Ta2lwIGEgZnJvemVuIHByZXkncyB0dXJuLiBGcm96ZW4gdHVybnMgYXJlIG5vcm1hbGx5IHNraXBwZWQgYXMgdGhlCmh1bnRlcidzIHR1cm4gZW5kczsgdGhpcyBzZXR0bGVzIG9uZSBsZWZ0IHBlbmRpbmcsIGFuZCBhbnlvbmUgbWF5IGNhbGwgaXQuAAAAAAAQcHJleV9wYXNzX2Zyb3plbgAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEhQcmV5IGRhc2hlcyB1cCB0byAyIHRpbGVzIG9uIHBsYWlucyBpbiBhIHNpbmdsZSBtb3ZlICgyIHVzZXMgcGVyIHJvdW5kKS4AAAAQcHJleV9kYXNoX3B1YmxpYwAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAEdgcHJleV9kYXNoX3B1YmxpY2Agc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAATcHJleV9kYXNoX3B1YmxpY19hcwAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAI5IdW50ZXIgY2xhaW1zIGNhdGNoIChwcmV5IGZhaWxlZCB0byByZXNwb25kIHRvIHNlYXJjaCkuIEEgZmxhcmUsIHNvbmFyCnBpbmcgb3IgYnVybiBsZWZ0IHVuYW5zd2VyZWQgcGFzdCBpdHMgZGVhZGxpbmUgaXMgY2xhaW1lZCB0aGUgc2FtZSB3YXkuAAAAAAALY2xhaW1fY2F0Y2gAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAEJgY2xhaW1fY2F0Y2hgIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAAAA5jbGFpbV9jYXRjaF9hcwAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAjxQcmV5IHJldmVhbHMgd2hlcmUgaXQgd2FzIGhpZGRlbiBkdXJpbmcgYSBmaW5pc2hlZCByb3VuZCAoYGF1ZGl0YCBydWxlKS4KRWFjaCByZXZlYWxlZCB0aWxlIChgeSAqIDggKyB4YCkgYW5kIG5vbmNlIG11c3Qgb3BlbiB0aGUgY29tbWl0bWVudApzdG9yZWQgZm9yIHRoYXQgdHVybiwgY2hlY2tlZCBieSBhIGByb3VuZF9hdWRpdGAgcHJvb2YuIEEgdGlsZSB0aGF0IGlzCm5vdCBqdW5nbGUgb24gdGhlIHJvdW5kJ3MgbWFwIGZhaWxzIHRoZSBhdWRpdDsgaW4gYSBzdGFrZWQgbWF0Y2ggdGhhdApmb3JmZWl0cyB0aGUgcm91bmQgYW5kIHRoZSBuZXh0IHJvdW5kIHN0YXJ0cyBlaXRoZXIgd2F5LgoKUHJvb2YgYmxvYiBsYXlvdXQgKGFmdGVyIDQtYnl0ZSBudW1fZmllbGRzIGhlYWRlcik6CmJ5dGVzIDQuLjM1NjogICAgIGNvbW1pdG1lbnRzWzAuLjExXSAodW51c2VkIHNsb3RzIDApCmJ5dGVzIDM1Ni4uNzA4OiAgIHBvc2l0aW9uc1swLi4xMV0gKHU4IGluIGxhc3QgYnl0ZSkKYnl0ZXMgNzA4Li4xMDYwOiAgbm9uY2VzWzAuLjExXQpieXRlcyAxMDYwLi4xMDkyOiBjb3VudCAodTggaW4gbGFzdCBieXRlKQAAAAxyZXZlYWxfcm91bmQAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAVyb3VuZAAAAAAAAAQAAAAAAAAACXBvc2l0aW9ucwAAAAAAA+oAAAAEAAAAAAAAAAZub25jZXMAAAAAA+oAAAPuAAAAIAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAENgcmV2ZWFsX3JvdW5kYCBzaWduZWQgYnkgYHNpZ25lcmA6IHRoZSBwbGF5ZXIgb3IgdGhlaXIgc2Vzc2lvbiBrZXkuAAAAAA9yZXZlYWxfcm91bmRfYXMAAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAAVyb3VuZAAAAAAAAAQAAAAAAAAACXBvc2l0aW9ucwAAAAAAA+oAAAAEAAAAAAAAAAZub25jZXMAAAAAA+oAAAPuAAAAIAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAIpUaGUgaHVudGVyIG9mIGEgc3Rha2VkIGF1ZGl0ZWQgcm91bmQgY2xhaW1zIGl0IGFmdGVyIHRoZSBwcmV5IG1pc3NlZAp0aGUgdHJhbnNjcmlwdCByZXZlYWwgZGVhZGxpbmUuIFRoZSByb3VuZCBpcyBmb3JmZWl0ZWQgdG8gdGhlIGh1bnRlci4AAAAAABNjbGFpbV9hdWRpdF90aW1lb3V0AAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEpgY2xhaW1fYXVkaXRfdGltZW91dGAgc2lnbmVkIGJ5IGBzaWduZXJgOiB0aGUgcGxheWVyIG9yIHRoZWlyIHNlc3Npb24ga2V5LgAAAAAAFmNsYWltX2F1ZGl0X3RpbWVvdXRfYXMAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAT5TdWJtaXQgdGhlIHByb29mIG9mIG9uZSBoaWRkZW4gbW92ZSBvZiBhIGZpbmlzaGVkIG9wdGltaXN0aWMgcm91bmQuCkVhY2ggcHJvb2YgaXMgc3RvcmVkIG9uIGl0cyBvd24sIGFzIGEgd2hvbGUgcm91bmQgb2YgdGhlbSB3b3VsZCBub3QKZml0IGluIG9uZSBlbnRyeSBvciB0cmFuc2FjdGlvbi4gT25seSBpdHMgcHVibGljIGlucHV0cyBhcmUgY2hlY2tlZApoZXJlOyB0aGUgcHJvb2YgaXRzZWxmIGlzIHZlcmlmaWVkIHdoZW4gdGhlIGh1bnRlciBjaGFsbGVuZ2VzIHRoZQpzdGVwLiBUaGUgbGFzdCBwcm9vZiBvcGVucyB0aGUgY2hhbGxlbmdlIHdpbmRvdy4AAAAAABFzdWJtaXRfc3RlcF9wcm9vZgAAAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABHN0ZXAAAAAEAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAEhgc3VibWl0X3N0ZXBfcHJvb2ZgIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAUc3VibWl0X3N0ZXBfcHJvb2ZfYXMAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAABHN0ZXAAAAAEAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAMdUaGUgaHVudGVyIGNoYWxsZW5nZXMgb25lIHN0ZXAgb2YgdGhlIHN1Ym1pdHRlZCBiYXRjaC4gSXRzIHByb29mIGlzCnZlcmlmaWVkIG5vdzsgaWYgaXQgZmFpbHMsIHRoZSByb3VuZCBpcyBmb3JmZWl0ZWQgdG8gdGhlIGh1bnRlciBhbmQgdGhlCm5leHQgcm91bmQgc3RhcnRzLiBSZXR1cm5zIHdoZXRoZXIgdGhlIGNoYWxsZW5nZSBzdWNjZWVkZWQuAAAAAA5jaGFsbGVuZ2Vfc3RlcAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAEc3RlcAAAAAQAAAABAAAD6QAAAAEAAAAD",
+        "AAAAAAAAAEVgY2hhbGxlbmdlX3N0ZXBgIHNpZ25lZCBieSBgc2lnbmVyYDogdGhlIHBsYXllciBvciB0aGVpciBzZXNzaW9uIGtleS4AAAAAAAARY2hhbGxlbmdlX3N0ZXBfYXMAAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAABHN0ZXAAAAAEAAAAAQAAA+kAAAABAAAAAw==",
+        "AAAAAAAAAP1DbG9zZSBhbiBvcHRpbWlzdGljIHJvdW5kIGFuZCBzdGFydCB0aGUgbmV4dCBvbmUuIEFueW9uZSBjYW4gY2FsbCBpdAphZnRlciB0aGUgZGVhZGxpbmU6IGlmIHRoZSBwcmV5IG5ldmVyIHN1Ym1pdHRlZCBpdHMgcHJvb2ZzLCB0aGUgcm91bmQKaXMgZm9yZmVpdGVkIHRvIHRoZSBodW50ZXIuIEJlZm9yZSB0aGUgZGVhZGxpbmUsIHRoZSBodW50ZXIgY2FuIGNhbGwgaXQKdG8gd2FpdmUgdGhlIHJlc3Qgb2YgdGhlIGNoYWxsZW5nZSB3aW5kb3cuAAAAAAAADHNldHRsZV9tb3ZlcwAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAENgc2V0dGxlX21vdmVzYCBzaWduZWQgYnkgYHNpZ25lcmA6IHRoZSBwbGF5ZXIgb3IgdGhlaXIgc2Vzc2lvbiBrZXkuAAAAAA9zZXR0bGVfbW92ZXNfYXMAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAM9SZXNlbmQgYSBzZXNzaW9uJ3MgcXVldWVkIEdhbWUgSHViIG5vdGlmaWNhdGlvbnMgaW4gb3JkZXIsIHN0b3BwaW5nCmF0IHRoZSBmaXJzdCB0aGF0IGZhaWxzIGFnYWluLiBPbmVzIHRoZSBodWIgcmVqZWN0cyB3aXRoIGEgY29udHJhY3QKZXJyb3IgYXJlIGRyb3BwZWQuIEFueW9uZSBjYW4gY2FsbCBpdC4gUmV0dXJucyBob3cgbWFueSB3ZXJlIGRlbGl2ZXJlZC4AAAAAF3JldHJ5X2h1Yl9ub3RpZmljYXRpb25zAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAdZ2V0X3BlbmRpbmdfaHViX25vdGlmaWNhdGlvbnMAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+oAAAfQAAAAD0h1Yk5vdGlmaWNhdGlvbgA=",
+        "AAAAAAAAADhSZWFkIHRoZSBvcHRpbWlzdGljIHJvdW5kIHdhaXRpbmcgdG8gYmUgc2V0dGxlZCwgaWYgYW55LgAAAA5nZXRfbW92ZV9iYXRjaAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAAlNb3ZlQmF0Y2gAAAA=",
+        "AAAAAAAAADdSZWFkIHRoZSB0cmFuc2NyaXB0IG9mIGEgZmluaXNoZWQgcm91bmQgKGBhdWRpdGAgcnVsZSkuAAAAAA5nZXRfdHJhbnNjcmlwdAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAFcm91bmQAAAAAAAAEAAAAAQAAA+kAAAfQAAAAD1JvdW5kVHJhbnNjcmlwdAAAAAAD",
+        "AAAAAAAAASFSZWdpc3RlciBhIHNlc3Npb24ga2V5IHRoYXQgc2lnbnMgYHBsYXllcmAncyBnYW1lIGFjdGlvbnMgaW4gdGhpcwpzZXNzaW9uIHVudGlsIGBleHBpcmVzX2xlZGdlcmAsIHRocm91Z2ggZWFjaCBhY3Rpb24ncyBgX2FzYCBlbnRyeSBwb2ludC4KVGhlIHBsYWluIGVudHJ5IHBvaW50cyBrZWVwIHRha2luZyB0aGUgcGxheWVyJ3Mgb3duIHNpZ25hdHVyZS4gVGhlIGtleQpjYW5ub3QgYWN0IGluIGFueSBvdGhlciBzZXNzaW9uLCBub3Igam9pbiwgcmV2ZWFsIHNlZWRzIG9yIG1hbmFnZQpzZXNzaW9uIGtleXMuAAAAAAAAFHJlZ2lzdGVyX3Nlc3Npb25fa2V5AAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAtzZXNzaW9uX2tleQAAAAATAAAAAAAAAA5leHBpcmVzX2xlZGdlcgAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAAD",
+        "AAAAAAAAAE9SZW1vdmUgYHBsYXllcmAncyBzZXNzaW9uIGtleTsgb25seSB0aGUgcGxheWVyIGNhbiBzaWduIGl0cyBnYW1lIGFjdGlvbnMgYWdhaW4uAAAAABJyZXZva2Vfc2Vzc2lvbl9rZXkAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAA=",
+        "AAAAAAAAAEBSZWFkIGBwbGF5ZXJgJ3Mgc2Vzc2lvbiBrZXkgZm9yIGEgc2Vzc2lvbiwgaWYgb25lIGlzIHJlZ2lzdGVyZWQuAAAAD2dldF9zZXNzaW9uX2tleQAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6AAAB9AAAAAKU2Vzc2lvbktleQAA",
+        "AAAAAAAAANFSZWdpc3RlciBgcGxheWVyYCdzIGVkMjU1MTkga2V5IGZvciBjaGFubmVsIHBsYXkuIE9uY2UgYm90aCBwbGF5ZXJzCmhhdmUgb25lLCB0aGV5IGNhbiBleGNoYW5nZSBzaWduZWQgYENoYW5uZWxVcGRhdGVgcyBvZmYtY2hhaW4gYW5kIG9ubHkKY29tZSBiYWNrIG9uLWNoYWluIHRvIHNldHRsZSBvciBkaXNwdXRlLiBLZXlzIGNhbm5vdCBiZSBjaGFuZ2VkIGxhdGVyLgAAAAAAAAxvcGVuX2NoYW5uZWwAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAC2NoYW5uZWxfa2V5AAAAA+4AAAAgAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAMFTZXR0bGUgYSBtYXRjaCBwbGF5ZWQgb2ZmLWNoYWluOiBwb3N0IHRoZSBmaW5hbCBzdGF0ZSAoYHBoYXNlID09IEVuZGVkYCkKc2lnbmVkIGJ5IGJvdGggcGxheWVycy4gVGhlIHdpbm5lciBmb2xsb3dzIGZyb20gdGhlIHNpZ25lZCBzY29yZXMgYW5kCnRoZSBHYW1lIEh1YiBpcyBub3RpZmllZCBhcyBmb3IgYW4gb24tY2hhaW4gbWF0Y2guAAAAAAAADHNldHRsZV9tYXRjaAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnVwZGF0ZQAAAAAH0AAAAA1DaGFubmVsVXBkYXRlAAAAAAAAAAAAAAtwbGF5ZXIxX3NpZwAAAAPuAAAAQAAAAAAAAAALcGxheWVyMl9zaWcAAAAD7gAAAEAAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAeFQb3N0IHRoZSBsYXRlc3Qgc3RhdGUgc2lnbmVkIGJ5IGJvdGggcGxheWVycyB3aGVuIHRoZSBvcHBvbmVudCBzdG9wcwphbnN3ZXJpbmcgb2ZmLWNoYWluLiBJdCByZXBsYWNlcyB0aGUgb24tY2hhaW4gZ2FtZSBzdGF0ZSB3aG9sZXNhbGUKKG9ubHkgdGhlIG1hdGNoIGNvbmZpZyBhbmQgcm9zdGVycyBtdXN0IG1hdGNoLCBhbmQgcm91bmQgYW5kIHNjb3JlcwpjYW5ub3QgZ28gYmFjaykuIEl0cyBgc2VxYCBtdXN0IGJlIGFib3ZlIHRoZSBjaGFubmVsJ3MsIHdoaWNoIGV2ZXJ5Cm9uLWNoYWluIGFjdGlvbiBhZHZhbmNlcywgc28gaXQgY2Fubm90IHJvbGwgYmFjayBvbi1jaGFpbiBtb3Zlcy4gVGhlCm9wcG9uZW50ICh3aG8gbXVzdCBiZSB0aGUgb25lIHRvIG1vdmUpIGhhcyB1bnRpbCB0aGUgZGVhZGxpbmUgdG8KYW5zd2VyIHdpdGggYW4gb24tY2hhaW4gbW92ZSBvciBhIG5ld2VyIHNpZ25lZCBzdGF0ZSBoYW5kaW5nIHRoZSBtb3ZlCmJhY2suAAAAAAAAB2Rpc3B1dGUAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZ1cGRhdGUAAAAAB9AAAAANQ2hhbm5lbFVwZGF0ZQAAAAAAAAAAAAALcGxheWVyMV9zaWcAAAAD7gAAAEAAAAAAAAAAC3BsYXllcjJfc2lnAAAAA+4AAABAAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAAG5BZnRlciB0aGUgZGlzcHV0ZSBkZWFkbGluZSwgZW5kIHRoZSBtYXRjaCBpbiB0aGUgY2hhbGxlbmdlcidzIGZhdm91ciBpZgp0aGUgZGlzcHV0ZWQgc3RhdGUgd2FzIG5ldmVyIG1vdmVkIG9uLgAAAAAAEGZpbmFsaXplX2Rpc3B1dGUAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAADtSZWFkIGEgc2Vzc2lvbidzIGNoYW5uZWwgc3RhdGUsIGlmIGNoYW5uZWwgcGxheSB3YXMgb3BlbmVkLgAAAAALZ2V0X2NoYW5uZWwAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAAdDaGFubmVsAA==",
+        "AAAAAAAAADJSZWFkIHRoZSBzZWVkIGNvbW1pdG1lbnRzIG9mIGEgY29tbWl0LXJldmVhbCBnYW1lLgAAAAAACWdldF9zZWVkcwAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAJU2VlZFN0YXRlAAAAAAAAAw==",
         "AAAAAAAAACdSZWFkIGdhbWUgc3RhdGUgKGZvciBmcm9udGVuZCBwb2xsaW5nKS4AAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
         "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
         "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
@@ -272,21 +1670,112 @@ export class Client extends ContractClient {
   }
   public readonly fromJSON = {
     set_vks: this.txFromJSON<null>,
+        set_vk: this.txFromJSON<null>,
         set_game_hub: this.txFromJSON<null>,
+        set_game_hub_v2: this.txFromJSON<null>,
         create_game: this.txFromJSON<u32>,
+        create_game_with_config: this.txFromJSON<Result<u32>>,
         join_game: this.txFromJSON<Result<void>>,
+        join_game_with_seed: this.txFromJSON<Result<void>>,
+        join_team: this.txFromJSON<Result<void>>,
+        reveal_seed: this.txFromJSON<Result<void>>,
+        claim_reveal_timeout: this.txFromJSON<Result<void>>,
+        ban_map: this.txFromJSON<Result<void>>,
+        force_draft_ban: this.txFromJSON<Result<void>>,
         hunter_move: this.txFromJSON<Result<void>>,
+        hunter_move_as: this.txFromJSON<Result<void>>,
         hunter_search: this.txFromJSON<Result<void>>,
+        hunter_search_as: this.txFromJSON<Result<void>>,
+        hunter_move_and_search: this.txFromJSON<Result<void>>,
+        hunter_move_and_search_as: this.txFromJSON<Result<void>>,
         hunter_power_search: this.txFromJSON<Result<void>>,
+        hunter_power_search_as: this.txFromJSON<Result<void>>,
+        hunter_enter_jungle: this.txFromJSON<Result<void>>,
+        hunter_enter_jungle_as: this.txFromJSON<Result<void>>,
+        hunter_move_jungle: this.txFromJSON<Result<void>>,
+        hunter_move_jungle_as: this.txFromJSON<Result<void>>,
+        hunter_exit_jungle: this.txFromJSON<Result<void>>,
+        hunter_exit_jungle_as: this.txFromJSON<Result<void>>,
+        hunter_search_hidden: this.txFromJSON<Result<void>>,
+        hunter_search_hidden_as: this.txFromJSON<Result<void>>,
+        hunter_sonar: this.txFromJSON<Result<void>>,
+        hunter_sonar_as: this.txFromJSON<Result<void>>,
+        hunter_flare: this.txFromJSON<Result<void>>,
+        hunter_flare_as: this.txFromJSON<Result<void>>,
+        hunter_place_trap: this.txFromJSON<Result<void>>,
+        hunter_place_trap_as: this.txFromJSON<Result<void>>,
+        hunter_reveal_trap: this.txFromJSON<Result<boolean>>,
+        hunter_reveal_trap_as: this.txFromJSON<Result<boolean>>,
+        prey_prove_traps: this.txFromJSON<Result<void>>,
+        prey_prove_traps_as: this.txFromJSON<Result<void>>,
+        close_trap_reveal: this.txFromJSON<Result<void>>,
+        close_trap_reveal_as: this.txFromJSON<Result<void>>,
+        prey_place_decoy: this.txFromJSON<Result<void>>,
+        prey_place_decoy_as: this.txFromJSON<Result<void>>,
+        hunter_burn: this.txFromJSON<Result<void>>,
+        hunter_burn_as: this.txFromJSON<Result<void>>,
+        respond_burn: this.txFromJSON<Result<void>>,
+        respond_burn_as: this.txFromJSON<Result<void>>,
+        prey_prove_zone: this.txFromJSON<Result<void>>,
+        prey_prove_zone_as: this.txFromJSON<Result<void>>,
+        prey_reveal_scent: this.txFromJSON<Result<void>>,
+        prey_reveal_scent_as: this.txFromJSON<Result<void>>,
+        prey_extract_hidden: this.txFromJSON<Result<void>>,
+        prey_extract_hidden_as: this.txFromJSON<Result<void>>,
+        prey_commit_items: this.txFromJSON<Result<void>>,
+        prey_commit_items_as: this.txFromJSON<Result<void>>,
+        prey_claim_item: this.txFromJSON<Result<void>>,
+        prey_claim_item_as: this.txFromJSON<Result<void>>,
         prey_move_public: this.txFromJSON<Result<void>>,
+        prey_move_public_as: this.txFromJSON<Result<void>>,
         prey_enter_jungle: this.txFromJSON<Result<void>>,
+        prey_enter_jungle_as: this.txFromJSON<Result<void>>,
+        prey_spawn_hidden: this.txFromJSON<Result<void>>,
+        prey_spawn_hidden_as: this.txFromJSON<Result<void>>,
         prey_move_jungle: this.txFromJSON<Result<void>>,
+        prey_move_jungle_as: this.txFromJSON<Result<void>>,
+        prey_dash_jungle: this.txFromJSON<Result<void>>,
+        prey_dash_jungle_as: this.txFromJSON<Result<void>>,
         prey_exit_jungle: this.txFromJSON<Result<void>>,
+        prey_exit_jungle_as: this.txFromJSON<Result<void>>,
         respond_search: this.txFromJSON<Result<void>>,
+        respond_search_as: this.txFromJSON<Result<void>>,
+        respond_sonar: this.txFromJSON<Result<void>>,
+        respond_sonar_as: this.txFromJSON<Result<void>>,
+        respond_flare: this.txFromJSON<Result<void>>,
+        respond_flare_as: this.txFromJSON<Result<void>>,
+        respond_search_with_decoy: this.txFromJSON<Result<void>>,
+        respond_search_with_decoy_as: this.txFromJSON<Result<void>>,
         hunter_emp: this.txFromJSON<Result<void>>,
+        hunter_emp_as: this.txFromJSON<Result<void>>,
         prey_pass_frozen: this.txFromJSON<Result<void>>,
         prey_dash_public: this.txFromJSON<Result<void>>,
+        prey_dash_public_as: this.txFromJSON<Result<void>>,
         claim_catch: this.txFromJSON<Result<string>>,
+        claim_catch_as: this.txFromJSON<Result<string>>,
+        reveal_round: this.txFromJSON<Result<void>>,
+        reveal_round_as: this.txFromJSON<Result<void>>,
+        claim_audit_timeout: this.txFromJSON<Result<void>>,
+        claim_audit_timeout_as: this.txFromJSON<Result<void>>,
+        submit_step_proof: this.txFromJSON<Result<void>>,
+        submit_step_proof_as: this.txFromJSON<Result<void>>,
+        challenge_step: this.txFromJSON<Result<boolean>>,
+        challenge_step_as: this.txFromJSON<Result<boolean>>,
+        settle_moves: this.txFromJSON<Result<void>>,
+        settle_moves_as: this.txFromJSON<Result<void>>,
+        retry_hub_notifications: this.txFromJSON<u32>,
+        get_pending_hub_notifications: this.txFromJSON<Array<HubNotification>>,
+        get_move_batch: this.txFromJSON<Option<MoveBatch>>,
+        get_transcript: this.txFromJSON<Result<RoundTranscript>>,
+        register_session_key: this.txFromJSON<Result<void>>,
+        revoke_session_key: this.txFromJSON<null>,
+        get_session_key: this.txFromJSON<Option<SessionKey>>,
+        open_channel: this.txFromJSON<Result<void>>,
+        settle_match: this.txFromJSON<Result<void>>,
+        dispute: this.txFromJSON<Result<void>>,
+        finalize_dispute: this.txFromJSON<Result<string>>,
+        get_channel: this.txFromJSON<Option<Channel>>,
+        get_seeds: this.txFromJSON<Result<SeedState>>,
         get_game: this.txFromJSON<Result<Game>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
@@ -334,7 +334,7 @@ export const GameView: React.FC<GameViewProps> = ({ sessionId, isHunter: initial
             const newNonce = generateRandomNonce();
             const proofResult = await gameServiceRef.current.generateJungleMoveProof(
               preySecret.x, preySecret.y, preySecret.nonce,
-              x, y, newNonce, mapId,
+              x, y, newNonce, mapId, game.burned_tiles,
             );
             const tx = await client.prey_move_jungle({
               session_id: sessionId,
@@ -356,7 +356,7 @@ export const GameView: React.FC<GameViewProps> = ({ sessionId, isHunter: initial
             const newNonce = generateRandomNonce();
             const proofResult = await gameServiceRef.current.generateJungleMoveProof(
               preySecret.x, preySecret.y, preySecret.nonce,
-              x, y, newNonce, mapId,
+              x, y, newNonce, mapId, game.burned_tiles,
             );
             const tx = await client.prey_enter_jungle({
               session_id: sessionId,
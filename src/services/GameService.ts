@@ -155,7 +155,8 @@ export class GameService {
   /**
    * Generate a jungle_move proof for entering or moving within jungle.
    * Commitments are computed automatically from positions and nonces.
-   * mapId is the map index from the game state.
+   * mapId is the map index from the game state; burned is the game's
+   * burned_tiles mask (bit y*8 + x).
    */
   async generateJungleMoveProof(
     oldX: number,
@@ -165,6 +166,7 @@ export class GameService {
     newY: number,
     newNonce: bigint,
     mapId: number,
+    burned: bigint = 0n,
   ) {
     console.log('[GameService] Generating jungle_move proof...');
 
@@ -176,6 +178,7 @@ export class GameService {
       old_commitment: oldCommitment,
       new_commitment: newCommitment,
       map_id: mapId,
+      burned: burned.toString(),
       old_x: oldX,
       old_y: oldY,
       old_nonce: oldNonce.toString(),